@@ -0,0 +1,216 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "bincode")))]
+
+use crate::util::cautious_capacity;
+use crate::{OrderMap, OrderSet};
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{BorrowDecode, Decode, Encode};
+use core::hash::{BuildHasher, Hash};
+
+/// Encodes a length prefix the same way as `bincode` does for its own
+/// collections.
+fn encode_len<E: Encoder>(encoder: &mut E, len: usize) -> Result<(), EncodeError> {
+    (len as u64).encode(encoder)
+}
+
+/// Decodes a length prefix for `len` items of type `T`, and claims their size
+/// against the decoder's limit before anything is allocated for them.
+///
+/// Without a limit, the length is still unchecked against the input, so only
+/// a [cautious capacity][cautious_capacity] should be preallocated for it.
+fn decode_len<T, D: Decoder>(decoder: &mut D) -> Result<usize, DecodeError> {
+    let len = u64::decode(decoder)?;
+    let len = usize::try_from(len).map_err(|_| DecodeError::OutsideUsizeRange(len))?;
+    decoder.claim_container_read::<T>(len)?;
+    Ok(len)
+}
+
+impl<K, V, S> Encode for OrderMap<K, V, S>
+where
+    K: Encode,
+    V: Encode,
+{
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encode_len(encoder, self.len())?;
+        for (key, value) in self {
+            key.encode(encoder)?;
+            value.encode(encoder)?;
+        }
+        Ok(())
+    }
+}
+
+impl<Context, K, V, S> Decode<Context> for OrderMap<K, V, S>
+where
+    K: Decode<Context> + Eq + Hash,
+    V: Decode<Context>,
+    S: BuildHasher + Default,
+{
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let len = decode_len::<(K, V), _>(decoder)?;
+        let capacity = cautious_capacity::<K, V>(Some(len));
+        let mut map = OrderMap::with_capacity_and_hasher(capacity, S::default());
+        for _ in 0..len {
+            // Each entry claims its own bytes as it is decoded.
+            decoder.unclaim_bytes_read(size_of::<(K, V)>());
+            let key = K::decode(decoder)?;
+            let value = V::decode(decoder)?;
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+}
+
+impl<'de, Context, K, V, S> BorrowDecode<'de, Context> for OrderMap<K, V, S>
+where
+    K: BorrowDecode<'de, Context> + Eq + Hash,
+    V: BorrowDecode<'de, Context>,
+    S: BuildHasher + Default,
+{
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        let len = decode_len::<(K, V), _>(decoder)?;
+        let capacity = cautious_capacity::<K, V>(Some(len));
+        let mut map = OrderMap::with_capacity_and_hasher(capacity, S::default());
+        for _ in 0..len {
+            decoder.unclaim_bytes_read(size_of::<(K, V)>());
+            let key = K::borrow_decode(decoder)?;
+            let value = V::borrow_decode(decoder)?;
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+}
+
+impl<T, S> Encode for OrderSet<T, S>
+where
+    T: Encode,
+{
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encode_len(encoder, self.len())?;
+        for value in self {
+            value.encode(encoder)?;
+        }
+        Ok(())
+    }
+}
+
+impl<Context, T, S> Decode<Context> for OrderSet<T, S>
+where
+    T: Decode<Context> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let len = decode_len::<T, _>(decoder)?;
+        let capacity = cautious_capacity::<T, ()>(Some(len));
+        let mut set = OrderSet::with_capacity_and_hasher(capacity, S::default());
+        for _ in 0..len {
+            decoder.unclaim_bytes_read(size_of::<T>());
+            set.insert(T::decode(decoder)?);
+        }
+        Ok(set)
+    }
+}
+
+impl<'de, Context, T, S> BorrowDecode<'de, Context> for OrderSet<T, S>
+where
+    T: BorrowDecode<'de, Context> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        let len = decode_len::<T, _>(decoder)?;
+        let capacity = cautious_capacity::<T, ()>(Some(len));
+        let mut set = OrderSet::with_capacity_and_hasher(capacity, S::default());
+        for _ in 0..len {
+            decoder.unclaim_bytes_read(size_of::<T>());
+            set.insert(T::borrow_decode(decoder)?);
+        }
+        Ok(set)
+    }
+}
+
+#[cfg(test)]
+mod bincode_tests {
+    use super::*;
+    use bincode::config;
+
+    #[test]
+    fn map_bincode_roundtrip() {
+        let original_map: OrderMap<i32, i32> = [(5, 6), (1, 2), (3, 4)].into();
+        let encoded_map = bincode::encode_to_vec(&original_map, config::standard()).unwrap();
+        let (decoded_map, len): (OrderMap<i32, i32>, _) =
+            bincode::decode_from_slice(&encoded_map, config::standard()).unwrap();
+        assert_eq!(len, encoded_map.len());
+        assert_eq!(original_map, decoded_map);
+
+        // The same encoding as a sequence of pairs, in order.
+        let pairs = [(5, 6), (1, 2), (3, 4)];
+        let encoded_pairs = bincode::encode_to_vec(&pairs[..], config::standard()).unwrap();
+        assert_eq!(encoded_map, encoded_pairs);
+    }
+
+    #[test]
+    fn set_bincode_roundtrip() {
+        let original_set: OrderSet<i32> = [6, 5, 4, 1, 2, 3].into_iter().collect();
+        let encoded_set = bincode::encode_to_vec(&original_set, config::standard()).unwrap();
+        let (decoded_set, _): (OrderSet<i32>, _) =
+            bincode::decode_from_slice(&encoded_set, config::standard()).unwrap();
+        assert_eq!(original_set, decoded_set);
+    }
+
+    #[test]
+    fn bincode_borrow_decode() {
+        let original_map: OrderMap<&str, &[u8]> = [("b", &b"bee"[..]), ("a", &b"ay"[..])].into();
+        let encoded_map = bincode::encode_to_vec(&original_map, config::standard()).unwrap();
+        let (decoded_map, _): (OrderMap<&str, &[u8]>, _) =
+            bincode::borrow_decode_from_slice(&encoded_map, config::standard()).unwrap();
+        assert_eq!(original_map, decoded_map);
+
+        let original_set: OrderSet<&str> = ["z", "y", "x"].into();
+        let encoded_set = bincode::encode_to_vec(&original_set, config::standard()).unwrap();
+        let (decoded_set, _): (OrderSet<&str>, _) =
+            bincode::borrow_decode_from_slice(&encoded_set, config::standard()).unwrap();
+        assert_eq!(original_set, decoded_set);
+    }
+
+    #[test]
+    fn bincode_decode_limit() {
+        // A length prefix of `u32::MAX` with no entries behind it.
+        let bytes = bincode::encode_to_vec(u64::from(u32::MAX), config::standard()).unwrap();
+        let limited = config::standard().with_limit::<1024>();
+
+        let result = bincode::decode_from_slice::<OrderMap<u64, u64>, _>(&bytes, limited);
+        assert!(matches!(result, Err(DecodeError::LimitExceeded)));
+        let result = bincode::decode_from_slice::<OrderSet<u64>, _>(&bytes, limited);
+        assert!(matches!(result, Err(DecodeError::LimitExceeded)));
+        let result = bincode::borrow_decode_from_slice::<OrderSet<&str>, _>(&bytes, limited);
+        assert!(matches!(result, Err(DecodeError::LimitExceeded)));
+
+        // Small maps still fit within the limit.
+        let map: OrderMap<u64, u64> = (0..10).map(|i| (i, i)).collect();
+        let bytes = bincode::encode_to_vec(&map, limited).unwrap();
+        let (decoded, _): (OrderMap<u64, u64>, _) =
+            bincode::decode_from_slice(&bytes, limited).unwrap();
+        assert_eq!(decoded, map);
+    }
+
+    #[test]
+    fn bincode_decode_unlimited() {
+        // A huge length prefix, which must not be preallocated without a limit.
+        let bytes = bincode::encode_to_vec(1u64 << 58, config::standard()).unwrap();
+        assert_eq!(bytes.len(), 9);
+
+        let result =
+            bincode::decode_from_slice::<OrderMap<u64, u64>, _>(&bytes, config::standard());
+        assert!(matches!(result, Err(DecodeError::UnexpectedEnd { .. })));
+        let result = bincode::decode_from_slice::<OrderSet<u64>, _>(&bytes, config::standard());
+        assert!(matches!(result, Err(DecodeError::UnexpectedEnd { .. })));
+        let result =
+            bincode::borrow_decode_from_slice::<OrderMap<&str, u64>, _>(&bytes, config::standard());
+        assert!(matches!(result, Err(DecodeError::UnexpectedEnd { .. })));
+    }
+}
@@ -1,10 +1,27 @@
+//! Borsh support for [`OrderMap`] and [`OrderSet`].
+//!
+//! The [`BorshDeserialize`] implementations of `OrderMap` and `OrderSet`
+//! quietly accept input with duplicate keys, where a later value overwrites
+//! an earlier one and the key keeps its first position. The [`Strict`]
+//! wrapper fails deserialization instead.
+//!
+//! Those implementations also need `S: Default` to create the hasher. For a
+//! keyed or runtime-seeded hasher, use [`OrderMap::deserialize_with_hasher`]
+//! or [`OrderSet::deserialize_with_hasher`] instead.
+
 #![cfg_attr(docsrs, doc(cfg(feature = "borsh")))]
 
+use crate::map::Entry;
+use crate::util::cautious_capacity;
 use crate::{OrderMap, OrderSet};
-use borsh::io::{Read, Result, Write};
+use alloc::format;
+use borsh::error::ERROR_ZST_FORBIDDEN;
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
 use borsh::{BorshDeserialize, BorshSerialize};
+use core::fmt;
 use core::hash::BuildHasher;
 use core::hash::Hash;
+use core::ops::{Deref, DerefMut};
 
 impl<K, V, S> BorshSerialize for OrderMap<K, V, S>
 where
@@ -54,9 +71,206 @@ where
     }
 }
 
+fn check_zst<T>() -> Result<()> {
+    if size_of::<T>() == 0 {
+        return Err(Error::new(ErrorKind::InvalidData, ERROR_ZST_FORBIDDEN));
+    }
+    Ok(())
+}
+
+impl<K, V, S> OrderMap<K, V, S>
+where
+    K: BorshDeserialize + Eq + Hash,
+    V: BorshDeserialize,
+    S: BuildHasher,
+{
+    /// Deserializes a map with borsh, using `hasher` for the new map.
+    ///
+    /// This reads the same format as the [`BorshDeserialize`] implementation,
+    /// which requires `S: Default` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    /// use std::hash::RandomState;
+    ///
+    /// let map: OrderMap<u8, u32> = [(3, 30), (1, 10)].into();
+    /// let bytes = borsh::to_vec(&map).unwrap();
+    /// let mut reader = &bytes[..];
+    /// let copy: OrderMap<u8, u32> = OrderMap::deserialize_with_hasher(&mut reader, RandomState::new()).unwrap();
+    /// assert_eq!(copy.as_slice(), map.as_slice());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "borsh")))]
+    pub fn deserialize_with_hasher<R: Read>(reader: &mut R, hasher: S) -> Result<Self> {
+        check_zst::<K>()?;
+        let len = read_len(reader)?;
+        let capacity = cautious_capacity::<K, V>(Some(len));
+        let mut map = OrderMap::with_capacity_and_hasher(capacity, hasher);
+        for _ in 0..len {
+            let key = K::deserialize_reader(reader)?;
+            let value = V::deserialize_reader(reader)?;
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+}
+
+impl<T, S> OrderSet<T, S>
+where
+    T: BorshDeserialize + Eq + Hash,
+    S: BuildHasher,
+{
+    /// Deserializes a set with borsh, using `hasher` for the new set.
+    ///
+    /// This reads the same format as the [`BorshDeserialize`] implementation,
+    /// which requires `S: Default` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderSet;
+    /// use std::hash::RandomState;
+    ///
+    /// let set: OrderSet<u8> = [3, 1, 2].into();
+    /// let bytes = borsh::to_vec(&set).unwrap();
+    /// let mut reader = &bytes[..];
+    /// let copy: OrderSet<u8> = OrderSet::deserialize_with_hasher(&mut reader, RandomState::new()).unwrap();
+    /// assert_eq!(copy.as_slice(), set.as_slice());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "borsh")))]
+    pub fn deserialize_with_hasher<R: Read>(reader: &mut R, hasher: S) -> Result<Self> {
+        check_zst::<T>()?;
+        let len = read_len(reader)?;
+        let capacity = cautious_capacity::<T, ()>(Some(len));
+        let mut set = OrderSet::with_capacity_and_hasher(capacity, hasher);
+        for _ in 0..len {
+            set.insert(T::deserialize_reader(reader)?);
+        }
+        Ok(set)
+    }
+}
+
+/// A wrapper for an [`OrderMap`] or [`OrderSet`] that rejects duplicate keys
+/// when deserializing with borsh.
+///
+/// This is serialized exactly like the wrapped collection, but deserializing
+/// fails with an [`ErrorKind::InvalidData`] error naming the first duplicate
+/// key or value and the positions where it appeared.
+///
+/// # Examples
+///
+/// ```
+/// use borsh::BorshDeserialize;
+/// use ordermap::OrderMap;
+/// use ordermap::borsh::Strict;
+///
+/// let bytes = borsh::to_vec(&[(1u8, 10u32), (2, 20), (1, 30)].as_slice()).unwrap();
+///
+/// let map = OrderMap::<u8, u32>::try_from_slice(&bytes).unwrap();
+/// assert_eq!(map, [(1, 30), (2, 20)].into());
+///
+/// let err = Strict::<OrderMap<u8, u32>>::try_from_slice(&bytes).unwrap_err();
+/// assert_eq!(err.to_string(), "duplicate key 1 at positions 0 and 2");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Strict<T>(pub T);
+
+impl<T> Strict<T> {
+    /// Unwraps the inner collection.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Strict<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Strict<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> From<T> for Strict<T> {
+    fn from(value: T) -> Self {
+        Strict(value)
+    }
+}
+
+impl<T: BorshSerialize> BorshSerialize for Strict<T> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+/// Reads the `u32` length prefix of a borsh sequence.
+fn read_len<R: Read>(reader: &mut R) -> Result<usize> {
+    let len = u32::deserialize_reader(reader)?;
+    usize::try_from(len).map_err(|_| Error::new(ErrorKind::InvalidData, "length overflows usize"))
+}
+
+fn duplicate(what: &str, item: &dyn fmt::Debug, first: usize, second: usize) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("duplicate {what} {item:?} at positions {first} and {second}"),
+    )
+}
+
+impl<K, V, S> BorshDeserialize for Strict<OrderMap<K, V, S>>
+where
+    K: BorshDeserialize + Eq + Hash + fmt::Debug,
+    V: BorshDeserialize,
+    S: BuildHasher + Default,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        check_zst::<K>()?;
+        let len = read_len(reader)?;
+        let capacity = cautious_capacity::<K, V>(Some(len));
+        let mut map = OrderMap::with_capacity_and_hasher(capacity, S::default());
+        for position in 0..len {
+            match map.entry(K::deserialize_reader(reader)?) {
+                Entry::Occupied(entry) => {
+                    return Err(duplicate("key", entry.key(), entry.index(), position));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(V::deserialize_reader(reader)?);
+                }
+            }
+        }
+        Ok(Strict(map))
+    }
+}
+
+impl<T, S> BorshDeserialize for Strict<OrderSet<T, S>>
+where
+    T: BorshDeserialize + Eq + Hash + fmt::Debug,
+    S: BuildHasher + Default,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        check_zst::<T>()?;
+        let len = read_len(reader)?;
+        let capacity = cautious_capacity::<T, ()>(Some(len));
+        let mut set = OrderSet::with_capacity_and_hasher(capacity, S::default());
+        for position in 0..len {
+            if let (index, false) = set.insert_full(T::deserialize_reader(reader)?) {
+                return Err(duplicate("value", &set[index], index, position));
+            }
+        }
+        Ok(Strict(set))
+    }
+}
+
 #[cfg(test)]
 mod borsh_tests {
     use super::*;
+    use alloc::string::ToString;
 
     #[test]
     fn map_borsh_roundtrip() {
@@ -81,4 +295,78 @@ mod borsh_tests {
             BorshDeserialize::try_from_slice(&serialized_map).unwrap();
         assert_eq!(original_map, deserialized_map);
     }
+
+    #[test]
+    fn strict_borsh_rejects_duplicates() {
+        let bytes = borsh::to_vec(&[(1, 2), (3, 4), (5, 6), (3, 0)].as_slice()).unwrap();
+        let map: OrderMap<i32, i32> = BorshDeserialize::try_from_slice(&bytes).unwrap();
+        assert_eq!(map, OrderMap::from([(1, 2), (3, 0), (5, 6)]));
+        let err = Strict::<OrderMap<i32, i32>>::try_from_slice(&bytes).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "duplicate key 3 at positions 1 and 3");
+
+        let bytes = borsh::to_vec(&map).unwrap();
+        let strict = Strict::<OrderMap<i32, i32>>::try_from_slice(&bytes).unwrap();
+        assert_eq!(strict.into_inner(), map);
+        assert_eq!(borsh::to_vec(&Strict(&map)).unwrap(), bytes);
+
+        let bytes = borsh::to_vec(&[1, 2, 3, 2, 1].as_slice()).unwrap();
+        let err = Strict::<OrderSet<i32>>::try_from_slice(&bytes).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate value 2 at positions 1 and 3");
+        let bytes = borsh::to_vec(&[1, 2, 3].as_slice()).unwrap();
+        let strict = Strict::<OrderSet<i32>>::try_from_slice(&bytes).unwrap();
+        assert!(strict.iter().eq(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn strict_borsh_checks_input() {
+        // A huge length prefix without the entries to back it up.
+        let bytes = u32::MAX.to_le_bytes();
+        let err = Strict::<OrderMap<i32, i32>>::try_from_slice(&bytes).unwrap_err();
+        assert_eq!(err.to_string(), "Unexpected length of input");
+        let err = Strict::<OrderSet<i32>>::try_from_slice(&bytes).unwrap_err();
+        assert_eq!(err.to_string(), "Unexpected length of input");
+
+        let bytes = borsh::to_vec(&[((), 1)].as_slice()).unwrap();
+        let err = Strict::<OrderMap<(), i32>>::try_from_slice(&bytes).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        let bytes = borsh::to_vec(&[()].as_slice()).unwrap();
+        let err = Strict::<OrderSet<()>>::try_from_slice(&bytes).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn borsh_deserialize_with_hasher() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let hasher = RandomState::new();
+        let map: OrderMap<i32, i32> = [(5, 6), (1, 2), (3, 4)].into();
+        let bytes = borsh::to_vec(&map).unwrap();
+        let copy: OrderMap<i32, i32> =
+            OrderMap::deserialize_with_hasher(&mut &bytes[..], hasher.clone()).unwrap();
+        assert_eq!(copy.as_slice(), map.as_slice());
+        assert_eq!(copy.hasher().hash_one(1), hasher.hash_one(1));
+
+        let set: OrderSet<i32> = [3, 1, 2].into();
+        let bytes = borsh::to_vec(&set).unwrap();
+        let copy: OrderSet<i32> =
+            OrderSet::deserialize_with_hasher(&mut &bytes[..], hasher.clone()).unwrap();
+        assert_eq!(copy.as_slice(), set.as_slice());
+        assert_eq!(copy.hasher().hash_one(1), hasher.hash_one(1));
+
+        let bytes = borsh::to_vec(&[(1, 2), (3, 4), (1, 5)].as_slice()).unwrap();
+        let map: OrderMap<i32, i32> =
+            OrderMap::deserialize_with_hasher(&mut &bytes[..], hasher.clone()).unwrap();
+        assert!(map.into_iter().eq([(1, 5), (3, 4)]));
+
+        let bytes = u32::MAX.to_le_bytes();
+        let err = OrderMap::<i32, i32>::deserialize_with_hasher(&mut &bytes[..], hasher.clone());
+        assert_eq!(err.unwrap_err().to_string(), "Unexpected length of input");
+        let err = OrderSet::<i32>::deserialize_with_hasher(&mut &bytes[..], hasher.clone());
+        assert_eq!(err.unwrap_err().to_string(), "Unexpected length of input");
+
+        let bytes = borsh::to_vec(&[((), 1)].as_slice()).unwrap();
+        let err = OrderMap::<(), i32>::deserialize_with_hasher(&mut &bytes[..], hasher);
+        assert_eq!(err.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
 }
@@ -31,9 +31,32 @@
 //! * `serde`: Adds implementations for [`Serialize`] and [`Deserialize`]
 //!   to [`OrderMap`] and [`OrderSet`]. Alternative implementations for
 //!   (de)serializing [`OrderMap`] as an ordered sequence are available in the
-//!   [`map::serde_seq`] module.
+//!   [`map::serde_seq`] module, for rejecting duplicate keys or values in
+//!   the [`map::serde_strict`] and [`set::serde_strict`] modules, and for
+//!   canonical sorted output in the [`map::serde_sorted`] and
+//!   [`set::serde_sorted`] modules.
+//! * `serde_with`: Adds implementations of [`SerializeAs`] and [`DeserializeAs`]
+//!   for `serde_with`'s `serde_as`, so that [`OrderMap`] and [`OrderSet`] can be
+//!   used with adapters like `OrderMap<DisplayFromStr, _>`, `Seq<(_, _)>`, and
+//!   `MapPreventDuplicates<_, _>`. The [`map::SeqPreventDuplicates`] adapter
+//!   combines the last two. This enables the `serde` feature.
+//! * `schemars`: Adds implementations for [`JsonSchema`] to [`OrderMap`] and
+//!   [`OrderSet`], as an object and an array with unique items respectively.
+//!   With the `serde` feature, [`map::serde_seq::json_schema`] describes the
+//!   ordered sequence format.
+//! * `bincode`: Adds implementations for [`Encode`], [`Decode`], and
+//!   [`BorrowDecode`] from `bincode` 2 to [`OrderMap`] and [`OrderSet`],
+//!   which encode the entries in order.
+//! * `rkyv`: Adds implementations for [`rkyv::Archive`], `Serialize`, and
+//!   `Deserialize` to [`OrderMap`] and [`OrderSet`], archived as
+//!   [`map::ArchivedOrderMap`] and [`set::ArchivedOrderSet`]. These keep the
+//!   order and can be read in place, with validation by `bytecheck`.
 //! * `borsh`: Adds implementations for [`BorshSerialize`] and [`BorshDeserialize`]
-//!   to [`OrderMap`] and [`OrderSet`].
+//!   to [`OrderMap`] and [`OrderSet`]. The [`borsh::Strict`] wrapper rejects
+//!   duplicate keys or values when deserializing.
+//! * `value`: Adds the [`value::Value`] type for self-describing documents,
+//!   whose objects are [`OrderMap`]s, with the [`value!`] macro to build them.
+//!   This enables the `serde` feature.
 //! * `arbitrary`: Adds implementations for the [`arbitrary::Arbitrary`] trait
 //!   to [`OrderMap`] and [`OrderSet`].
 //! * `quickcheck`: Adds implementations for the [`quickcheck::Arbitrary`] trait
@@ -45,6 +68,13 @@
 //! [`no_std`]: #no-standard-library-targets
 //! [`Serialize`]: `::serde_core::Serialize`
 //! [`Deserialize`]: `::serde_core::Deserialize`
+//! [`SerializeAs`]: `::serde_with::SerializeAs`
+//! [`DeserializeAs`]: `::serde_with::DeserializeAs`
+//! [`JsonSchema`]: `::schemars::JsonSchema`
+//! [`Encode`]: `::bincode::Encode`
+//! [`Decode`]: `::bincode::Decode`
+//! [`BorrowDecode`]: `::bincode::BorrowDecode`
+//! [`rkyv::Archive`]: `::rkyv::Archive`
 //! [`BorshSerialize`]: `::borsh::BorshSerialize`
 //! [`BorshDeserialize`]: `::borsh::BorshDeserialize`
 //! [`arbitrary::Arbitrary`]: `::arbitrary::Arbitrary`
@@ -102,18 +132,244 @@ extern crate alloc;
 extern crate std;
 
 mod arbitrary;
+#[cfg(feature = "bincode")]
+mod bincode;
 #[macro_use]
 mod macros;
 #[cfg(feature = "borsh")]
-mod borsh;
+pub mod borsh;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "schemars")]
+mod schemars;
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(feature = "serde_with")]
+mod serde_with;
 #[cfg(feature = "sval")]
 mod sval;
+mod util;
 
 pub mod map;
 pub mod set;
+#[cfg(feature = "value")]
+#[cfg_attr(docsrs, doc(cfg(feature = "value")))]
+pub mod value;
 
 pub use crate::map::OrderMap;
 pub use crate::set::OrderSet;
 pub use indexmap::{Equivalent, GetDisjointMutError, TryReserveError};
+
+use core::fmt;
+
+/// The error type for checked positional methods, like
+/// [`OrderMap::try_move_index`] and [`OrderSet::try_move_index`].
+///
+/// It reports the index that was out of bounds and the length it was checked against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IndexOutOfBounds {
+    /// The index that was out of bounds.
+    pub index: usize,
+    /// The length of the map or set, or the end of the range for a range start.
+    pub len: usize,
+}
+
+impl fmt::Display for IndexOutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "index out of bounds: the len is {len} but the index is {index}",
+            len = self.len,
+            index = self.index,
+        )
+    }
+}
+
+impl core::error::Error for IndexOutOfBounds {}
+
+/// The error type for checked insertion methods, like
+/// [`OrderMap::try_shift_insert`] and [`OrderSet::try_shift_insert`].
+///
+/// It reports the index that was out of bounds, and gives back the item that
+/// was not inserted, so the caller can retry without cloning it first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InsertOutOfBounds<T> {
+    /// The index that was out of bounds and the length it was checked against.
+    pub error: IndexOutOfBounds,
+    /// The item that was not inserted: a key-value pair for a map, or a value for a set.
+    pub item: T,
+}
+
+impl<T> fmt::Display for InsertOutOfBounds<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for InsertOutOfBounds<T> {}
+
+impl<T> From<InsertOutOfBounds<T>> for IndexOutOfBounds {
+    fn from(error: InsertOutOfBounds<T>) -> Self {
+        error.error
+    }
+}
+
+/// The error type for key-relative positioning methods, like
+/// [`OrderMap::move_before`] and [`OrderSet::move_after`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelativePositionError {
+    /// The key or value to be moved was not found.
+    NotFound,
+    /// The anchor to position relative to was not found.
+    AnchorNotFound,
+}
+
+impl fmt::Display for RelativePositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            RelativePositionError::NotFound => "the item to be moved was not found",
+            RelativePositionError::AnchorNotFound => "the anchor was not found",
+        };
+        fmt::Display::fmt(msg, f)
+    }
+}
+
+impl core::error::Error for RelativePositionError {}
+
+/// The error type for key-relative insertion methods, like
+/// [`OrderMap::insert_after`] and [`OrderSet::insert_after`].
+///
+/// The anchor to insert next to was not found. This gives back the item that
+/// was not inserted, and converts into [`RelativePositionError::AnchorNotFound`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnchorNotFound<T> {
+    /// The item that was not inserted: a key-value pair for a map, or a value for a set.
+    pub item: T,
+}
+
+impl<T> fmt::Display for AnchorNotFound<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&RelativePositionError::AnchorNotFound, f)
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for AnchorNotFound<T> {}
+
+impl<T> From<AnchorNotFound<T>> for RelativePositionError {
+    fn from(_: AnchorNotFound<T>) -> Self {
+        RelativePositionError::AnchorNotFound
+    }
+}
+
+/// The error type for [`OrderMap::apply_permutation`] and [`OrderSet::apply_permutation`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PermutationError {
+    /// The permutation's length does not match the length of the map or set.
+    LengthMismatch {
+        /// The length of the map or set.
+        len: usize,
+        /// The length of the permutation.
+        perm_len: usize,
+    },
+    /// An index in the permutation is out of bounds.
+    IndexOutOfBounds {
+        /// The index that was out of bounds.
+        index: usize,
+        /// The length of the map or set.
+        len: usize,
+    },
+    /// An index appears more than once in the permutation.
+    DuplicateIndex {
+        /// The index that was repeated.
+        index: usize,
+    },
+}
+
+impl fmt::Display for PermutationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PermutationError::LengthMismatch { len, perm_len } => write!(
+                f,
+                "permutation length mismatch: the len is {len} but the permutation has {perm_len}"
+            ),
+            PermutationError::IndexOutOfBounds { index, len } => write!(
+                f,
+                "permutation index out of bounds: the len is {len} but the index is {index}"
+            ),
+            PermutationError::DuplicateIndex { index } => {
+                write!(f, "permutation index {index} appears more than once")
+            }
+        }
+    }
+}
+
+impl core::error::Error for PermutationError {}
+
+/// The error type for [`OrderMap::remove_indices`] and [`OrderSet::remove_indices`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RemoveIndicesError {
+    /// An index is out of bounds.
+    IndexOutOfBounds {
+        /// The index that was out of bounds.
+        index: usize,
+        /// The length of the map or set.
+        len: usize,
+    },
+    /// An index appears more than once.
+    DuplicateIndex {
+        /// The index that was repeated.
+        index: usize,
+    },
+}
+
+impl fmt::Display for RemoveIndicesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            RemoveIndicesError::IndexOutOfBounds { index, len } => write!(
+                f,
+                "removal index out of bounds: the len is {len} but the index is {index}"
+            ),
+            RemoveIndicesError::DuplicateIndex { index } => {
+                write!(f, "removal index {index} appears more than once")
+            }
+        }
+    }
+}
+
+impl core::error::Error for RemoveIndicesError {}
+
+/// The error type for [`OrderMap::sort_topologically`] and
+/// [`OrderSet::sort_topologically`], and their `topological_order` variants.
+///
+/// The dependencies could not be ordered because some items depend on each
+/// other in a cycle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CycleError<T> {
+    /// The items of one dependency cycle: each depends on the next, and the
+    /// last depends on the first. An item that depends on itself makes a
+    /// cycle of length one.
+    pub cycle: alloc::vec::Vec<T>,
+}
+
+impl<T: fmt::Debug> fmt::Display for CycleError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "dependency cycle between {:?}", self.cycle)
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for CycleError<T> {}
+
+/// Where [`OrderMap::sort_by_reference`] and [`OrderSet::sort_by_reference`]
+/// place the items that are absent from the reference.
+///
+/// In every case, the absent items keep their relative order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ReorderPolicy {
+    /// Absent items are moved before all items found in the reference.
+    AbsentFirst,
+    /// Absent items are moved after all items found in the reference.
+    AbsentLast,
+    /// Absent items keep their current indices, and the items found in the
+    /// reference are reordered among the remaining indices.
+    AbsentInPlace,
+}
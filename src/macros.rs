@@ -145,3 +145,86 @@ macro_rules! orderset {
         }
     };
 }
+
+#[cfg(feature = "value")]
+#[cfg_attr(docsrs, doc(cfg(feature = "value")))]
+#[macro_export]
+/// Create a [`Value`][crate::value::Value] from a JSON-like literal
+///
+/// Objects are written as `{ key: value, ... }`, where each key is a single
+/// token that converts `Into<String>`, like a string literal or a variable.
+/// Arrays are written as `[value, ...]`, and `null` is the null value. Any
+/// other value is an expression converted with [`Value::from`], so
+/// interpolated variables and computations work too.
+///
+/// Object fields keep the order in which they are written.
+///
+/// [`Value::from`]: crate::value::Value#trait-implementations
+///
+/// ## Example
+///
+/// ```
+/// use ordermap::value;
+/// use ordermap::value::Value;
+///
+/// let port = 8080;
+/// let config: Value = value!({
+///     "name": "web",
+///     "listen": [port, port + 1],
+///     "tls": null,
+///     "debug": false,
+/// });
+/// assert_eq!(config.get_path("/listen/1"), Some(&Value::from(8081)));
+/// assert!(config.as_object().unwrap().keys().eq(["name", "listen", "tls", "debug"]));
+/// ```
+macro_rules! value {
+    // Collect array elements, each of which is the tokens up to the next comma.
+    (@array [$($elems:expr,)*]) => {
+        $crate::value::Value::Array(::core::iter::Iterator::collect(
+            ::core::iter::IntoIterator::into_iter([$($elems,)*]),
+        ))
+    };
+    (@array [$($elems:expr,)*] $($rest:tt)+) => {
+        $crate::value!(@element [$($elems,)*] () $($rest)+)
+    };
+    (@element [$($elems:expr,)*] ($($elem:tt)+) , $($rest:tt)*) => {
+        $crate::value!(@array [$($elems,)* $crate::value!($($elem)+),] $($rest)*)
+    };
+    (@element [$($elems:expr,)*] ($($elem:tt)+)) => {
+        $crate::value!(@array [$($elems,)* $crate::value!($($elem)+),])
+    };
+    (@element [$($elems:expr,)*] ($($elem:tt)*) $next:tt $($rest:tt)*) => {
+        $crate::value!(@element [$($elems,)*] ($($elem)* $next) $($rest)*)
+    };
+
+    // Collect object fields, each of which is a key token, a colon, and the
+    // tokens up to the next comma.
+    (@object [$($fields:expr,)*]) => {
+        $crate::value::Value::Object(::core::iter::Iterator::collect(
+            ::core::iter::IntoIterator::into_iter([$($fields,)*]),
+        ))
+    };
+    (@object [$($fields:expr,)*] $key:tt : $($rest:tt)+) => {
+        $crate::value!(@field [$($fields,)*] $key () $($rest)+)
+    };
+    (@field [$($fields:expr,)*] $key:tt ($($value:tt)+) , $($rest:tt)*) => {
+        $crate::value!(@object [
+            $($fields,)*
+            (::core::convert::Into::into($key), $crate::value!($($value)+)),
+        ] $($rest)*)
+    };
+    (@field [$($fields:expr,)*] $key:tt ($($value:tt)+)) => {
+        $crate::value!(@object [
+            $($fields,)*
+            (::core::convert::Into::into($key), $crate::value!($($value)+)),
+        ])
+    };
+    (@field [$($fields:expr,)*] $key:tt ($($value:tt)*) $next:tt $($rest:tt)*) => {
+        $crate::value!(@field [$($fields,)*] $key ($($value)* $next) $($rest)*)
+    };
+
+    (null) => { $crate::value::Value::Null };
+    ([$($tt:tt)*]) => { $crate::value!(@array [] $($tt)*) };
+    ({$($tt:tt)*}) => { $crate::value!(@object [] $($tt)*) };
+    ($other:expr) => { $crate::value::Value::from($other) };
+}
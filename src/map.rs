@@ -9,24 +9,58 @@
 //! Methods like [`OrderMap::remove`] use `IndexMap`'s "shift" semantics, so
 //! they preserve the relative order of remaining entries.
 
+mod cursor;
 mod entry;
 mod iter;
 mod mutable;
 mod slice;
+mod sub_map;
 
+pub mod handle;
+pub mod rank;
 pub mod raw_entry_v1;
+pub mod tombstone;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde_merge;
 
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 pub mod serde_seq;
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde_sorted;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde_strict;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use crate::serde::OrderMapSeed;
+
+#[cfg(feature = "serde_with")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_with")))]
+pub use crate::serde_with::SeqPreventDuplicates;
+
+#[cfg(feature = "rkyv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+pub use crate::rkyv::{ArchivedIter, ArchivedKeys, ArchivedOrderMap, ArchivedValues};
+
 #[cfg(test)]
 mod tests;
 
+pub use self::cursor::CursorMut;
 pub use self::entry::{Entry, IndexedEntry, OccupiedEntry, VacantEntry};
+pub use self::handle::{EntryHandle, HandleMap};
 pub use self::mutable::MutableEntryKey;
 pub use self::mutable::MutableKeys;
+pub use self::rank::RankMap;
 pub use self::raw_entry_v1::RawEntryApiV1;
+pub use self::sub_map::SubMap;
+pub use self::tombstone::TombstoneMap;
 pub use indexmap::map::{
     Drain, ExtractIf, IntoIter, IntoKeys, IntoValues, Iter, IterMut, IterMut2, Keys, Slice, Splice,
     Values, ValuesMut,
@@ -40,16 +74,21 @@ use alloc::boxed::Box;
 use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{BuildHasher, Hash, Hasher};
-use core::ops::{Index, IndexMut, RangeBounds};
+use core::ops::{Index, IndexMut, Range, RangeBounds};
 use indexmap::IndexMap;
 
-#[cfg(doc)]
 use alloc::vec::Vec;
 
 #[cfg(feature = "std")]
 use std::hash::RandomState;
 
-use crate::{Equivalent, GetDisjointMutError, TryReserveError};
+use crate::OrderSet;
+use crate::util::{self, check_index_le, check_index_lt, try_simplify_range};
+use crate::{
+    AnchorNotFound, CycleError, Equivalent, GetDisjointMutError, IndexOutOfBounds,
+    InsertOutOfBounds, PermutationError, RelativePositionError, RemoveIndicesError, ReorderPolicy,
+    TryReserveError,
+};
 
 /// A hash table where the iteration order of the key-value pairs is independent
 /// of the hash values of the keys.
@@ -282,6 +321,35 @@ impl<K, V, S> OrderMap<K, V, S> {
         self.inner.drain(range)
     }
 
+    /// Clears the `OrderMap` in the given index range, returning those
+    /// key-value pairs as a drain iterator, or an error if the range is invalid.
+    ///
+    /// This is a checked version of [`drain`][Self::drain], returning
+    /// [`IndexOutOfBounds`] if the starting point is greater than the end point
+    /// or if the end point is greater than the length of the map. The map is
+    /// unchanged in the error case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{IndexOutOfBounds, OrderMap};
+    ///
+    /// let mut map: OrderMap<i32, char> = (0..5).zip('a'..).collect();
+    /// let err = map.try_drain(3..7).unwrap_err();
+    /// assert_eq!(err, IndexOutOfBounds { index: 7, len: 5 });
+    ///
+    /// let drained: Vec<_> = map.try_drain(3..).unwrap().collect();
+    /// assert_eq!(drained, [(3, 'd'), (4, 'e')]);
+    /// assert_eq!(map.len(), 3);
+    /// ```
+    pub fn try_drain<R>(&mut self, range: R) -> Result<Drain<'_, K, V>, IndexOutOfBounds>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = try_simplify_range(range, self.len())?;
+        Ok(self.inner.drain(range))
+    }
+
     /// Creates an iterator which uses a closure to determine if an element should be removed,
     /// for all elements in the given range.
     ///
@@ -348,6 +416,63 @@ impl<K, V, S> OrderMap<K, V, S> {
         }
     }
 
+    /// Splits the collection into two at the given index, or returns an error
+    /// if `at > len`.
+    ///
+    /// This is a checked version of [`split_off`][Self::split_off].
+    /// The map is unchanged in the error case.
+    pub fn try_split_off(&mut self, at: usize) -> Result<Self, IndexOutOfBounds>
+    where
+        S: Clone,
+    {
+        check_index_le(at, self.len())?;
+        Ok(self.split_off(at))
+    }
+
+    /// Splits the collection into chunks of `size` elements each, in order,
+    /// except for the last chunk, which may be shorter.
+    ///
+    /// The elements are moved into the chunks, and each chunk gets a clone of
+    /// the hasher. The first chunk reuses the collection itself, shrunk to fit,
+    /// so it doesn't keep the whole original allocation. An empty collection
+    /// produces no chunks.
+    ///
+    /// ***Panics*** if `size` is zero.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    ///
+    /// let map: OrderMap<i32, char> = (0..5).zip('a'..).collect();
+    /// let chunks = map.chunk_into(2);
+    /// assert_eq!(chunks.len(), 3);
+    /// assert!(chunks[0].keys().eq(&[0, 1]));
+    /// assert!(chunks[1].keys().eq(&[2, 3]));
+    /// assert!(chunks[2].keys().eq(&[4]));
+    /// ```
+    #[track_caller]
+    pub fn chunk_into(mut self, size: usize) -> Vec<Self>
+    where
+        S: Clone,
+    {
+        assert!(size != 0, "chunk size must be non-zero");
+        let mut chunks = Vec::with_capacity(self.len().div_ceil(size));
+        while self.len() > size {
+            // Split off the last chunk, so that no entry is moved twice.
+            let at = (self.len() - 1) / size * size;
+            chunks.push(self.split_off(at));
+        }
+        if !self.is_empty() {
+            self.shrink_to_fit();
+            chunks.push(self);
+        }
+        chunks.reverse();
+        chunks
+    }
+
     /// Reserve capacity for `additional` more key-value pairs.
     ///
     /// Computes in **O(n)** time.
@@ -563,6 +688,111 @@ where
         self.inner.insert_before(index, key, value)
     }
 
+    /// Insert a key-value pair in the map before the entry at the given index, or at the end,
+    /// or returns an error if `index` is out of bounds.
+    ///
+    /// This is a checked version of [`insert_before`][Self::insert_before].
+    /// Valid indices are `0..=map.len()` (inclusive).
+    /// The map is unchanged in the error case, and the error gives back the
+    /// key-value pair.
+    ///
+    /// Computes in **O(n)** time (average).
+    #[allow(clippy::type_complexity)]
+    pub fn try_insert_before(
+        &mut self,
+        index: usize,
+        key: K,
+        value: V,
+    ) -> Result<(usize, Option<V>), InsertOutOfBounds<(K, V)>> {
+        match check_index_le(index, self.len()) {
+            Ok(()) => Ok(self.inner.insert_before(index, key, value)),
+            Err(error) => Err(InsertOutOfBounds {
+                error,
+                item: (key, value),
+            }),
+        }
+    }
+
+    /// Insert a key-value pair in the map just before the entry for `anchor`.
+    ///
+    /// This is equivalent to finding the index of `anchor` with
+    /// [`get_index_of`][Self::get_index_of], then calling
+    /// [`insert_before`][Self::insert_before] with that index, so an existing
+    /// key is moved next to the anchor and its value is updated.
+    ///
+    /// Returns the new index and the old value, if any, or an [`AnchorNotFound`]
+    /// error holding the key-value pair if `anchor` is not in the map. The map
+    /// is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{AnchorNotFound, OrderMap};
+    /// let mut map = OrderMap::from([('a', 1), ('b', 2), ('c', 3)]);
+    ///
+    /// assert_eq!(map.insert_before_key(&'b', 'x', 0), Ok((1, None)));
+    /// assert_eq!(map.insert_before_key(&'b', 'c', 4), Ok((2, Some(3))));
+    /// assert_eq!(map.insert_before_key(&'z', 'y', 5), Err(AnchorNotFound { item: ('y', 5) }));
+    /// assert!(map.into_iter().eq([('a', 1), ('x', 0), ('c', 4), ('b', 2)]));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn insert_before_key<Q>(
+        &mut self,
+        anchor: &Q,
+        key: K,
+        value: V,
+    ) -> Result<(usize, Option<V>), AnchorNotFound<(K, V)>>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        match self.get_index_of(anchor) {
+            Some(index) => Ok(self.inner.insert_before(index, key, value)),
+            None => Err(AnchorNotFound { item: (key, value) }),
+        }
+    }
+
+    /// Insert a key-value pair in the map just after the entry for `anchor`.
+    ///
+    /// This is equivalent to finding the index of `anchor` with
+    /// [`get_index_of`][Self::get_index_of], then calling
+    /// [`insert_before`][Self::insert_before] with the following index, so an
+    /// existing key is moved next to the anchor and its value is updated.
+    ///
+    /// Returns the new index and the old value, if any, or an [`AnchorNotFound`]
+    /// error holding the key-value pair if `anchor` is not in the map. The map
+    /// is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{AnchorNotFound, OrderMap};
+    /// let mut map = OrderMap::from([('a', 1), ('b', 2), ('c', 3)]);
+    ///
+    /// assert_eq!(map.insert_after(&'b', 'x', 0), Ok((2, None)));
+    /// assert_eq!(map.insert_after(&'b', 'a', 4), Ok((1, Some(1))));
+    /// assert_eq!(map.insert_after(&'z', 'y', 5), Err(AnchorNotFound { item: ('y', 5) }));
+    /// assert!(map.into_iter().eq([('b', 2), ('a', 4), ('x', 0), ('c', 3)]));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn insert_after<Q>(
+        &mut self,
+        anchor: &Q,
+        key: K,
+        value: V,
+    ) -> Result<(usize, Option<V>), AnchorNotFound<(K, V)>>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        match self.get_index_of(anchor) {
+            Some(index) => Ok(self.inner.insert_before(index + 1, key, value)),
+            None => Err(AnchorNotFound { item: (key, value) }),
+        }
+    }
+
     /// Insert a key-value pair in the map at the given index.
     ///
     /// If an equivalent key already exists in the map: the key remains and
@@ -625,6 +855,50 @@ where
         self.inner.shift_insert(index, key, value)
     }
 
+    /// Insert a key-value pair in the map at the given index, or returns an
+    /// error if `index` is out of bounds.
+    ///
+    /// This is a checked version of [`shift_insert`][Self::shift_insert].
+    /// Valid indices are `0..map.len()` (exclusive) when moving an existing entry, or
+    /// `0..=map.len()` (inclusive) when inserting a new key.
+    /// The map is unchanged in the error case, and the error gives back the
+    /// key-value pair.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{IndexOutOfBounds, OrderMap};
+    /// let mut map: OrderMap<char, ()> = ('a'..='z').map(|c| (c, ())).collect();
+    ///
+    /// // Existing keys can't move to the endpoint, but new keys can insert there.
+    /// let err = map.try_shift_insert(map.len(), 'a', ()).unwrap_err();
+    /// assert_eq!(err.error, IndexOutOfBounds { index: 26, len: 26 });
+    /// assert_eq!(err.item, ('a', ()));
+    /// assert_eq!(map.try_shift_insert(map.len(), '*', ()), Ok(None));
+    /// assert_eq!(map.get_index_of(&'*'), Some(26));
+    /// ```
+    pub fn try_shift_insert(
+        &mut self,
+        index: usize,
+        key: K,
+        value: V,
+    ) -> Result<Option<V>, InsertOutOfBounds<(K, V)>> {
+        let len = self.len();
+        let checked = match self.inner.get_index_of(&key) {
+            Some(_) => check_index_lt(index, len),
+            None => check_index_le(index, len),
+        };
+        match checked {
+            Ok(()) => Ok(self.inner.shift_insert(index, key, value)),
+            Err(error) => Err(InsertOutOfBounds {
+                error,
+                item: (key, value),
+            }),
+        }
+    }
+
     /// Replaces the key at the given index. The new key does not need to be
     /// equivalent to the one it is replacing, but it must be unique to the rest
     /// of the map.
@@ -650,7 +924,8 @@ where
     ///
     /// Computes in **O(1)** time (amortized average).
     pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
-        Entry::new(self.inner.entry(key))
+        let len = self.len();
+        Entry::new(self.inner.entry(key), len)
     }
 
     /// Creates a splicing iterator that replaces the specified range in the map
@@ -692,6 +967,27 @@ where
         self.inner.splice(range, replace_with)
     }
 
+    /// Creates a splicing iterator that replaces the specified range in the map
+    /// with the given `replace_with` key-value iterator and yields the removed
+    /// items, or returns an error if the range is invalid.
+    ///
+    /// This is a checked version of [`splice`][Self::splice], returning
+    /// [`IndexOutOfBounds`] if the starting point is greater than the end point
+    /// or if the end point is greater than the length of the map. The map is
+    /// unchanged in the error case.
+    pub fn try_splice<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Result<Splice<'_, I::IntoIter, K, V, S>, IndexOutOfBounds>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let range = try_simplify_range(range, self.len())?;
+        Ok(self.inner.splice(range, replace_with))
+    }
+
     /// Moves all key-value pairs from `other` into `self`, leaving `other` empty.
     ///
     /// This is equivalent to calling [`insert`][Self::insert] for each
@@ -779,6 +1075,153 @@ where
         self.inner.get_index_of(key)
     }
 
+    /// Get a cursor pointing at the entry for `key`, if it is present, else
+    /// `None`.
+    ///
+    /// See [`CursorMut`] for how to move the cursor and edit the map with it.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn cursor_at_key<Q>(&mut self, key: &Q) -> Option<CursorMut<'_, K, V, S>>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let index = self.get_index_of(key)?;
+        Some(CursorMut::new(self, index))
+    }
+
+    /// Returns a slice of the key-value pairs from the entry for `start` up
+    /// to, but not including, the entry for `end`, in their current order.
+    ///
+    /// Returns `None` if either key is not in the map, or if `end` comes
+    /// before `start`. The slice is empty if they are the same key.
+    ///
+    /// Computes in **O(1)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    ///
+    /// let map: OrderMap<char, u32> = ('a'..='f').zip(0..).collect();
+    /// assert!(map.range_between(&'b', &'e').unwrap().keys().eq(&['b', 'c', 'd']));
+    /// assert!(map.range_between_inclusive(&'b', &'e').unwrap().keys().eq(&['b', 'c', 'd', 'e']));
+    /// assert!(map.range_from_key(&'e').unwrap().keys().eq(&['e', 'f']));
+    /// assert!(map.range_to_key(&'b').unwrap().keys().eq(&['a']));
+    /// assert_eq!(map.range_between(&'e', &'b'), None);
+    /// assert_eq!(map.range_between(&'b', &'z'), None);
+    /// ```
+    pub fn range_between<Q1, Q2>(&self, start: &Q1, end: &Q2) -> Option<&Slice<K, V>>
+    where
+        Q1: ?Sized + Hash + Equivalent<K>,
+        Q2: ?Sized + Hash + Equivalent<K>,
+    {
+        let start = self.get_index_of(start)?;
+        let end = self.get_index_of(end)?;
+        self.get_range(start..end)
+    }
+
+    /// Returns a slice of the key-value pairs from the entry for `start` up
+    /// to and including the entry for `end`, in their current order.
+    ///
+    /// Returns `None` if either key is not in the map, or if `end` comes
+    /// before `start`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn range_between_inclusive<Q1, Q2>(&self, start: &Q1, end: &Q2) -> Option<&Slice<K, V>>
+    where
+        Q1: ?Sized + Hash + Equivalent<K>,
+        Q2: ?Sized + Hash + Equivalent<K>,
+    {
+        let start = self.get_index_of(start)?;
+        let end = self.get_index_of(end)?;
+        if end < start {
+            return None;
+        }
+        self.get_range(start..=end)
+    }
+
+    /// Returns a slice of the key-value pairs from the entry for `start` to
+    /// the end of the map, or `None` if `start` is not in the map.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn range_from_key<Q>(&self, start: &Q) -> Option<&Slice<K, V>>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let start = self.get_index_of(start)?;
+        Some(&self.as_slice()[start..])
+    }
+
+    /// Returns a slice of the key-value pairs from the start of the map up
+    /// to, but not including, the entry for `end`, or `None` if `end` is not
+    /// in the map.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn range_to_key<Q>(&self, end: &Q) -> Option<&Slice<K, V>>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let end = self.get_index_of(end)?;
+        Some(&self.as_slice()[..end])
+    }
+
+    /// Splits the map into two at the position of `key`, or returns `None`
+    /// if `key` is not in the map.
+    ///
+    /// Returns a newly allocated map containing the entry for `key` and all
+    /// that follow it. After the call, the original map will be left
+    /// containing the entries before it, with its previous capacity unchanged.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    ///
+    /// let mut map: OrderMap<char, i32> = ('a'..='e').zip(0..).collect();
+    /// let tail = map.split_by_key(&'c').unwrap();
+    /// assert!(map.keys().eq(&['a', 'b']));
+    /// assert!(tail.keys().eq(&['c', 'd', 'e']));
+    /// assert!(map.split_by_key(&'z').is_none());
+    /// ```
+    pub fn split_by_key<Q>(&mut self, key: &Q) -> Option<Self>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+        S: Clone,
+    {
+        let at = self.get_index_of(key)?;
+        Some(self.split_off(at))
+    }
+
+    /// Splits the map into the entries for which `pred` returns `true`, and
+    /// those for which it returns `false`, both in their original order.
+    ///
+    /// The entries are moved, and the second map gets a clone of the hasher.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    ///
+    /// let map: OrderMap<i32, char> = (0..6).zip('a'..).collect();
+    /// let (even, odd) = map.partition(|key, _| key % 2 == 0);
+    /// assert!(even.into_iter().eq([(0, 'a'), (2, 'c'), (4, 'e')]));
+    /// assert!(odd.into_iter().eq([(1, 'b'), (3, 'd'), (5, 'f')]));
+    /// ```
+    pub fn partition<F>(mut self, mut pred: F) -> (Self, Self)
+    where
+        K: Hash + Eq,
+        F: FnMut(&K, &V) -> bool,
+        S: Clone,
+    {
+        let mut rejected = Self::with_hasher(self.hasher().clone());
+        rejected.extend(self.inner.extract_if(.., |key, value| !pred(key, value)));
+        (self, rejected)
+    }
+
     /// Return a mutable reference to the stored value for `key`,
     /// if it is present, else `None`.
     ///
@@ -931,63 +1374,337 @@ where
     {
         self.inner.swap_remove_full(key)
     }
-}
-
-impl<K, V, S> OrderMap<K, V, S> {
-    /// Remove the last key-value pair
-    ///
-    /// This preserves the order of the remaining elements.
-    ///
-    /// Computes in **O(1)** time (average).
-    #[doc(alias = "pop_last")] // like `BTreeMap`
-    pub fn pop(&mut self) -> Option<(K, V)> {
-        self.inner.pop()
-    }
 
-    /// Removes and returns the last key-value pair from a map if the predicate
-    /// returns `true`, or [`None`] if the predicate returns false or the map
-    /// is empty (the predicate will not be called in that case).
+    /// Remove the key-value pairs equivalent to any of the given `keys` in a
+    /// single pass, and return them in their original order.
     ///
-    /// This preserves the order of the remaining elements.
+    /// Keys that are not in the map, or that are given more than once, are
+    /// ignored. The remaining pairs keep their relative order.
     ///
-    /// Computes in **O(1)** time (average).
+    /// Computes in **O(n + k)** time (average), for `k` keys.
     ///
     /// # Examples
     ///
     /// ```
     /// use ordermap::OrderMap;
     ///
-    /// let init = [(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')];
-    /// let mut map = OrderMap::from(init);
-    /// let pred = |key: &i32, _value: &mut char| *key % 2 == 0;
-    ///
-    /// assert_eq!(map.pop_if(pred), Some((4, 'd')));
-    /// assert_eq!(map.as_slice(), &init[..3]);
-    /// assert_eq!(map.pop_if(pred), None);
+    /// let mut map: OrderMap<char, usize> = ('a'..='f').zip(0..).collect();
+    /// let removed = map.remove_keys(&['e', 'b', 'z', 'b']);
+    /// assert_eq!(removed, [('b', 1), ('e', 4)]);
+    /// assert!(map.keys().eq(&['a', 'c', 'd', 'f']));
     /// ```
-    pub fn pop_if(&mut self, predicate: impl FnOnce(&K, &mut V) -> bool) -> Option<(K, V)> {
-        self.inner.pop_if(predicate)
+    pub fn remove_keys<'q, Q, I>(&mut self, keys: I) -> Vec<(K, V)>
+    where
+        Q: ?Sized + 'q + Hash + Equivalent<K>,
+        I: IntoIterator<Item = &'q Q>,
+    {
+        let mut remove = alloc::vec![false; self.len()];
+        for key in keys {
+            if let Some(index) = self.get_index_of(key) {
+                remove[index] = true;
+            }
+        }
+        self.remove_marked(&remove)
     }
 
-    /// Scan through each key-value pair in the map and keep those where the
-    /// closure `keep` returns `true`.
+    /// Moves the entry for `key` to the position just before the entry for
+    /// `anchor`, shifting all other entries in-between.
     ///
-    /// The elements are visited in order, and remaining elements keep their
-    /// order.
+    /// Returns the new index of the moved entry, or an error if either `key`
+    /// or `anchor` is not in the map. The map is unchanged in the error case.
     ///
     /// Computes in **O(n)** time (average).
-    pub fn retain<F>(&mut self, keep: F)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{OrderMap, RelativePositionError};
+    /// let mut map: OrderMap<char, ()> = ('a'..='e').map(|c| (c, ())).collect();
+    ///
+    /// assert_eq!(map.move_before(&'a', &'d'), Ok(2));
+    /// assert!(map.keys().eq(&['b', 'c', 'a', 'd', 'e']));
+    /// assert_eq!(map.move_before(&'e', &'b'), Ok(0));
+    /// assert!(map.keys().eq(&['e', 'b', 'c', 'a', 'd']));
+    ///
+    /// assert_eq!(map.move_before(&'z', &'b'), Err(RelativePositionError::NotFound));
+    /// assert_eq!(map.move_before(&'b', &'z'), Err(RelativePositionError::AnchorNotFound));
+    /// ```
+    pub fn move_before<Q1, Q2>(
+        &mut self,
+        key: &Q1,
+        anchor: &Q2,
+    ) -> Result<usize, RelativePositionError>
     where
-        F: FnMut(&K, &mut V) -> bool,
+        Q1: ?Sized + Hash + Equivalent<K>,
+        Q2: ?Sized + Hash + Equivalent<K>,
     {
-        self.inner.retain(keep);
+        let (from, anchor) = self.relative_indices(key, anchor)?;
+        let to = if from < anchor { anchor - 1 } else { anchor };
+        self.inner.move_index(from, to);
+        Ok(to)
     }
 
-    /// Sort the map's key-value pairs by the default ordering of the keys.
+    /// Moves the entry for `key` to the position just after the entry for
+    /// `anchor`, shifting all other entries in-between.
     ///
-    /// This is a stable sort -- but equivalent keys should not normally coexist in
-    /// a map at all, so [`sort_unstable_keys`][Self::sort_unstable_keys] is preferred
-    /// because it is generally faster and doesn't allocate auxiliary memory.
+    /// Returns the new index of the moved entry, or an error if either `key`
+    /// or `anchor` is not in the map. The map is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    /// let mut map: OrderMap<char, ()> = ('a'..='e').map(|c| (c, ())).collect();
+    ///
+    /// assert_eq!(map.move_after(&'a', &'d'), Ok(3));
+    /// assert!(map.keys().eq(&['b', 'c', 'd', 'a', 'e']));
+    /// assert_eq!(map.move_after(&'e', &'b'), Ok(1));
+    /// assert!(map.keys().eq(&['b', 'e', 'c', 'd', 'a']));
+    /// ```
+    pub fn move_after<Q1, Q2>(
+        &mut self,
+        key: &Q1,
+        anchor: &Q2,
+    ) -> Result<usize, RelativePositionError>
+    where
+        Q1: ?Sized + Hash + Equivalent<K>,
+        Q2: ?Sized + Hash + Equivalent<K>,
+    {
+        let (from, anchor) = self.relative_indices(key, anchor)?;
+        let to = if from > anchor { anchor + 1 } else { anchor };
+        self.inner.move_index(from, to);
+        Ok(to)
+    }
+
+    /// Swaps the positions of the entries for keys `a` and `b`.
+    ///
+    /// Returns an error if either key is not in the map, treating `b` as the
+    /// anchor. The map is unchanged in the error case.
+    ///
+    /// Computes in **O(1)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    /// let mut map = OrderMap::from([('a', 1), ('b', 2), ('c', 3)]);
+    ///
+    /// assert_eq!(map.swap_keys(&'a', &'c'), Ok(()));
+    /// assert!(map.keys().eq(&['c', 'b', 'a']));
+    /// ```
+    pub fn swap_keys<Q1, Q2>(&mut self, a: &Q1, b: &Q2) -> Result<(), RelativePositionError>
+    where
+        Q1: ?Sized + Hash + Equivalent<K>,
+        Q2: ?Sized + Hash + Equivalent<K>,
+    {
+        let (a, b) = self.relative_indices(a, b)?;
+        self.inner.swap_indices(a, b);
+        Ok(())
+    }
+
+    /// Returns an order of the map's key-value pairs in which every key comes
+    /// after its dependencies, without modifying the map.
+    ///
+    /// The closure `deps` lists the keys that each entry depends on, which
+    /// may be borrowed from the map itself. Dependencies that are not in the
+    /// map are ignored. Wherever the dependencies allow it, entries keep their
+    /// current relative order, so the result is deterministic.
+    ///
+    /// The result is a permutation in the form taken by
+    /// [`apply_permutation`][Self::apply_permutation]: `indices[i]` is the
+    /// current index of the entry that goes to index `i`.
+    ///
+    /// Returns an error with the keys of one cycle if there is no such order.
+    ///
+    /// Computes in **O(n log n + e)** time (average), where *e* is the number
+    /// of dependencies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{CycleError, OrderMap};
+    ///
+    /// let mut targets = OrderMap::from([
+    ///     ("app", vec!["lib", "codegen"]),
+    ///     ("lib", vec!["codegen"]),
+    ///     ("docs", vec![]),
+    ///     ("codegen", vec![]),
+    /// ]);
+    /// let order = targets.topological_order(|_, deps| deps).unwrap();
+    /// assert_eq!(order, [2, 3, 1, 0]);
+    ///
+    /// targets["codegen"].push("app");
+    /// let err = targets.topological_order(|_, deps| deps).unwrap_err();
+    /// assert_eq!(err, CycleError { cycle: vec![&"app", &"lib", &"codegen"] });
+    /// ```
+    pub fn topological_order<'a, Q, I, F>(
+        &'a self,
+        mut deps: F,
+    ) -> Result<Vec<usize>, CycleError<&'a K>>
+    where
+        Q: 'a + ?Sized + Hash + Equivalent<K>,
+        I: IntoIterator<Item = &'a Q>,
+        F: FnMut(&'a K, &'a V) -> I,
+    {
+        let edges: Vec<Vec<usize>> = self
+            .iter()
+            .map(|(key, value)| {
+                (deps(key, value).into_iter())
+                    .filter_map(|dep| self.get_index_of(dep))
+                    .collect()
+            })
+            .collect();
+        util::topological_order(&edges).map_err(|cycle| self.cycle_error(cycle))
+    }
+
+    /// Sorts the map's key-value pairs in place so that every key comes after
+    /// its dependencies.
+    ///
+    /// The closure `deps` lists the keys that each entry depends on, as owned
+    /// values that are looked up by [`Equivalent`]. To borrow them from the
+    /// map instead, use [`topological_order`][Self::topological_order] and
+    /// then [`apply_permutation`][Self::apply_permutation].
+    ///
+    /// Dependencies that are not in the map are ignored. Wherever the
+    /// dependencies allow it, entries keep their current relative order, so
+    /// the sort is stable and deterministic.
+    ///
+    /// Returns an error with the keys of one cycle if there is no such order,
+    /// leaving the map unchanged.
+    ///
+    /// Computes in **O(n log n + e)** time (average), where *e* is the number
+    /// of dependencies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    ///
+    /// let mut migrations = OrderMap::from([
+    ///     (3, "add index"),
+    ///     (1, "create table"),
+    ///     (4, "backfill"),
+    ///     (2, "add column"),
+    /// ]);
+    /// let requires = |id: &i32| match id {
+    ///     2 => vec![1],
+    ///     3 => vec![1, 2],
+    ///     4 => vec![2],
+    ///     _ => vec![],
+    /// };
+    ///
+    /// migrations.sort_topologically(|id, _| requires(id)).unwrap();
+    /// assert!(migrations.keys().eq(&[1, 2, 3, 4]));
+    /// ```
+    pub fn sort_topologically<Q, I, F>(&mut self, mut deps: F) -> Result<(), CycleError<&K>>
+    where
+        Q: Hash + Equivalent<K>,
+        I: IntoIterator<Item = Q>,
+        F: FnMut(&K, &V) -> I,
+    {
+        let edges: Vec<Vec<usize>> = self
+            .iter()
+            .map(|(key, value)| {
+                (deps(key, value).into_iter())
+                    .filter_map(|dep| self.get_index_of(&dep))
+                    .collect()
+            })
+            .collect();
+        match util::topological_order(&edges) {
+            Ok(order) => {
+                self.apply_sorted_indices(&order);
+                Ok(())
+            }
+            Err(cycle) => Err(self.cycle_error(cycle)),
+        }
+    }
+
+    fn cycle_error(&self, cycle: Vec<usize>) -> CycleError<&K> {
+        let keys = self.as_slice();
+        CycleError {
+            cycle: cycle
+                .into_iter()
+                .map(|i| keys.get_index(i).unwrap().0)
+                .collect(),
+        }
+    }
+
+    fn relative_indices<Q1, Q2>(
+        &self,
+        key: &Q1,
+        anchor: &Q2,
+    ) -> Result<(usize, usize), RelativePositionError>
+    where
+        Q1: ?Sized + Hash + Equivalent<K>,
+        Q2: ?Sized + Hash + Equivalent<K>,
+    {
+        let index = self
+            .get_index_of(key)
+            .ok_or(RelativePositionError::NotFound)?;
+        let anchor = self
+            .get_index_of(anchor)
+            .ok_or(RelativePositionError::AnchorNotFound)?;
+        Ok((index, anchor))
+    }
+}
+
+impl<K, V, S> OrderMap<K, V, S> {
+    /// Remove the last key-value pair
+    ///
+    /// This preserves the order of the remaining elements.
+    ///
+    /// Computes in **O(1)** time (average).
+    #[doc(alias = "pop_last")] // like `BTreeMap`
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        self.inner.pop()
+    }
+
+    /// Removes and returns the last key-value pair from a map if the predicate
+    /// returns `true`, or [`None`] if the predicate returns false or the map
+    /// is empty (the predicate will not be called in that case).
+    ///
+    /// This preserves the order of the remaining elements.
+    ///
+    /// Computes in **O(1)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    ///
+    /// let init = [(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')];
+    /// let mut map = OrderMap::from(init);
+    /// let pred = |key: &i32, _value: &mut char| *key % 2 == 0;
+    ///
+    /// assert_eq!(map.pop_if(pred), Some((4, 'd')));
+    /// assert_eq!(map.as_slice(), &init[..3]);
+    /// assert_eq!(map.pop_if(pred), None);
+    /// ```
+    pub fn pop_if(&mut self, predicate: impl FnOnce(&K, &mut V) -> bool) -> Option<(K, V)> {
+        self.inner.pop_if(predicate)
+    }
+
+    /// Scan through each key-value pair in the map and keep those where the
+    /// closure `keep` returns `true`.
+    ///
+    /// The elements are visited in order, and remaining elements keep their
+    /// order.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn retain<F>(&mut self, keep: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.inner.retain(keep);
+    }
+
+    /// Sort the map's key-value pairs by the default ordering of the keys.
+    ///
+    /// This is a stable sort -- but equivalent keys should not normally coexist in
+    /// a map at all, so [`sort_unstable_keys`][Self::sort_unstable_keys] is preferred
+    /// because it is generally faster and doesn't allocate auxiliary memory.
     ///
     /// See [`sort_by`](Self::sort_by) for details.
     pub fn sort_keys(&mut self)
@@ -1101,6 +1818,162 @@ impl<K, V, S> OrderMap<K, V, S> {
         self.inner.sort_by_cached_key(sort_key);
     }
 
+    /// Returns the order that sorting with the comparison function `cmp`
+    /// would produce, without modifying the map.
+    ///
+    /// The result is a permutation in the form taken by
+    /// [`apply_permutation`][Self::apply_permutation]: `indices[i]` is the
+    /// current index of the key-value pair that sorts into index `i`. Gathering
+    /// any parallel array through it, e.g. `indices.iter().map(|&i| &array[i])`,
+    /// reorders that array the same way.
+    ///
+    /// Computes in **O(n log n)** time and **O(n)** space. The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    ///
+    /// let map = OrderMap::from([('c', 3), ('a', 1), ('b', 2)]);
+    /// let indices = map.sorted_indices_by(|k1, _, k2, _| k1.cmp(k2));
+    /// assert_eq!(indices, [1, 2, 0]);
+    /// assert!(map.keys().eq(&['c', 'a', 'b']));
+    /// ```
+    pub fn sorted_indices_by<F>(&self, mut cmp: F) -> Vec<usize>
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        let entries: Vec<(&K, &V)> = self.iter().collect();
+        let mut indices: Vec<usize> = (0..entries.len()).collect();
+        indices.sort_by(|&a, &b| {
+            let ((k1, v1), (k2, v2)) = (entries[a], entries[b]);
+            cmp(k1, v1, k2, v2)
+        });
+        indices
+    }
+
+    /// Sort the map's key-value pairs in place using the comparison function
+    /// `cmp`, and return the permutation that was applied.
+    ///
+    /// The returned permutation is the same as from
+    /// [`sorted_indices_by`][Self::sorted_indices_by]: `indices[i]` is the old
+    /// index of the key-value pair that is now at index `i`.
+    ///
+    /// Computes in **O(n log n)** time and **O(n)** space. The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    ///
+    /// let mut map = OrderMap::from([("c", 3), ("a", 1), ("b", 2)]);
+    /// let mut weights = vec![0.3, 0.1, 0.2];
+    ///
+    /// let indices = map.sort_by_with_permutation(|k1, _, k2, _| k1.cmp(k2));
+    /// weights = indices.iter().map(|&i| weights[i]).collect();
+    ///
+    /// assert!(map.keys().eq(&["a", "b", "c"]));
+    /// assert_eq!(weights, [0.1, 0.2, 0.3]);
+    /// ```
+    pub fn sort_by_with_permutation<F>(&mut self, cmp: F) -> Vec<usize>
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        let indices = self.sorted_indices_by(cmp);
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the map's key-value pairs in place using a sort-key extraction
+    /// function, and return the permutation that was applied.
+    ///
+    /// See [`sort_by_with_permutation`][Self::sort_by_with_permutation] for
+    /// the form of the permutation.
+    ///
+    /// Computes in **O(n log n)** time and **O(n)** space. The sort is stable.
+    pub fn sort_by_key_with_permutation<T, F>(&mut self, mut sort_key: F) -> Vec<usize>
+    where
+        T: Ord,
+        F: FnMut(&K, &V) -> T,
+    {
+        let entries: Vec<(&K, &V)> = self.iter().collect();
+        let mut indices: Vec<usize> = (0..entries.len()).collect();
+        indices.sort_by_key(|&i| sort_key(entries[i].0, entries[i].1));
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the map's key-value pairs in place using the comparison function
+    /// `cmp`, and return the permutation that was applied.
+    ///
+    /// See [`sort_by_with_permutation`][Self::sort_by_with_permutation] for
+    /// the form of the permutation.
+    ///
+    /// Computes in **O(n log n)** time and **O(n)** space. The sort is unstable.
+    pub fn sort_unstable_by_with_permutation<F>(&mut self, mut cmp: F) -> Vec<usize>
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        let entries: Vec<(&K, &V)> = self.iter().collect();
+        let mut indices: Vec<usize> = (0..entries.len()).collect();
+        indices.sort_unstable_by(|&a, &b| {
+            let ((k1, v1), (k2, v2)) = (entries[a], entries[b]);
+            cmp(k1, v1, k2, v2)
+        });
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the map's key-value pairs in place using a sort-key extraction
+    /// function, and return the permutation that was applied.
+    ///
+    /// See [`sort_by_with_permutation`][Self::sort_by_with_permutation] for
+    /// the form of the permutation.
+    ///
+    /// Computes in **O(n log n)** time and **O(n)** space. The sort is unstable.
+    pub fn sort_unstable_by_key_with_permutation<T, F>(&mut self, mut sort_key: F) -> Vec<usize>
+    where
+        T: Ord,
+        F: FnMut(&K, &V) -> T,
+    {
+        let entries: Vec<(&K, &V)> = self.iter().collect();
+        let mut indices: Vec<usize> = (0..entries.len()).collect();
+        indices.sort_unstable_by_key(|&i| sort_key(entries[i].0, entries[i].1));
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the map's key-value pairs in place using a sort-key extraction
+    /// function, and return the permutation that was applied.
+    ///
+    /// During sorting, the function is called at most once per entry, like
+    /// [`sort_by_cached_key`][Self::sort_by_cached_key]. See
+    /// [`sort_by_with_permutation`][Self::sort_by_with_permutation] for the
+    /// form of the permutation.
+    ///
+    /// Computes in **O(m n + n log n)** time and **O(n)** space, where the
+    /// function is **O(m)** and *n* is the length of the map. The sort is stable.
+    pub fn sort_by_cached_key_with_permutation<T, F>(&mut self, mut sort_key: F) -> Vec<usize>
+    where
+        T: Ord,
+        F: FnMut(&K, &V) -> T,
+    {
+        let entries: Vec<(&K, &V)> = self.iter().collect();
+        let mut indices: Vec<usize> = (0..entries.len()).collect();
+        indices.sort_by_cached_key(|&i| sort_key(entries[i].0, entries[i].1));
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Reorders by a permutation that is known to be valid, i.e. from sorting
+    /// the indices `0..self.len()`.
+    pub(crate) fn apply_sorted_indices(&mut self, indices: &[usize]) {
+        let inverse = util::inverse_permutation(indices, self.len())
+            .expect("sorted indices should form a permutation");
+        let inner = &mut self.inner;
+        util::permute_by_swaps(inverse, |a, b| inner.swap_indices(a, b));
+    }
+
     /// Search over a sorted map for a key.
     ///
     /// Returns the position where that key is present, or the position where it can be inserted to
@@ -1238,7 +2111,9 @@ impl<K, V, S> OrderMap<K, V, S> {
     ///
     /// Computes in **O(1)** time.
     pub fn get_index_entry(&mut self, index: usize) -> Option<IndexedEntry<'_, K, V>> {
-        self.inner.get_index_entry(index).map(IndexedEntry::new)
+        let len = self.len();
+        let entry = self.inner.get_index_entry(index)?;
+        Some(IndexedEntry::new(entry, len))
     }
 
     /// Get an array of `N` key-value pairs by `N` indices
@@ -1267,6 +2142,18 @@ impl<K, V, S> OrderMap<K, V, S> {
         self.inner.get_range(range)
     }
 
+    /// Returns a view of the key-value pairs in the given range of indices,
+    /// with hash lookups restricted to that range, or `None` if the range is
+    /// out of bounds.
+    ///
+    /// Valid indices are `0 <= index < self.len()`.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn sub_map<R: RangeBounds<usize>>(&self, range: R) -> Option<SubMap<'_, K, V, S>> {
+        let range = try_simplify_range(range, self.len()).ok()?;
+        Some(SubMap::new(self, range))
+    }
+
     /// Returns a mutable slice of key-value pairs in the given range of indices.
     ///
     /// Valid indices are `0 <= index < self.len()`.
@@ -1295,7 +2182,9 @@ impl<K, V, S> OrderMap<K, V, S> {
     ///
     /// Computes in **O(1)** time.
     pub fn first_entry(&mut self) -> Option<IndexedEntry<'_, K, V>> {
-        self.inner.first_entry().map(IndexedEntry::new)
+        let len = self.len();
+        let entry = self.inner.first_entry()?;
+        Some(IndexedEntry::new(entry, len))
     }
 
     /// Get the last key-value pair
@@ -1317,7 +2206,34 @@ impl<K, V, S> OrderMap<K, V, S> {
     ///
     /// Computes in **O(1)** time.
     pub fn last_entry(&mut self) -> Option<IndexedEntry<'_, K, V>> {
-        self.inner.last_entry().map(IndexedEntry::new)
+        let len = self.len();
+        let entry = self.inner.last_entry()?;
+        Some(IndexedEntry::new(entry, len))
+    }
+
+    /// Get a cursor pointing at the first entry of the map, or at the ghost
+    /// non-element if the map is empty.
+    ///
+    /// See [`CursorMut`] for how to move the cursor and edit the map with it.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, K, V, S> {
+        CursorMut::new(self, 0)
+    }
+
+    /// Get a cursor pointing at the entry at `index`, or at the ghost
+    /// non-element if `index` is the length of the map.
+    ///
+    /// ***Panics*** if `index` is out of bounds.
+    /// Valid indices are `0..=map.len()` (inclusive).
+    ///
+    /// Computes in **O(1)** time.
+    #[track_caller]
+    pub fn cursor_at(&mut self, index: usize) -> CursorMut<'_, K, V, S> {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "index out of bounds: the len is {len} but the index is {index}"
+        );
+        CursorMut::new(self, index)
     }
 
     /// Remove the key-value pair by index
@@ -1347,6 +2263,57 @@ impl<K, V, S> OrderMap<K, V, S> {
         self.inner.swap_remove_index(index)
     }
 
+    /// Remove the key-value pairs at all of the given `indices` in a single pass,
+    /// and return them in their original order.
+    ///
+    /// The indices may be given in any order, and refer to the positions
+    /// before any removal. The remaining pairs keep their relative order.
+    ///
+    /// Returns an error if an index is out of bounds or appears more than
+    /// once. The map is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{OrderMap, RemoveIndicesError};
+    ///
+    /// let mut map: OrderMap<char, usize> = ('a'..='f').zip(0..).collect();
+    /// assert_eq!(map.remove_indices([4, 1, 2]), Ok(vec![('b', 1), ('c', 2), ('e', 4)]));
+    /// assert!(map.keys().eq(&['a', 'd', 'f']));
+    ///
+    /// assert_eq!(
+    ///     map.remove_indices([0, 0]),
+    ///     Err(RemoveIndicesError::DuplicateIndex { index: 0 }),
+    /// );
+    /// assert_eq!(
+    ///     map.remove_indices([3]),
+    ///     Err(RemoveIndicesError::IndexOutOfBounds { index: 3, len: 3 }),
+    /// );
+    /// assert_eq!(map.len(), 3);
+    /// ```
+    pub fn remove_indices<I>(&mut self, indices: I) -> Result<Vec<(K, V)>, RemoveIndicesError>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let remove = util::removal_mask(self.len(), indices)?;
+        Ok(self.remove_marked(&remove))
+    }
+
+    /// Remove the key-value pairs whose indices are marked in `remove`,
+    /// in a single pass.
+    fn remove_marked(&mut self, remove: &[bool]) -> Vec<(K, V)> {
+        let start = remove
+            .iter()
+            .position(|&marked| marked)
+            .unwrap_or(remove.len());
+        let mut marks = remove[start..].iter();
+        self.inner
+            .extract_if(start.., |_, _| *marks.next().unwrap())
+            .collect()
+    }
+
     /// Moves the position of a key-value pair from one index to another
     /// by shifting all other pairs in-between.
     ///
@@ -1361,6 +2328,33 @@ impl<K, V, S> OrderMap<K, V, S> {
         self.inner.move_index(from, to)
     }
 
+    /// Moves the position of a key-value pair from one index to another
+    /// by shifting all other pairs in-between, or returns an error if
+    /// `from` or `to` are out of bounds.
+    ///
+    /// This is a checked version of [`move_index`][Self::move_index].
+    /// The map is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{IndexOutOfBounds, OrderMap};
+    ///
+    /// let mut map = OrderMap::from([('a', 1), ('b', 2), ('c', 3)]);
+    /// assert_eq!(map.try_move_index(0, 3), Err(IndexOutOfBounds { index: 3, len: 3 }));
+    /// assert_eq!(map.try_move_index(0, 2), Ok(()));
+    /// assert!(map.keys().eq(&['b', 'c', 'a']));
+    /// ```
+    pub fn try_move_index(&mut self, from: usize, to: usize) -> Result<(), IndexOutOfBounds> {
+        let len = self.len();
+        check_index_lt(from, len)?;
+        check_index_lt(to, len)?;
+        self.inner.move_index(from, to);
+        Ok(())
+    }
+
     /// Swaps the position of two key-value pairs in the map.
     ///
     /// ***Panics*** if `a` or `b` are out of bounds.
@@ -1370,6 +2364,234 @@ impl<K, V, S> OrderMap<K, V, S> {
     pub fn swap_indices(&mut self, a: usize, b: usize) {
         self.inner.swap_indices(a, b)
     }
+
+    /// Swaps the position of two key-value pairs in the map, or returns an
+    /// error if `a` or `b` are out of bounds.
+    ///
+    /// This is a checked version of [`swap_indices`][Self::swap_indices].
+    /// The map is unchanged in the error case.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn try_swap_indices(&mut self, a: usize, b: usize) -> Result<(), IndexOutOfBounds> {
+        let len = self.len();
+        check_index_lt(a, len)?;
+        check_index_lt(b, len)?;
+        self.inner.swap_indices(a, b);
+        Ok(())
+    }
+}
+
+impl<K, V, S> OrderMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Reorders the key-value pairs in the map by the permutation `perm`, so the
+    /// key-value pair at the old index `perm[i]` moves to the new index `i`.
+    ///
+    /// Returns an error if `perm` is not a permutation of `0..self.len()`,
+    /// i.e. if its length differs or if it has an out-of-bounds or repeated
+    /// index. The map is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{OrderMap, PermutationError};
+    ///
+    /// let mut map = OrderMap::from([('a', 1), ('b', 2), ('c', 3), ('d', 4)]);
+    /// map.apply_permutation(&[2, 0, 3, 1]).unwrap();
+    /// assert!(map.keys().eq(&['c', 'a', 'd', 'b']));
+    ///
+    /// let err = map.apply_permutation(&[0, 1, 1, 2]).unwrap_err();
+    /// assert_eq!(err, PermutationError::DuplicateIndex { index: 1 });
+    /// ```
+    pub fn apply_permutation(&mut self, perm: &[usize]) -> Result<(), PermutationError> {
+        let inverse = util::inverse_permutation(perm, self.len())?;
+        self.reorder_by(|index| inverse[index]);
+        Ok(())
+    }
+
+    /// Rotates the map in-place such that the key-value pairs are shifted `mid`
+    /// places to the left, like [`slice::rotate_left`]. The key-value pair at index
+    /// `mid` becomes the first.
+    ///
+    /// ***Panics*** if `mid` is greater than the length of the map.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    ///
+    /// let mut map: OrderMap<char, ()> = ('a'..='f').map(|c| (c, ())).collect();
+    /// map.rotate_left(2);
+    /// assert!(map.keys().eq(&['c', 'd', 'e', 'f', 'a', 'b']));
+    /// assert_eq!(map.get_index_of(&'a'), Some(4));
+    /// ```
+    #[track_caller]
+    pub fn rotate_left(&mut self, mid: usize) {
+        let len = self.len();
+        assert!(mid <= len, "mid {mid} out of range for length {len}");
+        self.move_entries(mid..len, 0);
+    }
+
+    /// Rotates the map in-place such that the key-value pairs are shifted `k`
+    /// places to the right, like [`slice::rotate_right`]. The last `k` key-value pairs
+    /// become the first.
+    ///
+    /// ***Panics*** if `k` is greater than the length of the map.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    ///
+    /// let mut map: OrderMap<char, ()> = ('a'..='f').map(|c| (c, ())).collect();
+    /// map.rotate_right(2);
+    /// assert!(map.keys().eq(&['e', 'f', 'a', 'b', 'c', 'd']));
+    /// ```
+    #[track_caller]
+    pub fn rotate_right(&mut self, k: usize) {
+        let len = self.len();
+        assert!(k <= len, "k {k} out of range for length {len}");
+        self.move_entries(len - k..len, 0);
+    }
+
+    /// Moves the key-value pairs in the given index range, so that the first of them
+    /// moves to index `to`, shifting all other key-value pairs in-between.
+    ///
+    /// This is like [`move_index`][Self::move_index] for a block of key-value pairs:
+    /// the moved key-value pairs keep their relative order, as do all others.
+    /// Valid destinations are `0..=self.len() - range.len()` (inclusive).
+    ///
+    /// ***Panics*** if the starting point is greater than the end point, if
+    /// the end point is greater than the length of the map, or if `to` is
+    /// out of bounds.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    ///
+    /// let mut map: OrderMap<char, ()> = ('a'..='f').map(|c| (c, ())).collect();
+    /// map.move_range(1..3, 3);
+    /// assert!(map.keys().eq(&['a', 'd', 'e', 'b', 'c', 'f']));
+    /// map.move_range(3.., 0);
+    /// assert!(map.keys().eq(&['b', 'c', 'f', 'a', 'd', 'e']));
+    /// ```
+    #[track_caller]
+    pub fn move_range<R>(&mut self, range: R, to: usize)
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let range = util::simplify_range(range, len);
+        util::check_move_range(&range, to, len);
+        self.move_entries(range, to);
+    }
+
+    /// Reorders the map's key-value pairs so that the keys found in
+    /// `reference` follow its order, and `policy` decides where the other keys
+    /// go.
+    ///
+    /// Each value of the reference is looked up as an [`Equivalent`] key, so
+    /// the reference may hold a different type than the map's keys.
+    ///
+    /// Computes in **O(n + m)** time (average), where *m* is the length of
+    /// the reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{OrderMap, OrderSet, ReorderPolicy};
+    ///
+    /// let mut map = OrderMap::from([("x", 1), ("b", 2), ("y", 3), ("a", 4)]);
+    /// let reference = OrderSet::from(["a", "b", "c"]);
+    ///
+    /// map.sort_by_reference(&reference, ReorderPolicy::AbsentLast);
+    /// assert!(map.keys().eq(&["a", "b", "x", "y"]));
+    ///
+    /// map.sort_by_reference(&reference, ReorderPolicy::AbsentFirst);
+    /// assert!(map.keys().eq(&["x", "y", "a", "b"]));
+    /// ```
+    pub fn sort_by_reference<Q, S2>(&mut self, reference: &OrderSet<Q, S2>, policy: ReorderPolicy)
+    where
+        Q: Hash + Equivalent<K>,
+    {
+        self.reorder_by_keys(reference.iter(), policy);
+    }
+
+    /// Reorders the map's key-value pairs so that the keys found in `other`
+    /// follow its order, and `policy` decides where the other keys go.
+    ///
+    /// This is like [`sort_by_reference`][Self::sort_by_reference], using the
+    /// keys of `other` as the reference.
+    ///
+    /// Computes in **O(n + m)** time (average), where *m* is the length of
+    /// `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{OrderMap, ReorderPolicy};
+    ///
+    /// let mut map = OrderMap::from([(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')]);
+    /// let other = OrderMap::from([(4, ()), (9, ()), (2, ())]);
+    ///
+    /// map.reorder_like(&other, ReorderPolicy::AbsentInPlace);
+    /// assert!(map.keys().eq(&[1, 4, 3, 2]));
+    /// ```
+    pub fn reorder_like<Q, V2, S2>(&mut self, other: &OrderMap<Q, V2, S2>, policy: ReorderPolicy)
+    where
+        Q: Hash + Equivalent<K>,
+    {
+        self.reorder_by_keys(other.keys(), policy);
+    }
+
+    fn reorder_by_keys<'a, Q, I>(&mut self, keys: I, policy: ReorderPolicy)
+    where
+        Q: 'a + Hash + Equivalent<K>,
+        I: Iterator<Item = &'a Q>,
+    {
+        let found: Vec<usize> = keys.filter_map(|key| self.get_index_of(key)).collect();
+        let perm = util::reference_permutation(self.len(), found, policy);
+        let inverse = util::inverse_permutation(&perm, self.len())
+            .expect("reference order should form a permutation");
+        self.reorder_by(|index| inverse[index]);
+    }
+
+    /// Moves the entries in `range` to start at `to`, which must be in bounds.
+    fn move_entries(&mut self, range: Range<usize>, to: usize) {
+        if !range.is_empty() && to != range.start {
+            self.reorder_by(|index| util::moved_index(index, &range, to));
+        }
+    }
+
+    /// Moves the key-value pair at each index `i` to the new index `new_index(i)`,
+    /// which must be a permutation of `0..self.len()`.
+    ///
+    /// This takes out all key-value pairs in order, and puts them back in their new
+    /// order, so the hash table is rebuilt once rather than fixed up per move.
+    fn reorder_by<F>(&mut self, mut new_index: F)
+    where
+        F: FnMut(usize) -> usize,
+    {
+        let mut slots: Vec<Option<(K, V)>> = (0..self.len()).map(|_| None).collect();
+        for (index, entry) in self.inner.drain(..).enumerate() {
+            slots[new_index(index)] = Some(entry);
+        }
+        let entries = slots
+            .into_iter()
+            .map(|slot| slot.expect("new indices should form a permutation"));
+        self.inner.extend(entries);
+    }
 }
 
 /// Access [`OrderMap`] values corresponding to a key.
@@ -1675,3 +2897,198 @@ where
         }
     }
 }
+
+impl<K, V, S> OrderMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    /// Returns `true` if the first entries of `self` are equal to all entries
+    /// of `other`, in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    ///
+    /// let map = OrderMap::from([('a', 1), ('b', 2), ('c', 3)]);
+    /// assert!(map.starts_with(&OrderMap::from([('a', 1), ('b', 2)])));
+    /// assert!(!map.starts_with(&OrderMap::from([('a', 1), ('b', 0)])));
+    /// assert!(map.keys_start_with(&OrderMap::from([('a', ()), ('b', ())])));
+    /// assert!(map.ends_with(&OrderMap::from([('c', 3)])));
+    /// ```
+    pub fn starts_with<V2, S2>(&self, other: &OrderMap<K, V2, S2>) -> bool
+    where
+        V: PartialEq<V2>,
+    {
+        other.len() <= self.len() && self.common_prefix_len_by(other, V::eq) == other.len()
+    }
+
+    /// Returns `true` if the last entries of `self` are equal to all entries
+    /// of `other`, in the same order.
+    pub fn ends_with<V2, S2>(&self, other: &OrderMap<K, V2, S2>) -> bool
+    where
+        V: PartialEq<V2>,
+    {
+        self.ends_with_by(other, V::eq)
+    }
+
+    /// Returns `true` if all entries of `self` are equal to the first entries
+    /// of `other`, in the same order.
+    pub fn is_prefix_of<V2, S2>(&self, other: &OrderMap<K, V2, S2>) -> bool
+    where
+        V: PartialEq<V2>,
+    {
+        self.len() <= other.len() && self.common_prefix_len_by(other, V::eq) == self.len()
+    }
+
+    /// Returns `true` if all entries of `self` are also in `other`, with
+    /// equal values, in the same relative order.
+    ///
+    /// Computes in **O(n)** time (average), where *n* is the length of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderMap;
+    ///
+    /// let map = OrderMap::from([('a', 1), ('b', 2), ('c', 3)]);
+    /// assert!(OrderMap::from([('a', 1), ('c', 3)]).is_subsequence_of(&map));
+    /// assert!(!OrderMap::from([('c', 3), ('a', 1)]).is_subsequence_of(&map));
+    /// assert!(!OrderMap::from([('a', 1), ('c', 0)]).is_subsequence_of(&map));
+    /// assert!(OrderMap::from([('a', 0), ('c', 0)]).keys_are_subsequence_of(&map));
+    /// ```
+    pub fn is_subsequence_of<V2, S2>(&self, other: &OrderMap<K, V2, S2>) -> bool
+    where
+        V: PartialEq<V2>,
+        S2: BuildHasher,
+    {
+        self.is_subsequence_of_by(other, V::eq)
+    }
+
+    /// Returns `true` if `self` and `other` contain equal entries, even if
+    /// they are not in the same order.
+    ///
+    /// (Note that `PartialEq for OrderMap` **does** consider the order.)
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn is_permutation_of<V2, S2>(&self, other: &OrderMap<K, V2, S2>) -> bool
+    where
+        V: PartialEq<V2>,
+        S2: BuildHasher,
+    {
+        self.is_permutation_of_by(other, V::eq)
+    }
+
+    /// Returns the number of leading entries that are equal in `self` and
+    /// `other`.
+    pub fn common_prefix_len<V2, S2>(&self, other: &OrderMap<K, V2, S2>) -> usize
+    where
+        V: PartialEq<V2>,
+    {
+        self.common_prefix_len_by(other, V::eq)
+    }
+
+    /// Returns `true` if the first keys of `self` are equal to all keys of
+    /// `other`, in the same order, regardless of their values.
+    pub fn keys_start_with<V2, S2>(&self, other: &OrderMap<K, V2, S2>) -> bool {
+        other.len() <= self.len() && self.common_prefix_len_by(other, any_values) == other.len()
+    }
+
+    /// Returns `true` if the last keys of `self` are equal to all keys of
+    /// `other`, in the same order, regardless of their values.
+    pub fn keys_end_with<V2, S2>(&self, other: &OrderMap<K, V2, S2>) -> bool {
+        self.ends_with_by(other, any_values)
+    }
+
+    /// Returns `true` if all keys of `self` are equal to the first keys of
+    /// `other`, in the same order, regardless of their values.
+    pub fn keys_are_prefix_of<V2, S2>(&self, other: &OrderMap<K, V2, S2>) -> bool {
+        self.len() <= other.len() && self.common_prefix_len_by(other, any_values) == self.len()
+    }
+
+    /// Returns `true` if all keys of `self` are also in `other`, in the same
+    /// relative order, regardless of their values.
+    ///
+    /// Computes in **O(n)** time (average), where *n* is the length of `self`.
+    pub fn keys_are_subsequence_of<V2, S2>(&self, other: &OrderMap<K, V2, S2>) -> bool
+    where
+        S2: BuildHasher,
+    {
+        self.is_subsequence_of_by(other, any_values)
+    }
+
+    /// Returns `true` if `self` and `other` contain the same keys, even if
+    /// they are not in the same order, regardless of their values.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn keys_are_permutation_of<V2, S2>(&self, other: &OrderMap<K, V2, S2>) -> bool
+    where
+        S2: BuildHasher,
+    {
+        self.is_permutation_of_by(other, any_values)
+    }
+
+    /// Returns the number of leading keys that are equal in `self` and
+    /// `other`, regardless of their values.
+    pub fn common_key_prefix_len<V2, S2>(&self, other: &OrderMap<K, V2, S2>) -> usize {
+        self.common_prefix_len_by(other, any_values)
+    }
+
+    fn common_prefix_len_by<V2, S2, F>(&self, other: &OrderMap<K, V2, S2>, mut eq: F) -> usize
+    where
+        F: FnMut(&V, &V2) -> bool,
+    {
+        self.iter()
+            .zip(other)
+            .take_while(|((k1, v1), (k2, v2))| k1 == k2 && eq(v1, v2))
+            .count()
+    }
+
+    fn ends_with_by<V2, S2, F>(&self, other: &OrderMap<K, V2, S2>, mut eq: F) -> bool
+    where
+        F: FnMut(&V, &V2) -> bool,
+    {
+        match self.len().checked_sub(other.len()) {
+            Some(start) => self.as_slice()[start..]
+                .iter()
+                .zip(other)
+                .all(|((k1, v1), (k2, v2))| k1 == k2 && eq(v1, v2)),
+            None => false,
+        }
+    }
+
+    fn is_subsequence_of_by<V2, S2, F>(&self, other: &OrderMap<K, V2, S2>, mut eq: F) -> bool
+    where
+        S2: BuildHasher,
+        F: FnMut(&V, &V2) -> bool,
+    {
+        let mut next = 0;
+        self.len() <= other.len()
+            && self.iter().all(|(key, value)| match other.get_full(key) {
+                Some((index, _, other_value)) if index >= next && eq(value, other_value) => {
+                    next = index + 1;
+                    true
+                }
+                _ => false,
+            })
+    }
+
+    fn is_permutation_of_by<V2, S2, F>(&self, other: &OrderMap<K, V2, S2>, mut eq: F) -> bool
+    where
+        S2: BuildHasher,
+        F: FnMut(&V, &V2) -> bool,
+    {
+        self.len() == other.len()
+            && self.iter().all(|(key, value)| {
+                other
+                    .get(key)
+                    .is_some_and(|other_value| eq(value, other_value))
+            })
+    }
+}
+
+/// Ignores the values, for comparing keys alone.
+fn any_values<V, V2>(_: &V, _: &V2) -> bool {
+    true
+}
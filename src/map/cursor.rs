@@ -0,0 +1,211 @@
+use super::OrderMap;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use indexmap::map::Entry as IndexEntry;
+
+/// A cursor over an [`OrderMap`] with editing operations.
+///
+/// A cursor is like an iterator, except that it can freely seek back and
+/// forth, and it can insert and remove entries around its position. It is
+/// modeled on the `CursorMut` of [`LinkedList`][std_cursor].
+///
+/// A cursor points either at an entry, or at a "ghost" non-element between
+/// the last and the first entry. The ghost is at index `map.len()`, and
+/// [`current`][Self::current] returns `None` there. Moving past either end
+/// of the map lands on the ghost, and moving again wraps around to the other
+/// end.
+///
+/// This `struct` is created by the [`OrderMap::cursor_front_mut`],
+/// [`OrderMap::cursor_at`], and [`OrderMap::cursor_at_key`] methods.
+///
+/// [std_cursor]: https://doc.rust-lang.org/std/collections/linked_list/struct.CursorMut.html
+///
+/// # Examples
+///
+/// ```
+/// use ordermap::OrderMap;
+///
+/// let mut map: OrderMap<i32, char> = (1..=5).zip('a'..).collect();
+/// let mut cursor = map.cursor_front_mut();
+/// while let Some((&key, value)) = cursor.current() {
+///     if key % 2 == 0 {
+///         cursor.remove_current();
+///     } else {
+///         *value = value.to_ascii_uppercase();
+///         cursor.insert_after(-key, '-');
+///         cursor.move_next();
+///         cursor.move_next();
+///     }
+/// }
+/// assert!(map.into_iter().eq([(1, 'A'), (-1, '-'), (3, 'C'), (-3, '-'), (5, 'E'), (-5, '-')]));
+/// ```
+pub struct CursorMut<'a, K, V, S> {
+    map: &'a mut OrderMap<K, V, S>,
+    index: usize,
+}
+
+impl<'a, K, V, S> CursorMut<'a, K, V, S> {
+    pub(super) fn new(map: &'a mut OrderMap<K, V, S>, index: usize) -> Self {
+        debug_assert!(index <= map.len());
+        CursorMut { map, index }
+    }
+
+    fn is_ghost(&self) -> bool {
+        self.index == self.map.len()
+    }
+
+    /// Returns the index of the cursor's current entry, or `None` if it is
+    /// pointing at the ghost non-element.
+    pub fn index(&self) -> Option<usize> {
+        (!self.is_ghost()).then_some(self.index)
+    }
+
+    /// Moves the cursor to the next entry.
+    ///
+    /// From the last entry, this moves to the ghost non-element, and from the
+    /// ghost, this moves to the first entry of the map.
+    pub fn move_next(&mut self) {
+        if self.is_ghost() {
+            self.index = 0;
+        } else {
+            self.index += 1;
+        }
+    }
+
+    /// Moves the cursor to the previous entry.
+    ///
+    /// From the first entry, this moves to the ghost non-element, and from the
+    /// ghost, this moves to the last entry of the map.
+    pub fn move_prev(&mut self) {
+        self.index = match self.index.checked_sub(1) {
+            Some(index) => index,
+            None => self.map.len(),
+        };
+    }
+
+    /// Returns the current key and a mutable reference to its value, or `None`
+    /// if the cursor is pointing at the ghost non-element.
+    pub fn current(&mut self) -> Option<(&K, &mut V)> {
+        self.map.get_index_mut(self.index)
+    }
+
+    /// Returns the next key and a mutable reference to its value, without
+    /// moving the cursor.
+    ///
+    /// From the ghost non-element, this peeks at the first entry, and from the
+    /// last entry, this returns `None`.
+    pub fn peek_next(&mut self) -> Option<(&K, &mut V)> {
+        let next = if self.is_ghost() { 0 } else { self.index + 1 };
+        self.map.get_index_mut(next)
+    }
+
+    /// Returns the previous key and a mutable reference to its value, without
+    /// moving the cursor.
+    ///
+    /// From the ghost non-element, this peeks at the last entry, and from the
+    /// first entry, this returns `None`.
+    pub fn peek_prev(&mut self) -> Option<(&K, &mut V)> {
+        self.map.get_index_mut(self.index.checked_sub(1)?)
+    }
+
+    /// Removes the current entry and returns it, moving the cursor to the
+    /// next entry, or returns `None` if the cursor is pointing at the ghost
+    /// non-element.
+    ///
+    /// Like [`OrderMap::remove_index`], this shifts all of the following
+    /// entries.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn remove_current(&mut self) -> Option<(K, V)> {
+        self.map.remove_index(self.index)
+    }
+
+    /// Returns a read-only view of the whole map.
+    pub fn as_map(&self) -> &OrderMap<K, V, S> {
+        self.map
+    }
+}
+
+impl<K, V, S> CursorMut<'_, K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Inserts a key-value pair just before the current entry, or last in the
+    /// map if the cursor is pointing at the ghost non-element. The cursor
+    /// keeps pointing at the same entry, or at the ghost.
+    ///
+    /// If an equivalent key already exists in the map, it is moved to that
+    /// position, its value is updated with `value`, and the old value is
+    /// returned inside `Some(_)`. If it is the current entry, only its value
+    /// is updated.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn insert_before(&mut self, key: K, value: V) -> Option<V> {
+        let index = self.index;
+        match self.map.inner.entry(key) {
+            IndexEntry::Occupied(mut entry) => {
+                let old = entry.insert(value);
+                let from = entry.index();
+                if from < index {
+                    self.map.inner.move_index(from, index - 1);
+                } else if from > index {
+                    self.map.inner.move_index(from, index);
+                    self.index += 1;
+                }
+                Some(old)
+            }
+            IndexEntry::Vacant(entry) => {
+                entry.shift_insert(index, value);
+                self.index += 1;
+                None
+            }
+        }
+    }
+
+    /// Inserts a key-value pair just after the current entry, or first in the
+    /// map if the cursor is pointing at the ghost non-element. The cursor
+    /// keeps pointing at the same entry, or at the ghost.
+    ///
+    /// If an equivalent key already exists in the map, it is moved to that
+    /// position, its value is updated with `value`, and the old value is
+    /// returned inside `Some(_)`. If it is the current entry, only its value
+    /// is updated.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn insert_after(&mut self, key: K, value: V) -> Option<V> {
+        let ghost = self.is_ghost();
+        let after = if ghost { 0 } else { self.index + 1 };
+        match self.map.inner.entry(key) {
+            IndexEntry::Occupied(mut entry) => {
+                let old = entry.insert(value);
+                let from = entry.index();
+                if from < after {
+                    if from != self.index {
+                        self.map.inner.move_index(from, after - 1);
+                        self.index -= 1;
+                    }
+                } else if from > after {
+                    self.map.inner.move_index(from, after);
+                }
+                Some(old)
+            }
+            IndexEntry::Vacant(entry) => {
+                entry.shift_insert(after, value);
+                if ghost {
+                    self.index += 1;
+                }
+                None
+            }
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for CursorMut<'_, K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CursorMut")
+            .field("index", &self.index())
+            .field("current", &self.map.get_index(self.index))
+            .finish()
+    }
+}
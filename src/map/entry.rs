@@ -1,3 +1,5 @@
+use crate::util::{check_index_le, check_index_lt};
+use crate::{IndexOutOfBounds, InsertOutOfBounds};
 use core::cmp::Ordering;
 use core::fmt;
 use indexmap::map as ix;
@@ -15,9 +17,9 @@ pub enum Entry<'a, K, V> {
 }
 
 impl<'a, K, V> Entry<'a, K, V> {
-    pub(super) fn new(entry: ix::Entry<'a, K, V>) -> Self {
+    pub(super) fn new(entry: ix::Entry<'a, K, V>, len: usize) -> Self {
         match entry {
-            ix::Entry::Occupied(inner) => Self::Occupied(OccupiedEntry { inner }),
+            ix::Entry::Occupied(inner) => Self::Occupied(OccupiedEntry { inner, len }),
             ix::Entry::Vacant(inner) => Self::Vacant(VacantEntry { inner }),
         }
     }
@@ -136,6 +138,8 @@ impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for Entry<'_, K, V> {
 /// It is part of the [`Entry`] enum.
 pub struct OccupiedEntry<'a, K, V> {
     pub(crate) inner: ix::OccupiedEntry<'a, K, V>,
+    // The map's length, which can't change while the entry borrows it.
+    len: usize,
 }
 
 impl<'a, K, V> OccupiedEntry<'a, K, V> {
@@ -243,6 +247,20 @@ impl<'a, K, V> OccupiedEntry<'a, K, V> {
         self.inner.move_index(to);
     }
 
+    /// Moves the position of the entry to a new index
+    /// by shifting all other entries in-between, or returns an error if `to`
+    /// is out of bounds.
+    ///
+    /// This is a checked version of [`move_index`][Self::move_index].
+    /// The map is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn try_move_index(self, to: usize) -> Result<(), IndexOutOfBounds> {
+        check_index_lt(to, self.len)?;
+        self.inner.move_index(to);
+        Ok(())
+    }
+
     /// Swaps the position of entry with another.
     ///
     /// This is equivalent to [`OrderMap::swap_indices`][`crate::OrderMap::swap_indices`]
@@ -255,6 +273,19 @@ impl<'a, K, V> OccupiedEntry<'a, K, V> {
     pub fn swap_indices(self, other: usize) {
         self.inner.swap_indices(other);
     }
+
+    /// Swaps the position of entry with another, or returns an error if the
+    /// `other` index is out of bounds.
+    ///
+    /// This is a checked version of [`swap_indices`][Self::swap_indices].
+    /// The map is unchanged in the error case.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn try_swap_indices(self, other: usize) -> Result<(), IndexOutOfBounds> {
+        check_index_lt(other, self.len)?;
+        self.inner.swap_indices(other);
+        Ok(())
+    }
 }
 
 impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for OccupiedEntry<'_, K, V> {
@@ -270,6 +301,7 @@ impl<'a, K, V> From<IndexedEntry<'a, K, V>> for OccupiedEntry<'a, K, V> {
     fn from(entry: IndexedEntry<'a, K, V>) -> Self {
         Self {
             inner: entry.inner.into(),
+            len: entry.len,
         }
     }
 }
@@ -306,8 +338,11 @@ impl<'a, K, V> VacantEntry<'a, K, V> {
     ///
     /// Computes in **O(1)** time (amortized average).
     pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V> {
+        // A vacant entry's index is always the map's length.
+        let len = self.index() + 1;
         OccupiedEntry {
             inner: self.inner.insert_entry(value),
+            len,
         }
     }
 
@@ -371,6 +406,29 @@ impl<'a, K, V> VacantEntry<'a, K, V> {
         self.inner.shift_insert(index, value)
     }
 
+    /// Inserts the entry's key and the given value into the map at the given index,
+    /// shifting others to the right, and returns a mutable reference to the value,
+    /// or returns an error if `index` is out of bounds.
+    ///
+    /// This is a checked version of [`shift_insert`][Self::shift_insert].
+    /// Valid indices are `0..=map.len()` (inclusive).
+    /// The error gives back the entry's key and the value.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn try_shift_insert(
+        self,
+        index: usize,
+        value: V,
+    ) -> Result<&'a mut V, InsertOutOfBounds<(K, V)>> {
+        match check_index_le(index, self.index()) {
+            Ok(()) => Ok(self.inner.shift_insert(index, value)),
+            Err(error) => Err(InsertOutOfBounds {
+                error,
+                item: (self.into_key(), value),
+            }),
+        }
+    }
+
     /// Replaces the key at the given index with this entry's key, returning the
     /// old key and an `OccupiedEntry` for that index.
     ///
@@ -379,8 +437,9 @@ impl<'a, K, V> VacantEntry<'a, K, V> {
     /// Computes in **O(1)** time (average).
     #[track_caller]
     pub fn replace_index(self, index: usize) -> (K, OccupiedEntry<'a, K, V>) {
+        let len = self.index();
         let (old_key, inner) = self.inner.replace_index(index);
-        (old_key, OccupiedEntry { inner })
+        (old_key, OccupiedEntry { inner, len })
     }
 }
 
@@ -395,11 +454,13 @@ impl<K: fmt::Debug, V> fmt::Debug for VacantEntry<'_, K, V> {
 /// This `struct` is created from the [`get_index_entry`][crate::OrderMap::get_index_entry] method.
 pub struct IndexedEntry<'a, K, V> {
     pub(crate) inner: ix::IndexedEntry<'a, K, V>,
+    // The map's length, which can't change while the entry borrows it.
+    len: usize,
 }
 
 impl<'a, K, V> IndexedEntry<'a, K, V> {
-    pub(super) fn new(inner: ix::IndexedEntry<'a, K, V>) -> Self {
-        Self { inner }
+    pub(super) fn new(inner: ix::IndexedEntry<'a, K, V>, len: usize) -> Self {
+        Self { inner, len }
     }
 
     /// Return the index of the key-value pair
@@ -502,6 +563,20 @@ impl<'a, K, V> IndexedEntry<'a, K, V> {
         self.inner.move_index(to)
     }
 
+    /// Moves the position of the entry to a new index
+    /// by shifting all other entries in-between, or returns an error if `to`
+    /// is out of bounds.
+    ///
+    /// This is a checked version of [`move_index`][Self::move_index].
+    /// The map is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn try_move_index(self, to: usize) -> Result<(), IndexOutOfBounds> {
+        check_index_lt(to, self.len)?;
+        self.inner.move_index(to);
+        Ok(())
+    }
+
     /// Swaps the position of entry with another.
     ///
     /// This is equivalent to [`OrderMap::swap_indices`][`crate::OrderMap::swap_indices`]
@@ -514,6 +589,19 @@ impl<'a, K, V> IndexedEntry<'a, K, V> {
     pub fn swap_indices(self, other: usize) {
         self.inner.swap_indices(other)
     }
+
+    /// Swaps the position of entry with another, or returns an error if the
+    /// `other` index is out of bounds.
+    ///
+    /// This is a checked version of [`swap_indices`][Self::swap_indices].
+    /// The map is unchanged in the error case.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn try_swap_indices(self, other: usize) -> Result<(), IndexOutOfBounds> {
+        check_index_lt(other, self.len)?;
+        self.inner.swap_indices(other);
+        Ok(())
+    }
 }
 
 impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for IndexedEntry<'_, K, V> {
@@ -530,6 +618,7 @@ impl<'a, K, V> From<OccupiedEntry<'a, K, V>> for IndexedEntry<'a, K, V> {
     fn from(entry: OccupiedEntry<'a, K, V>) -> Self {
         Self {
             inner: entry.inner.into(),
+            len: entry.len,
         }
     }
 }
@@ -0,0 +1,1079 @@
+//! [`HandleMap`] is an order-preserving hash map with stable entry handles.
+//!
+//! Indices into an [`OrderMap`] are only valid until the next shift, swap,
+//! sort, or removal, and looking an entry up again by its key means hashing
+//! the key again. A `HandleMap` gives out an [`EntryHandle`] for each entry
+//! instead, which keeps referring to the same entry wherever it moves, and
+//! finds its current index in **O(1)** time.
+//!
+//! Handles are *generational*: once their entry is removed, a handle is
+//! never valid again, even if the same key is inserted again later.
+
+use super::OrderMap;
+use crate::Equivalent;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FusedIterator;
+use core::ops::{Index, IndexMut, Range};
+use indexmap::IndexMap;
+use indexmap::map::Entry as IndexEntry;
+
+#[cfg(feature = "std")]
+use std::hash::RandomState;
+
+/// A stable reference to an entry of a [`HandleMap`].
+///
+/// A handle stays valid while its entry is in the map, no matter how the
+/// entries are moved or sorted, and it is invalid forever after the entry
+/// is removed. Methods that take a handle return `None` for an invalid one.
+///
+/// Handles are only meaningful for the map that created them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EntryHandle {
+    slot: usize,
+    generation: u64,
+}
+
+/// A hash table with consistent order and stable entry handles.
+///
+/// This is like an [`OrderMap`], except that every entry also has an
+/// [`EntryHandle`], which [`insert_full`][Self::insert_full] and
+/// [`handle`][Self::handle] return. The handle finds the current index and
+/// entry in **O(1)** time, without hashing the key, and stays valid across
+/// all moves and sorts until the entry is removed.
+///
+/// Keeping the handles up to date adds **O(1)** work for each entry that
+/// changes its index, which doesn't change the complexity of any operation.
+///
+/// # Examples
+///
+/// ```
+/// use ordermap::map::HandleMap;
+///
+/// let mut map = HandleMap::new();
+/// let (a, _) = map.insert_full("a", 1);
+/// let (b, _) = map.insert_full("b", 2);
+/// map.insert("c", 3);
+///
+/// map.sort_by(|_, v1, _, v2| v2.cmp(v1));
+/// assert_eq!(map.index_of(a), Some(2));
+/// assert_eq!(map.get_by_handle(b), Some((&"b", &2)));
+///
+/// map.remove("a");
+/// assert_eq!(map.index_of(a), None);
+///
+/// // A handle never refers to a later entry, even for the same key.
+/// let (new_a, _) = map.insert_full("a", 10);
+/// assert_ne!(new_a, a);
+/// assert_eq!(map.get_by_handle(a), None);
+/// ```
+#[cfg(feature = "std")]
+pub struct HandleMap<K, V, S = RandomState> {
+    inner: IndexMap<K, Tagged<V>, S>,
+    slots: Slots,
+}
+#[cfg(not(feature = "std"))]
+pub struct HandleMap<K, V, S> {
+    inner: IndexMap<K, Tagged<V>, S>,
+    slots: Slots,
+}
+
+/// A value with the slot of its entry's handle.
+#[derive(Clone)]
+struct Tagged<V> {
+    slot: usize,
+    value: V,
+}
+
+/// The current index of each handle's entry, by slot.
+#[derive(Clone)]
+struct Slots {
+    slots: Vec<SlotState>,
+    free: Vec<usize>,
+}
+
+#[derive(Clone)]
+struct SlotState {
+    generation: u64,
+    /// The index of the entry, or `VACANT` if the slot is free.
+    index: usize,
+}
+
+const VACANT: usize = usize::MAX;
+
+impl Slots {
+    fn with_capacity(n: usize) -> Self {
+        Slots {
+            slots: Vec::with_capacity(n),
+            free: Vec::new(),
+        }
+    }
+
+    /// Returns a handle for a new entry at `index`.
+    fn allocate(&mut self, index: usize) -> EntryHandle {
+        let slot = match self.free.pop() {
+            Some(slot) => slot,
+            None => {
+                self.slots.push(SlotState {
+                    generation: 0,
+                    index: VACANT,
+                });
+                self.slots.len() - 1
+            }
+        };
+        let state = &mut self.slots[slot];
+        state.index = index;
+        EntryHandle {
+            slot,
+            generation: state.generation,
+        }
+    }
+
+    /// Invalidates all handles of `slot`, and frees it for reuse.
+    fn release(&mut self, slot: usize) {
+        let state = &mut self.slots[slot];
+        state.index = VACANT;
+        state.generation += 1;
+        self.free.push(slot);
+    }
+
+    fn handle(&self, slot: usize) -> EntryHandle {
+        EntryHandle {
+            slot,
+            generation: self.slots[slot].generation,
+        }
+    }
+
+    fn index_of(&self, handle: EntryHandle) -> Option<usize> {
+        let state = self.slots.get(handle.slot)?;
+        (state.generation == handle.generation && state.index != VACANT).then_some(state.index)
+    }
+
+    /// Records the current indices of the entries in `range`.
+    fn reindex<K, V, S>(&mut self, map: &IndexMap<K, Tagged<V>, S>, range: Range<usize>) {
+        let entries = &map.as_slice()[range.clone()];
+        for (index, tagged) in range.zip(entries.values()) {
+            self.slots[tagged.slot].index = index;
+        }
+    }
+}
+
+impl<K, V, S> Clone for HandleMap<K, V, S>
+where
+    K: Clone,
+    V: Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        HandleMap {
+            inner: self.inner.clone(),
+            slots: self.slots.clone(),
+        }
+    }
+}
+
+impl<K, V, S> fmt::Debug for HandleMap<K, V, S>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<K, V> HandleMap<K, V> {
+    /// Create a new map. (Does not allocate.)
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+
+    /// Create a new map with capacity for `n` key-value pairs. (Does not
+    /// allocate if `n` is zero.)
+    ///
+    /// Computes in **O(n)** time.
+    #[inline]
+    pub fn with_capacity(n: usize) -> Self {
+        Self::with_capacity_and_hasher(n, RandomState::new())
+    }
+}
+
+impl<K, V, S> HandleMap<K, V, S> {
+    /// Create a new map with capacity for `n` key-value pairs. (Does not
+    /// allocate if `n` is zero.)
+    ///
+    /// Computes in **O(n)** time.
+    #[inline]
+    pub fn with_capacity_and_hasher(n: usize, hash_builder: S) -> Self {
+        HandleMap {
+            inner: IndexMap::with_capacity_and_hasher(n, hash_builder),
+            slots: Slots::with_capacity(n),
+        }
+    }
+
+    /// Create a new map with `hash_builder`. (Does not allocate.)
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_capacity_and_hasher(0, hash_builder)
+    }
+
+    /// Return the number of key-value pairs in the map.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the map contains no elements.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Return a reference to the map's `BuildHasher`.
+    pub fn hasher(&self) -> &S {
+        self.inner.hasher()
+    }
+
+    /// Remove all key-value pairs in the map, while preserving its capacity.
+    /// All of their handles become invalid.
+    ///
+    /// Computes in **O(n)** time.
+    pub fn clear(&mut self) {
+        for tagged in self.inner.values() {
+            self.slots.release(tagged.slot);
+        }
+        self.inner.clear();
+    }
+
+    /// Return an iterator over the key-value pairs of the map, in their order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            iter: self.inner.iter(),
+        }
+    }
+
+    /// Return an iterator over the key-value pairs of the map, in their order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            iter: self.inner.iter_mut(),
+        }
+    }
+
+    /// Return an iterator over the handles of the map's entries, in their
+    /// order.
+    pub fn handles(&self) -> Handles<'_, K, V> {
+        Handles {
+            iter: self.inner.values(),
+            slots: &self.slots,
+        }
+    }
+
+    /// Return an iterator over the keys of the map, in their order.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys {
+            iter: self.inner.keys(),
+        }
+    }
+
+    /// Return an iterator over the values of the map, in their order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values {
+            iter: self.inner.values(),
+        }
+    }
+
+    /// Return an iterator over mutable references to the values of the map,
+    /// in their order.
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut {
+            iter: self.inner.values_mut(),
+        }
+    }
+
+    /// Return the current index of the entry for `handle`, or `None` if the
+    /// entry has been removed.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn index_of(&self, handle: EntryHandle) -> Option<usize> {
+        self.slots.index_of(handle)
+    }
+
+    /// Return `true` if the entry for `handle` is still in the map.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn contains_handle(&self, handle: EntryHandle) -> bool {
+        self.index_of(handle).is_some()
+    }
+
+    /// Return references to the key-value pair for `handle`, or `None` if
+    /// the entry has been removed.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn get_by_handle(&self, handle: EntryHandle) -> Option<(&K, &V)> {
+        self.get_index(self.index_of(handle)?)
+    }
+
+    /// Return the key and a mutable reference to the value for `handle`, or
+    /// `None` if the entry has been removed.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn get_by_handle_mut(&mut self, handle: EntryHandle) -> Option<(&K, &mut V)> {
+        self.get_index_mut(self.index_of(handle)?)
+    }
+
+    /// Return the handle of the entry at `index`, if it is in bounds.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn handle_at(&self, index: usize) -> Option<EntryHandle> {
+        let (_, tagged) = self.inner.get_index(index)?;
+        Some(self.slots.handle(tagged.slot))
+    }
+
+    /// Get a key-value pair by index.
+    ///
+    /// Valid indices are `0 <= index < self.len()`.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        let (key, tagged) = self.inner.get_index(index)?;
+        Some((key, &tagged.value))
+    }
+
+    /// Get a key-value pair by index, with mutable access to the value.
+    ///
+    /// Valid indices are `0 <= index < self.len()`.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        let (key, tagged) = self.inner.get_index_mut(index)?;
+        Some((key, &mut tagged.value))
+    }
+
+    /// Get the first key-value pair.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.get_index(0)
+    }
+
+    /// Get the last key-value pair.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.get_index(self.len().checked_sub(1)?)
+    }
+
+    /// Remove the key-value pair by index, and invalidate its handle.
+    ///
+    /// Valid indices are `0 <= index < self.len()`.
+    ///
+    /// Like [`OrderMap::remove_index`], the pair is removed by shifting all of
+    /// the elements that follow it, preserving their relative order.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let (key, tagged) = self.inner.shift_remove_index(index)?;
+        self.slots.release(tagged.slot);
+        self.slots.reindex(&self.inner, index..self.len());
+        Some((key, tagged.value))
+    }
+
+    /// Remove the key-value pair by index, and invalidate its handle.
+    ///
+    /// Valid indices are `0 <= index < self.len()`.
+    ///
+    /// Like [`OrderMap::swap_remove_index`], the pair is removed by swapping
+    /// it with the last element of the map and popping it off.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let (key, tagged) = self.inner.swap_remove_index(index)?;
+        self.slots.release(tagged.slot);
+        let moved = index..(index + 1).min(self.len());
+        self.slots.reindex(&self.inner, moved);
+        Some((key, tagged.value))
+    }
+
+    /// Remove the key-value pair for `handle`, and return it, or `None` if
+    /// the entry has already been removed.
+    ///
+    /// Like [`remove_index`][Self::remove_index], the pair is removed by
+    /// shifting all of the elements that follow it.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn remove_by_handle(&mut self, handle: EntryHandle) -> Option<(K, V)> {
+        self.remove_index(self.index_of(handle)?)
+    }
+
+    /// Remove the last key-value pair, and invalidate its handle.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        let (key, tagged) = self.inner.pop()?;
+        self.slots.release(tagged.slot);
+        Some((key, tagged.value))
+    }
+
+    /// Scan through each key-value pair in the map and keep those where the
+    /// closure `keep` returns `true`. The handles of the removed pairs become
+    /// invalid.
+    ///
+    /// The elements are visited in order, and remaining elements keep their
+    /// order.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let slots = &mut self.slots;
+        self.inner.retain(|key, tagged| {
+            let kept = keep(key, &mut tagged.value);
+            if !kept {
+                slots.release(tagged.slot);
+            }
+            kept
+        });
+        self.reindex_all();
+    }
+
+    /// Moves the position of a key-value pair from one index to another
+    /// by shifting all other pairs in-between. All handles stay valid.
+    ///
+    /// ***Panics*** if `from` or `to` are out of bounds.
+    ///
+    /// Computes in **O(n)** time (average).
+    #[track_caller]
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        self.inner.move_index(from, to);
+        self.slots
+            .reindex(&self.inner, from.min(to)..from.max(to) + 1);
+    }
+
+    /// Swaps the position of two key-value pairs in the map. All handles
+    /// stay valid.
+    ///
+    /// ***Panics*** if `a` or `b` are out of bounds.
+    ///
+    /// Computes in **O(1)** time (average).
+    #[track_caller]
+    pub fn swap_indices(&mut self, a: usize, b: usize) {
+        self.inner.swap_indices(a, b);
+        self.slots.reindex(&self.inner, a..a + 1);
+        self.slots.reindex(&self.inner, b..b + 1);
+    }
+
+    /// Reverses the order of the map's key-value pairs in place. All handles
+    /// stay valid.
+    ///
+    /// Computes in **O(n)** time and **O(1)** space.
+    pub fn reverse(&mut self) {
+        self.inner.reverse();
+        self.reindex_all();
+    }
+
+    /// Sort the map's key-value pairs by the default ordering of the keys.
+    /// All handles stay valid.
+    ///
+    /// See [`sort_by`](Self::sort_by) for details.
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.sort_by(|k1, _, k2, _| k1.cmp(k2));
+    }
+
+    /// Sort the map's key-value pairs in place using the comparison
+    /// function `cmp`. All handles stay valid.
+    ///
+    /// The comparison function receives two key and value pairs to compare (you
+    /// can sort by keys or values or their combination as needed).
+    ///
+    /// Computes in **O(n log n + c)** time and **O(n)** space where *n* is
+    /// the length of the map and *c* the capacity. The sort is stable.
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        self.inner
+            .sort_by(|k1, t1, k2, t2| cmp(k1, &t1.value, k2, &t2.value));
+        self.reindex_all();
+    }
+
+    /// Sort the map's key-value pairs in place using a sort-key extraction
+    /// function. All handles stay valid.
+    ///
+    /// Computes in **O(m n log n + c)** time and **O(n)** space, where the
+    /// function is **O(m)**, *n* is the length of the map, and *c* the
+    /// capacity. The sort is stable.
+    pub fn sort_by_key<T, F>(&mut self, mut sort_key: F)
+    where
+        T: Ord,
+        F: FnMut(&K, &V) -> T,
+    {
+        self.inner
+            .sort_by_key(|key, tagged| sort_key(key, &tagged.value));
+        self.reindex_all();
+    }
+
+    /// Sort the map's key-value pairs in place using the comparison function
+    /// `cmp`, but may not preserve the order of equal elements. All handles
+    /// stay valid.
+    ///
+    /// Computes in **O(n log n + c)** time where *n* is the length of the map
+    /// and *c* is the capacity. The sort is unstable.
+    pub fn sort_unstable_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        self.inner
+            .sort_unstable_by(|k1, t1, k2, t2| cmp(k1, &t1.value, k2, &t2.value));
+        self.reindex_all();
+    }
+
+    fn reindex_all(&mut self) {
+        self.slots.reindex(&self.inner, 0..self.inner.len());
+    }
+}
+
+impl<K, V, S> HandleMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Reserve capacity for `additional` more key-value pairs.
+    ///
+    /// Computes in **O(n)** time.
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional);
+    }
+
+    /// Insert a key-value pair in the map.
+    ///
+    /// If an equivalent key already exists in the map: the key remains and
+    /// retains in its place in the order, its corresponding value is updated
+    /// with `value`, and the older value is returned inside `Some(_)`.
+    ///
+    /// If no equivalent key existed in the map: the new key-value pair is
+    /// inserted, last in order, and `None` is returned.
+    ///
+    /// Computes in **O(1)** time (amortized average).
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.insert_full(key, value).1
+    }
+
+    /// Insert a key-value pair in the map, and get the handle of its entry.
+    ///
+    /// If an equivalent key already exists in the map: the key remains and
+    /// retains in its place in the order and its handle, its corresponding
+    /// value is updated with `value`, and the older value is returned inside
+    /// `(handle, Some(_))`.
+    ///
+    /// If no equivalent key existed in the map: the new key-value pair is
+    /// inserted, last in order, and `(handle, None)` is returned.
+    ///
+    /// Computes in **O(1)** time (amortized average).
+    pub fn insert_full(&mut self, key: K, value: V) -> (EntryHandle, Option<V>) {
+        match self.inner.entry(key) {
+            IndexEntry::Occupied(mut entry) => {
+                let tagged = entry.get_mut();
+                let old = core::mem::replace(&mut tagged.value, value);
+                (self.slots.handle(tagged.slot), Some(old))
+            }
+            IndexEntry::Vacant(entry) => {
+                let handle = self.slots.allocate(entry.index());
+                entry.insert(Tagged {
+                    slot: handle.slot,
+                    value,
+                });
+                (handle, None)
+            }
+        }
+    }
+
+    /// Return the handle of the entry for `key`, if it is present, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn handle<Q>(&self, key: &Q) -> Option<EntryHandle>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let tagged = self.inner.get(key)?;
+        Some(self.slots.handle(tagged.slot))
+    }
+
+    /// Return `true` if an equivalent to `key` exists in the map.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.inner.contains_key(key)
+    }
+
+    /// Return a reference to the value stored for `key`, if it is present,
+    /// else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        Some(&self.inner.get(key)?.value)
+    }
+
+    /// Return references to the stored key-value pair for the lookup `key`,
+    /// if it is present, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let (key, tagged) = self.inner.get_key_value(key)?;
+        Some((key, &tagged.value))
+    }
+
+    /// Return the index with references to the stored key-value pair for the
+    /// lookup `key`, if it is present, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_full<Q>(&self, key: &Q) -> Option<(usize, &K, &V)>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let (index, key, tagged) = self.inner.get_full(key)?;
+        Some((index, key, &tagged.value))
+    }
+
+    /// Return the index for `key`, if it is present, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.inner.get_index_of(key)
+    }
+
+    /// Return a mutable reference to the value stored for `key`, if it is
+    /// present, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        Some(&mut self.inner.get_mut(key)?.value)
+    }
+
+    /// Remove the key-value pair equivalent to `key` and return its value,
+    /// and invalidate its handle.
+    ///
+    /// Like [`OrderMap::remove`], the pair is removed by shifting all of the
+    /// elements that follow it, preserving their relative order.
+    ///
+    /// Return `None` if `key` is not in map.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Remove and return the key-value pair equivalent to `key`, and
+    /// invalidate its handle.
+    ///
+    /// See [`remove`][Self::remove] for details.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.remove_index(self.get_index_of(key)?)
+    }
+
+    /// Remove the key-value pair equivalent to `key` and return its value,
+    /// and invalidate its handle.
+    ///
+    /// Like [`OrderMap::swap_remove`], the pair is removed by swapping it
+    /// with the last element of the map and popping it off.
+    ///
+    /// Return `None` if `key` is not in map.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let index = self.get_index_of(key)?;
+        self.swap_remove_index(index).map(|(_, value)| value)
+    }
+}
+
+impl<K, V, Q, S> Index<&Q> for HandleMap<K, V, S>
+where
+    K: Hash + Eq,
+    Q: ?Sized + Hash + Equivalent<K>,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    /// Returns a reference to the value corresponding to the supplied `key`.
+    ///
+    /// ***Panics*** if `key` is not present in the map.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found")
+    }
+}
+
+impl<K, V, Q, S> IndexMut<&Q> for HandleMap<K, V, S>
+where
+    K: Hash + Eq,
+    Q: ?Sized + Hash + Equivalent<K>,
+    S: BuildHasher,
+{
+    /// Returns a mutable reference to the value corresponding to the supplied
+    /// `key`.
+    ///
+    /// ***Panics*** if `key` is not present in the map.
+    fn index_mut(&mut self, key: &Q) -> &mut V {
+        self.get_mut(key).expect("key not found")
+    }
+}
+
+impl<K, V, S> Index<EntryHandle> for HandleMap<K, V, S> {
+    type Output = V;
+
+    /// Returns a reference to the value corresponding to the supplied
+    /// `handle`.
+    ///
+    /// ***Panics*** if the entry of `handle` has been removed.
+    fn index(&self, handle: EntryHandle) -> &V {
+        match self.get_by_handle(handle) {
+            Some((_, value)) => value,
+            None => panic!("invalid entry handle"),
+        }
+    }
+}
+
+impl<K, V, S> IndexMut<EntryHandle> for HandleMap<K, V, S> {
+    /// Returns a mutable reference to the value corresponding to the supplied
+    /// `handle`.
+    ///
+    /// ***Panics*** if the entry of `handle` has been removed.
+    fn index_mut(&mut self, handle: EntryHandle) -> &mut V {
+        match self.get_by_handle_mut(handle) {
+            Some((_, value)) => value,
+            None => panic!("invalid entry handle"),
+        }
+    }
+}
+
+impl<K, V, S> Default for HandleMap<K, V, S>
+where
+    S: Default,
+{
+    /// Return an empty [`HandleMap`]
+    fn default() -> Self {
+        Self::with_capacity_and_hasher(0, S::default())
+    }
+}
+
+impl<K, V1, S1, V2, S2> PartialEq<HandleMap<K, V2, S2>> for HandleMap<K, V1, S1>
+where
+    K: PartialEq,
+    V1: PartialEq<V2>,
+{
+    fn eq(&self, other: &HandleMap<K, V2, S2>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|((k1, v1), (k2, v2))| k1 == k2 && v1 == v2)
+    }
+}
+
+impl<K, V, S> Eq for HandleMap<K, V, S>
+where
+    K: Eq,
+    V: Eq,
+{
+}
+
+impl<K, V, S> FromIterator<(K, V)> for HandleMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iterable: I) -> Self {
+        let mut map = Self::default();
+        map.extend(iterable);
+        map
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for HandleMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iterable: I) {
+        let iter = iterable.into_iter();
+        self.reserve(iter.size_hint().0);
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// Converts into a [`HandleMap`] with the same order and hasher.
+///
+/// Computes in **O(n)** time (average).
+impl<K, V, S> From<OrderMap<K, V, S>> for HandleMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Clone,
+{
+    fn from(map: OrderMap<K, V, S>) -> Self {
+        let mut handle_map = Self::with_capacity_and_hasher(map.len(), map.hasher().clone());
+        handle_map.extend(map);
+        handle_map
+    }
+}
+
+/// Converts into an [`OrderMap`] with the same order and hasher.
+///
+/// Computes in **O(n)** time (average).
+impl<K, V, S> From<HandleMap<K, V, S>> for OrderMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Clone,
+{
+    fn from(handle_map: HandleMap<K, V, S>) -> Self {
+        let mut map = Self::with_capacity_and_hasher(handle_map.len(), handle_map.hasher().clone());
+        map.extend(handle_map);
+        map
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a HandleMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut HandleMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V, S> IntoIterator for HandleMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.inner.into_iter(),
+        }
+    }
+}
+
+macro_rules! tagged_iterator {
+    ($name:ident<$($lt:lifetime,)? $K:ident, $V:ident>, $item:ty, $untag:expr) => {
+        impl<$($lt,)? $K, $V> Iterator for $name<$($lt,)? $K, $V> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.next().map($untag)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+        }
+
+        impl<$($lt,)? $K, $V> DoubleEndedIterator for $name<$($lt,)? $K, $V> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.iter.next_back().map($untag)
+            }
+        }
+
+        impl<$($lt,)? $K, $V> ExactSizeIterator for $name<$($lt,)? $K, $V> {}
+        impl<$($lt,)? $K, $V> FusedIterator for $name<$($lt,)? $K, $V> {}
+    };
+}
+
+/// An iterator over the entries of a [`HandleMap`].
+///
+/// This `struct` is created by the [`HandleMap::iter`] method.
+/// See its documentation for more.
+pub struct Iter<'a, K, V> {
+    iter: indexmap::map::Iter<'a, K, Tagged<V>>,
+}
+
+tagged_iterator!(Iter<'a, K, V>, (&'a K, &'a V), |(key, tagged)| (
+    key,
+    &tagged.value
+));
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Iter {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for Iter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// A mutable iterator over the entries of a [`HandleMap`].
+///
+/// This `struct` is created by the [`HandleMap::iter_mut`] method.
+/// See its documentation for more.
+pub struct IterMut<'a, K, V> {
+    iter: indexmap::map::IterMut<'a, K, Tagged<V>>,
+}
+
+tagged_iterator!(IterMut<'a, K, V>, (&'a K, &'a mut V), |(key, tagged)| (
+    key,
+    &mut tagged.value
+));
+
+/// An owning iterator over the entries of a [`HandleMap`].
+///
+/// This `struct` is created by the [`into_iter`] method on [`HandleMap`]
+/// (provided by the [`IntoIterator`] trait). See its documentation for more.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+pub struct IntoIter<K, V> {
+    iter: indexmap::map::IntoIter<K, Tagged<V>>,
+}
+
+tagged_iterator!(IntoIter<K, V>, (K, V), |(key, tagged)| (key, tagged.value));
+
+/// An iterator over the keys of a [`HandleMap`].
+///
+/// This `struct` is created by the [`HandleMap::keys`] method.
+/// See its documentation for more.
+pub struct Keys<'a, K, V> {
+    iter: indexmap::map::Keys<'a, K, Tagged<V>>,
+}
+
+tagged_iterator!(Keys<'a, K, V>, &'a K, |key| key);
+
+impl<K, V> Clone for Keys<'_, K, V> {
+    fn clone(&self) -> Self {
+        Keys {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<K: fmt::Debug, V> fmt::Debug for Keys<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// An iterator over the values of a [`HandleMap`].
+///
+/// This `struct` is created by the [`HandleMap::values`] method.
+/// See its documentation for more.
+pub struct Values<'a, K, V> {
+    iter: indexmap::map::Values<'a, K, Tagged<V>>,
+}
+
+tagged_iterator!(Values<'a, K, V>, &'a V, |tagged| &tagged.value);
+
+impl<K, V> Clone for Values<'_, K, V> {
+    fn clone(&self) -> Self {
+        Values {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<K, V: fmt::Debug> fmt::Debug for Values<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// A mutable iterator over the values of a [`HandleMap`].
+///
+/// This `struct` is created by the [`HandleMap::values_mut`] method.
+/// See its documentation for more.
+pub struct ValuesMut<'a, K, V> {
+    iter: indexmap::map::ValuesMut<'a, K, Tagged<V>>,
+}
+
+tagged_iterator!(ValuesMut<'a, K, V>, &'a mut V, |tagged| &mut tagged.value);
+
+/// An iterator over the handles of the entries of a [`HandleMap`].
+///
+/// This `struct` is created by the [`HandleMap::handles`] method.
+/// See its documentation for more.
+pub struct Handles<'a, K, V> {
+    iter: indexmap::map::Values<'a, K, Tagged<V>>,
+    slots: &'a Slots,
+}
+
+impl<K, V> Iterator for Handles<'_, K, V> {
+    type Item = EntryHandle;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let tagged = self.iter.next()?;
+        Some(self.slots.handle(tagged.slot))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Handles<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let tagged = self.iter.next_back()?;
+        Some(self.slots.handle(tagged.slot))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Handles<'_, K, V> {}
+impl<K, V> FusedIterator for Handles<'_, K, V> {}
+
+impl<K, V> Clone for Handles<'_, K, V> {
+    fn clone(&self) -> Self {
+        Handles {
+            iter: self.iter.clone(),
+            slots: self.slots,
+        }
+    }
+}
+
+impl<K, V> fmt::Debug for Handles<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
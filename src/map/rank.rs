@@ -0,0 +1,889 @@
+//! [`RankMap`] is an order-preserving hash map with **O(log n)** positional
+//! operations.
+//!
+//! An [`OrderMap`] keeps its entries in a dense vector, so inserting,
+//! removing, or moving an entry in the middle shifts all entries after it.
+//! A `RankMap` keeps its order in a balanced tree with subtree sizes
+//! (an order-statistics tree) instead, so it finds the entry at an index, or
+//! the index of an entry, in **O(log n)** time, and it inserts, removes, and
+//! moves entries anywhere in the order without shifting the rest.
+//!
+//! Lookups by key are still **O(1)** on average, and they don't need the
+//! index at all. A [`Handle`] from [`RankMap::handle`] computes the index of
+//! its entry only on demand.
+
+mod tree;
+
+use self::tree::Tree;
+use super::OrderMap;
+use crate::Equivalent;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FusedIterator;
+use core::ops::{Index, IndexMut};
+use indexmap::IndexMap;
+use indexmap::map::Entry as IndexEntry;
+
+#[cfg(feature = "std")]
+use std::hash::RandomState;
+
+/// A hash table with consistent order and **O(log n)** positional operations.
+///
+/// This is like an [`OrderMap`], but it keeps the order in a balanced tree,
+/// so that [`shift_insert`][Self::shift_insert], [`remove`][Self::remove],
+/// [`move_index`][Self::move_index], [`get_index`][Self::get_index], and
+/// [`get_index_of`][Self::get_index_of] all compute in **O(log n)** time
+/// (average), instead of **O(n)**. In exchange, appending, iterating, and
+/// looking up an index are slower by a constant factor or a logarithm, so an
+/// `OrderMap` is still the better choice for workloads that don't edit the
+/// middle of the order.
+///
+/// # Examples
+///
+/// ```
+/// use ordermap::map::RankMap;
+///
+/// let mut lines = RankMap::new();
+/// lines.insert("title", "Hello");
+/// lines.insert("body", "World");
+/// lines.shift_insert(1, "subtitle", "Greetings");
+/// assert!(lines.keys().eq(&["title", "subtitle", "body"]));
+///
+/// lines.move_index(0, 2);
+/// assert_eq!(lines.get_index_of("title"), Some(2));
+///
+/// let handle = lines.handle("body").unwrap();
+/// assert_eq!(handle.value(), &"World");
+/// assert_eq!(handle.index(), 1);
+/// ```
+#[cfg(feature = "std")]
+pub struct RankMap<K, V, S = RandomState> {
+    inner: IndexMap<K, V, S>,
+    tree: Tree,
+}
+#[cfg(not(feature = "std"))]
+pub struct RankMap<K, V, S> {
+    inner: IndexMap<K, V, S>,
+    tree: Tree,
+}
+
+// The entries of `inner` are in no particular order. Their storage index is
+// their slot in the `tree`, which orders them. Removals use `swap_remove`, so
+// the last slot moves into the hole, and the tree follows it.
+
+impl<K, V, S> Clone for RankMap<K, V, S>
+where
+    K: Clone,
+    V: Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        RankMap {
+            inner: self.inner.clone(),
+            tree: self.tree.clone(),
+        }
+    }
+}
+
+impl<K, V, S> fmt::Debug for RankMap<K, V, S>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<K, V> RankMap<K, V> {
+    /// Create a new map. (Does not allocate.)
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+
+    /// Create a new map with capacity for `n` key-value pairs. (Does not
+    /// allocate if `n` is zero.)
+    ///
+    /// Computes in **O(n)** time.
+    #[inline]
+    pub fn with_capacity(n: usize) -> Self {
+        Self::with_capacity_and_hasher(n, RandomState::new())
+    }
+}
+
+impl<K, V, S> RankMap<K, V, S> {
+    /// Create a new map with capacity for `n` key-value pairs. (Does not
+    /// allocate if `n` is zero.)
+    ///
+    /// Computes in **O(n)** time.
+    #[inline]
+    pub fn with_capacity_and_hasher(n: usize, hash_builder: S) -> Self {
+        RankMap {
+            inner: IndexMap::with_capacity_and_hasher(n, hash_builder),
+            tree: Tree::with_capacity(n),
+        }
+    }
+
+    /// Create a new map with `hash_builder`. (Does not allocate.)
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_capacity_and_hasher(0, hash_builder)
+    }
+
+    /// Return the number of key-value pairs in the map.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the map contains no elements.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Return a reference to the map's `BuildHasher`.
+    pub fn hasher(&self) -> &S {
+        self.inner.hasher()
+    }
+
+    /// Remove all key-value pairs in the map, while preserving its capacity.
+    ///
+    /// Computes in **O(n)** time.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+        self.tree.clear();
+    }
+
+    /// Return an iterator over the key-value pairs of the map, in their order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            entries: self.inner.as_slice(),
+            tree: &self.tree,
+            front: self.tree.first(),
+            back: self.tree.last(),
+            len: self.len(),
+        }
+    }
+
+    /// Return an iterator over the key-value pairs of the map, in their order,
+    /// with mutable access to the values.
+    ///
+    /// This gathers the entries up front, in **O(n)** time and space.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        let mut entries: Vec<_> = self.inner.iter_mut().map(Some).collect();
+        let ordered: Vec<_> = (self.tree.slots().into_iter())
+            .filter_map(|slot| entries[slot].take())
+            .collect();
+        IterMut {
+            iter: ordered.into_iter(),
+        }
+    }
+
+    /// Return an iterator over the keys of the map, in their order.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// Return an iterator over the values of the map, in their order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+
+    /// Get a key-value pair by index.
+    ///
+    /// Valid indices are `0 <= index < self.len()`.
+    ///
+    /// Computes in **O(log n)** time (average).
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.inner.get_index(self.tree.select(index)?)
+    }
+
+    /// Get a key-value pair by index, with mutable access to the value.
+    ///
+    /// Valid indices are `0 <= index < self.len()`.
+    ///
+    /// Computes in **O(log n)** time (average).
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.inner.get_index_mut(self.tree.select(index)?)
+    }
+
+    /// Get the first key-value pair.
+    ///
+    /// Computes in **O(log n)** time (average).
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.inner.get_index(self.tree.first()?)
+    }
+
+    /// Get the last key-value pair.
+    ///
+    /// Computes in **O(log n)** time (average).
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.inner.get_index(self.tree.last()?)
+    }
+
+    /// Moves the position of a key-value pair from one index to another
+    /// by shifting all other pairs in-between.
+    ///
+    /// * If `from < to`, the other pairs will shift down while the targeted pair moves up.
+    /// * If `from > to`, the other pairs will shift up while the targeted pair moves down.
+    ///
+    /// ***Panics*** if `from` or `to` are out of bounds.
+    ///
+    /// Computes in **O(log n)** time (average).
+    #[track_caller]
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        let len = self.len();
+        let slot = self.tree.select(from);
+        let slot =
+            slot.unwrap_or_else(|| panic!("from index {from} out of bounds for length {len}"));
+        assert!(to < len, "to index {to} out of bounds for length {len}");
+        self.tree.move_to(slot, to);
+    }
+
+    /// Swaps the position of two key-value pairs in the map.
+    ///
+    /// ***Panics*** if `a` or `b` are out of bounds.
+    ///
+    /// Computes in **O(log n)** time (average).
+    #[track_caller]
+    pub fn swap_indices(&mut self, a: usize, b: usize) {
+        let (a, b) = (a.min(b), a.max(b));
+        if a != b {
+            self.move_index(b, a);
+            self.move_index(a + 1, b);
+        } else {
+            let len = self.len();
+            assert!(a < len, "index {a} out of bounds for length {len}");
+        }
+    }
+
+    /// Remove the key-value pair at `index`, and shift all following pairs
+    /// down by one.
+    ///
+    /// Valid indices are `0 <= index < self.len()`.
+    ///
+    /// Computes in **O(log n)** time (average).
+    pub fn remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let slot = self.tree.select(index)?;
+        self.tree.swap_remove(slot);
+        self.inner.swap_remove_index(slot)
+    }
+
+    /// Remove the last key-value pair.
+    ///
+    /// Computes in **O(log n)** time (average).
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        self.remove_index(self.len().checked_sub(1)?)
+    }
+
+    /// Scan through each key-value pair in the map and keep those where the
+    /// closure `keep` returns `true`.
+    ///
+    /// The elements are visited in order, and remaining elements keep their
+    /// order.
+    ///
+    /// Computes in **O(n log n)** time (average).
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let mut index = 0;
+        while let Some(slot) = self.tree.select(index) {
+            let (key, value) = self.inner.get_index_mut(slot).unwrap();
+            if keep(key, value) {
+                index += 1;
+            } else {
+                self.tree.swap_remove(slot);
+                self.inner.swap_remove_index(slot);
+            }
+        }
+    }
+}
+
+impl<K, V, S> RankMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Reserve capacity for `additional` more key-value pairs.
+    ///
+    /// Computes in **O(n)** time.
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional);
+        self.tree.reserve(additional);
+    }
+
+    /// Insert a key-value pair in the map.
+    ///
+    /// If an equivalent key already exists in the map: the key remains and
+    /// retains in its place in the order, its corresponding value is updated
+    /// with `value`, and the older value is returned inside `Some(_)`.
+    ///
+    /// If no equivalent key existed in the map: the new key-value pair is
+    /// inserted, last in order, and `None` is returned.
+    ///
+    /// Computes in **O(log n)** time (amortized average).
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.inner.entry(key) {
+            IndexEntry::Occupied(mut entry) => Some(entry.insert(value)),
+            IndexEntry::Vacant(entry) => {
+                entry.insert(value);
+                self.tree.push_at(self.tree.len());
+                None
+            }
+        }
+    }
+
+    /// Insert a key-value pair in the map at the given index.
+    ///
+    /// If an equivalent key already exists in the map: the key remains and
+    /// is moved to the new position in the map, its corresponding value is updated
+    /// with `value`, and the older value is returned inside `Some(_)`. The returned index
+    /// will either be the given index or one less, depending on how other keys shift.
+    ///
+    /// If no equivalent key existed in the map: the new key-value pair is
+    /// inserted at the given index, and `None` is returned.
+    ///
+    /// ***Panics*** if `index` is out of bounds.
+    /// Valid indices are `0..map.len()` (exclusive) when moving an existing entry, or
+    /// `0..=map.len()` (inclusive) when inserting a new key.
+    ///
+    /// Computes in **O(log n)** time (average).
+    #[track_caller]
+    pub fn shift_insert(&mut self, index: usize, key: K, value: V) -> Option<V> {
+        let len = self.len();
+        match self.inner.entry(key) {
+            IndexEntry::Occupied(mut entry) => {
+                assert!(
+                    index < len,
+                    "index out of bounds: the len is {len} but the index is {index}"
+                );
+                let old = entry.insert(value);
+                self.tree.move_to(entry.index(), index);
+                Some(old)
+            }
+            IndexEntry::Vacant(entry) => {
+                assert!(
+                    index <= len,
+                    "index out of bounds: the len is {len} but the index is {index}"
+                );
+                entry.insert(value);
+                self.tree.push_at(index);
+                None
+            }
+        }
+    }
+
+    /// Return `true` if an equivalent to `key` exists in the map.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.inner.contains_key(key)
+    }
+
+    /// Return a reference to the value stored for `key`, if it is present,
+    /// else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.inner.get(key)
+    }
+
+    /// Return references to the stored key-value pair for the lookup `key`,
+    /// if it is present, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.inner.get_key_value(key)
+    }
+
+    /// Return a mutable reference to the value stored for `key`, if it is
+    /// present, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.inner.get_mut(key)
+    }
+
+    /// Return a handle to the stored key-value pair for the lookup `key`, if
+    /// it is present, else `None`.
+    ///
+    /// Computes in **O(1)** time (average). The handle computes the index of
+    /// the pair only when asked.
+    pub fn handle<Q>(&self, key: &Q) -> Option<Handle<'_, K, V>>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let (slot, key, value) = self.inner.get_full(key)?;
+        Some(Handle {
+            key,
+            value,
+            slot,
+            tree: &self.tree,
+        })
+    }
+
+    /// Return the index with references to the stored key-value pair for the
+    /// lookup `key`, if it is present, else `None`.
+    ///
+    /// Computes in **O(log n)** time (average).
+    pub fn get_full<Q>(&self, key: &Q) -> Option<(usize, &K, &V)>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let (slot, key, value) = self.inner.get_full(key)?;
+        Some((self.tree.rank(slot), key, value))
+    }
+
+    /// Return the index for `key`, if it is present, else `None`.
+    ///
+    /// Computes in **O(log n)** time (average).
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        Some(self.tree.rank(self.inner.get_index_of(key)?))
+    }
+
+    /// Remove the key-value pair equivalent to `key` and return its value.
+    ///
+    /// Like [`OrderMap::remove`], this preserves the order of the remaining
+    /// entries, shifting the indices of all that follow it, but without
+    /// touching them.
+    ///
+    /// Return `None` if `key` is not in map.
+    ///
+    /// Computes in **O(log n)** time (average).
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Remove and return the key-value pair equivalent to `key`.
+    ///
+    /// See [`remove`][Self::remove] for details.
+    ///
+    /// Computes in **O(log n)** time (average).
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let slot = self.inner.get_index_of(key)?;
+        self.tree.swap_remove(slot);
+        self.inner.swap_remove_index(slot)
+    }
+}
+
+/// A reference to a key-value pair in a [`RankMap`], which computes its
+/// index on demand.
+///
+/// This `struct` is created by the [`RankMap::handle`] method.
+/// See its documentation for more.
+pub struct Handle<'a, K, V> {
+    key: &'a K,
+    value: &'a V,
+    slot: usize,
+    tree: &'a Tree,
+}
+
+impl<'a, K, V> Handle<'a, K, V> {
+    /// Gets a reference to the entry's key in the map.
+    pub fn key(&self) -> &'a K {
+        self.key
+    }
+
+    /// Gets a reference to the entry's value in the map.
+    pub fn value(&self) -> &'a V {
+        self.value
+    }
+
+    /// Return the index of the key-value pair.
+    ///
+    /// Computes in **O(log n)** time (average).
+    pub fn index(&self) -> usize {
+        self.tree.rank(self.slot)
+    }
+}
+
+impl<K, V> Clone for Handle<'_, K, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V> Copy for Handle<'_, K, V> {}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for Handle<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Handle")
+            .field("key", self.key)
+            .field("value", self.value)
+            .finish()
+    }
+}
+
+impl<K, V, Q, S> Index<&Q> for RankMap<K, V, S>
+where
+    K: Hash + Eq,
+    Q: ?Sized + Hash + Equivalent<K>,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    /// Returns a reference to the value corresponding to the supplied `key`.
+    ///
+    /// ***Panics*** if `key` is not present in the map.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found")
+    }
+}
+
+impl<K, V, Q, S> IndexMut<&Q> for RankMap<K, V, S>
+where
+    K: Hash + Eq,
+    Q: ?Sized + Hash + Equivalent<K>,
+    S: BuildHasher,
+{
+    /// Returns a mutable reference to the value corresponding to the supplied
+    /// `key`.
+    ///
+    /// ***Panics*** if `key` is not present in the map.
+    fn index_mut(&mut self, key: &Q) -> &mut V {
+        self.get_mut(key).expect("key not found")
+    }
+}
+
+impl<K, V, S> Index<usize> for RankMap<K, V, S> {
+    type Output = V;
+
+    /// Returns a reference to the value at the supplied `index`.
+    ///
+    /// ***Panics*** if `index` is out of bounds.
+    fn index(&self, index: usize) -> &V {
+        match self.get_index(index) {
+            Some((_, value)) => value,
+            None => panic!(
+                "index out of bounds: the len is {len} but the index is {index}",
+                len = self.len()
+            ),
+        }
+    }
+}
+
+impl<K, V, S> IndexMut<usize> for RankMap<K, V, S> {
+    /// Returns a mutable reference to the value at the supplied `index`.
+    ///
+    /// ***Panics*** if `index` is out of bounds.
+    fn index_mut(&mut self, index: usize) -> &mut V {
+        let len = self.len();
+        match self.get_index_mut(index) {
+            Some((_, value)) => value,
+            None => panic!("index out of bounds: the len is {len} but the index is {index}"),
+        }
+    }
+}
+
+impl<K, V, S> Default for RankMap<K, V, S>
+where
+    S: Default,
+{
+    /// Return an empty [`RankMap`]
+    fn default() -> Self {
+        Self::with_capacity_and_hasher(0, S::default())
+    }
+}
+
+impl<K, V1, S1, V2, S2> PartialEq<RankMap<K, V2, S2>> for RankMap<K, V1, S1>
+where
+    K: PartialEq,
+    V1: PartialEq<V2>,
+{
+    fn eq(&self, other: &RankMap<K, V2, S2>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|((k1, v1), (k2, v2))| k1 == k2 && v1 == v2)
+    }
+}
+
+impl<K, V, S> Eq for RankMap<K, V, S>
+where
+    K: Eq,
+    V: Eq,
+{
+}
+
+impl<K, V, S> FromIterator<(K, V)> for RankMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iterable: I) -> Self {
+        let mut map = Self::default();
+        map.extend(iterable);
+        map
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for RankMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iterable: I) {
+        let iter = iterable.into_iter();
+        self.reserve(iter.size_hint().0);
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// Converts into a [`RankMap`] with the same order and hasher.
+///
+/// Computes in **O(n log n)** time (average).
+impl<K, V, S> From<OrderMap<K, V, S>> for RankMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Clone,
+{
+    fn from(map: OrderMap<K, V, S>) -> Self {
+        let mut rank_map = Self::with_capacity_and_hasher(map.len(), map.hasher().clone());
+        rank_map.extend(map);
+        rank_map
+    }
+}
+
+/// Converts into an [`OrderMap`] with the same order and hasher.
+///
+/// Computes in **O(n)** time (average).
+impl<K, V, S> From<RankMap<K, V, S>> for OrderMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Clone,
+{
+    fn from(rank_map: RankMap<K, V, S>) -> Self {
+        let mut map = Self::with_capacity_and_hasher(rank_map.len(), rank_map.hasher().clone());
+        map.extend(rank_map);
+        map
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a RankMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut RankMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V, S> IntoIterator for RankMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    /// Gathers the entries in order, in **O(n)** time and space.
+    fn into_iter(self) -> Self::IntoIter {
+        let slots = self.tree.slots();
+        let mut entries: Vec<_> = self.inner.into_iter().map(Some).collect();
+        let ordered: Vec<_> = (slots.into_iter())
+            .filter_map(|slot| entries[slot].take())
+            .collect();
+        IntoIter {
+            iter: ordered.into_iter(),
+        }
+    }
+}
+
+/// An iterator over the entries of a [`RankMap`].
+///
+/// This `struct` is created by the [`RankMap::iter`] method.
+/// See its documentation for more.
+pub struct Iter<'a, K, V> {
+    entries: &'a indexmap::map::Slice<K, V>,
+    tree: &'a Tree,
+    front: Option<usize>,
+    back: Option<usize>,
+    len: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let slot = self.front?;
+        self.front = self.tree.next(slot);
+        self.len -= 1;
+        self.entries.get_index(slot)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let slot = self.back?;
+        self.back = self.tree.prev(slot);
+        self.len -= 1;
+        self.entries.get_index(slot)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for Iter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// A mutable iterator over the entries of a [`RankMap`].
+///
+/// This `struct` is created by the [`RankMap::iter_mut`] method.
+/// See its documentation for more.
+pub struct IterMut<'a, K, V> {
+    iter: alloc::vec::IntoIter<(&'a K, &'a mut V)>,
+}
+
+/// An owning iterator over the entries of a [`RankMap`].
+///
+/// This `struct` is created by the [`into_iter`] method on [`RankMap`]
+/// (provided by the [`IntoIterator`] trait). See its documentation for more.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+pub struct IntoIter<K, V> {
+    iter: alloc::vec::IntoIter<(K, V)>,
+}
+
+macro_rules! vec_iterator {
+    ($name:ident<$($lt:lifetime,)? $K:ident, $V:ident>, $item:ty) => {
+        impl<$($lt,)? $K, $V> Iterator for $name<$($lt,)? $K, $V> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+        }
+
+        impl<$($lt,)? $K, $V> DoubleEndedIterator for $name<$($lt,)? $K, $V> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.iter.next_back()
+            }
+        }
+
+        impl<$($lt,)? $K, $V> ExactSizeIterator for $name<$($lt,)? $K, $V> {}
+        impl<$($lt,)? $K, $V> FusedIterator for $name<$($lt,)? $K, $V> {}
+    };
+}
+
+vec_iterator!(IterMut<'a, K, V>, (&'a K, &'a mut V));
+vec_iterator!(IntoIter<K, V>, (K, V));
+
+/// An iterator over the keys of a [`RankMap`].
+///
+/// This `struct` is created by the [`RankMap::keys`] method.
+/// See its documentation for more.
+#[derive(Clone, Debug)]
+pub struct Keys<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Keys<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+impl<K, V> FusedIterator for Keys<'_, K, V> {}
+
+/// An iterator over the values of a [`RankMap`].
+///
+/// This `struct` is created by the [`RankMap::values`] method.
+/// See its documentation for more.
+#[derive(Clone, Debug)]
+pub struct Values<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Values<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+impl<K, V> FusedIterator for Values<'_, K, V> {}
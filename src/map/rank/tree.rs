@@ -0,0 +1,340 @@
+//! An implicit treap that keeps the order of the slots of a `RankMap`.
+//!
+//! Each node is identified by its slot, the index of its entry in the map's
+//! storage. The in-order sequence of the tree is the map's order, and the
+//! subtree sizes give the rank of a node in **O(log n)** expected time.
+
+use alloc::vec::Vec;
+
+const NIL: usize = usize::MAX;
+
+#[derive(Clone, Debug)]
+struct Node {
+    parent: usize,
+    left: usize,
+    right: usize,
+    size: usize,
+    priority: u64,
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct Tree {
+    nodes: Vec<Node>,
+    root: usize,
+    /// The state of the priority generator.
+    seed: u64,
+}
+
+impl Tree {
+    pub(super) fn with_capacity(n: usize) -> Self {
+        Tree {
+            nodes: Vec::with_capacity(n),
+            root: NIL,
+            seed: 0,
+        }
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.nodes.clear();
+        self.root = NIL;
+    }
+
+    pub(super) fn reserve(&mut self, additional: usize) {
+        self.nodes.reserve(additional);
+    }
+
+    /// Adds a node for the next slot, and inserts it at position `index`.
+    pub(super) fn push_at(&mut self, index: usize) {
+        // SplitMix64, so priorities are well mixed without any global state.
+        self.seed = self.seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        self.nodes.push(Node {
+            parent: NIL,
+            left: NIL,
+            right: NIL,
+            size: 1,
+            priority: z ^ (z >> 31),
+        });
+        self.attach(self.nodes.len() - 1, index);
+    }
+
+    /// Removes the node of the last slot, after moving the node of `slot` to
+    /// be last. This mirrors `swap_remove_index` on the map's storage.
+    pub(super) fn swap_remove(&mut self, slot: usize) {
+        self.detach(slot);
+        let last = self.nodes.len() - 1;
+        if slot != last {
+            self.relink(last, slot);
+            self.nodes.swap(slot, last);
+        }
+        self.nodes.pop();
+    }
+
+    /// Moves the node of `slot` to position `index`.
+    pub(super) fn move_to(&mut self, slot: usize, index: usize) {
+        self.detach(slot);
+        self.attach(slot, index);
+    }
+
+    /// Returns the position of the node of `slot`.
+    pub(super) fn rank(&self, slot: usize) -> usize {
+        let mut x = slot;
+        let mut rank = self.size(self.nodes[x].left);
+        while self.nodes[x].parent != NIL {
+            let p = self.nodes[x].parent;
+            if self.nodes[p].right == x {
+                rank += self.size(self.nodes[p].left) + 1;
+            }
+            x = p;
+        }
+        rank
+    }
+
+    /// Returns the slot at position `index`, if it is in bounds.
+    pub(super) fn select(&self, mut index: usize) -> Option<usize> {
+        if index >= self.len() {
+            return None;
+        }
+        let mut x = self.root;
+        loop {
+            let left = self.size(self.nodes[x].left);
+            if index < left {
+                x = self.nodes[x].left;
+            } else if index == left {
+                return Some(x);
+            } else {
+                index -= left + 1;
+                x = self.nodes[x].right;
+            }
+        }
+    }
+
+    /// Returns the first slot in order.
+    pub(super) fn first(&self) -> Option<usize> {
+        (self.root != NIL).then(|| self.leftmost(self.root))
+    }
+
+    /// Returns the last slot in order.
+    pub(super) fn last(&self) -> Option<usize> {
+        (self.root != NIL).then(|| self.rightmost(self.root))
+    }
+
+    /// Returns the slot after `slot` in order.
+    pub(super) fn next(&self, slot: usize) -> Option<usize> {
+        let right = self.nodes[slot].right;
+        if right != NIL {
+            return Some(self.leftmost(right));
+        }
+        let mut x = slot;
+        loop {
+            let p = self.nodes[x].parent;
+            if p == NIL {
+                return None;
+            }
+            if self.nodes[p].left == x {
+                return Some(p);
+            }
+            x = p;
+        }
+    }
+
+    /// Returns the slot before `slot` in order.
+    pub(super) fn prev(&self, slot: usize) -> Option<usize> {
+        let left = self.nodes[slot].left;
+        if left != NIL {
+            return Some(self.rightmost(left));
+        }
+        let mut x = slot;
+        loop {
+            let p = self.nodes[x].parent;
+            if p == NIL {
+                return None;
+            }
+            if self.nodes[p].right == x {
+                return Some(p);
+            }
+            x = p;
+        }
+    }
+
+    /// Returns all slots in order.
+    pub(super) fn slots(&self) -> Vec<usize> {
+        let mut slots = Vec::with_capacity(self.len());
+        let mut next = self.first();
+        while let Some(slot) = next {
+            slots.push(slot);
+            next = self.next(slot);
+        }
+        slots
+    }
+
+    fn size(&self, x: usize) -> usize {
+        if x == NIL { 0 } else { self.nodes[x].size }
+    }
+
+    fn leftmost(&self, mut x: usize) -> usize {
+        while self.nodes[x].left != NIL {
+            x = self.nodes[x].left;
+        }
+        x
+    }
+
+    fn rightmost(&self, mut x: usize) -> usize {
+        while self.nodes[x].right != NIL {
+            x = self.nodes[x].right;
+        }
+        x
+    }
+
+    /// Inserts the detached node `x` at position `index`.
+    fn attach(&mut self, x: usize, index: usize) {
+        let (left, right) = self.split(self.root, index);
+        let left = self.merge(left, x);
+        self.root = self.merge(left, right);
+    }
+
+    /// Takes the node `x` out of the tree, leaving it as a single node.
+    fn detach(&mut self, x: usize) {
+        let (left, rest) = self.split(self.root, self.rank(x));
+        let (node, right) = self.split(rest, 1);
+        debug_assert_eq!(node, x);
+        self.root = self.merge(left, right);
+    }
+
+    /// Points all links to the node `from` at `to` instead, before the two
+    /// are swapped in storage.
+    fn relink(&mut self, from: usize, to: usize) {
+        let Node {
+            parent,
+            left,
+            right,
+            ..
+        } = self.nodes[from];
+        if parent == NIL {
+            self.root = to;
+        } else if self.nodes[parent].left == from {
+            self.nodes[parent].left = to;
+        } else {
+            self.nodes[parent].right = to;
+        }
+        for child in [left, right] {
+            if child != NIL {
+                self.nodes[child].parent = to;
+            }
+        }
+    }
+
+    /// Recomputes the size of `x` and the parent links of its children.
+    fn update(&mut self, x: usize) {
+        let Node { left, right, .. } = self.nodes[x];
+        self.nodes[x].size = 1 + self.size(left) + self.size(right);
+        for child in [left, right] {
+            if child != NIL {
+                self.nodes[child].parent = x;
+            }
+        }
+    }
+
+    /// Makes `child` the right or left child of `parent`, or a root if
+    /// `parent` is `NIL`.
+    fn link(&mut self, parent: usize, right: bool, child: usize) {
+        if parent != NIL {
+            if right {
+                self.nodes[parent].right = child;
+            } else {
+                self.nodes[parent].left = child;
+            }
+        }
+        if child != NIL {
+            self.nodes[child].parent = parent;
+        }
+    }
+
+    /// Updates `x` and all of its ancestors, from the bottom up.
+    fn update_path(&mut self, mut x: usize) {
+        while x != NIL {
+            self.update(x);
+            x = self.nodes[x].parent;
+        }
+    }
+
+    /// Splits the subtree `t` into its first `k` nodes and the rest, returning
+    /// both roots.
+    ///
+    /// This walks down a single path, so it needs no recursion even when the
+    /// tree has degenerated into a chain.
+    fn split(&mut self, t: usize, mut k: usize) -> (usize, usize) {
+        // The roots of both halves, and the nodes whose right (for the first
+        // half) or left (for the rest) link is open for the next node.
+        let (mut first, mut rest) = (NIL, NIL);
+        let (mut first_last, mut rest_last) = (NIL, NIL);
+        let mut x = t;
+        while x != NIL {
+            let left_size = self.size(self.nodes[x].left);
+            if k <= left_size {
+                let next = self.nodes[x].left;
+                if rest_last == NIL {
+                    rest = x;
+                }
+                self.link(rest_last, false, x);
+                rest_last = x;
+                x = next;
+            } else {
+                k -= left_size + 1;
+                let next = self.nodes[x].right;
+                if first_last == NIL {
+                    first = x;
+                }
+                self.link(first_last, true, x);
+                first_last = x;
+                x = next;
+            }
+        }
+        self.link(first_last, true, NIL);
+        self.link(rest_last, false, NIL);
+        self.update_path(first_last);
+        self.update_path(rest_last);
+        (first, rest)
+    }
+
+    /// Joins the subtrees `a` and `b`, in that order, returning the new root.
+    ///
+    /// Like [`split`][Self::split], this walks down a single path without
+    /// recursion.
+    fn merge(&mut self, mut a: usize, mut b: usize) -> usize {
+        let mut root = NIL;
+        // The last node placed on the merged path, and whether its open link
+        // is the right one, from `a`, or the left one, from `b`.
+        let mut last = NIL;
+        let mut right = false;
+        loop {
+            let (x, open_right) = if a == NIL || b == NIL {
+                (if a == NIL { b } else { a }, None)
+            } else if self.nodes[a].priority > self.nodes[b].priority {
+                let x = a;
+                a = self.nodes[a].right;
+                (x, Some(true))
+            } else {
+                let x = b;
+                b = self.nodes[b].left;
+                (x, Some(false))
+            };
+            if last == NIL {
+                root = x;
+            }
+            self.link(last, right, x);
+            match open_right {
+                Some(open_right) => (last, right) = (x, open_right),
+                None => break,
+            }
+        }
+        self.update_path(last);
+        root
+    }
+}
@@ -8,6 +8,7 @@ pub use indexmap::map::rayon::{
 };
 
 use super::OrderMap;
+use alloc::vec::Vec;
 use core::cmp::Ordering;
 use core::hash::{BuildHasher, Hash};
 use core::ops::RangeBounds;
@@ -215,6 +216,105 @@ where
     }
 }
 
+/// Parallel sorting methods that report the resulting permutation.
+///
+/// The following methods **require crate feature `"rayon"`**.
+impl<K, V, S> OrderMap<K, V, S>
+where
+    K: Sync,
+    V: Sync,
+{
+    /// Returns the order that sorting in parallel with the comparison function
+    /// `cmp` would produce, without modifying the map.
+    ///
+    /// See [`sorted_indices_by`][Self::sorted_indices_by] for the form of the
+    /// result.
+    pub fn par_sorted_indices_by<F>(&self, cmp: F) -> Vec<usize>
+    where
+        F: Fn(&K, &V, &K, &V) -> Ordering + Sync,
+    {
+        let entries: Vec<(&K, &V)> = self.iter().collect();
+        let mut indices: Vec<usize> = (0..entries.len()).collect();
+        indices.par_sort_by(|&a, &b| {
+            let ((k1, v1), (k2, v2)) = (entries[a], entries[b]);
+            cmp(k1, v1, k2, v2)
+        });
+        indices
+    }
+
+    /// Sort the map's key-value pairs in place and in parallel, using the comparison
+    /// function `cmp`, and return the permutation that was applied.
+    ///
+    /// See [`sort_by_with_permutation`][Self::sort_by_with_permutation] for the
+    /// form of the permutation.
+    pub fn par_sort_by_with_permutation<F>(&mut self, cmp: F) -> Vec<usize>
+    where
+        F: Fn(&K, &V, &K, &V) -> Ordering + Sync,
+    {
+        let indices = self.par_sorted_indices_by(cmp);
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the map's key-value pairs in place and in parallel, using a sort-key extraction
+    /// function, and return the permutation that was applied.
+    pub fn par_sort_by_key_with_permutation<T, F>(&mut self, sort_key: F) -> Vec<usize>
+    where
+        T: Ord,
+        F: Fn(&K, &V) -> T + Sync,
+    {
+        let entries: Vec<(&K, &V)> = self.iter().collect();
+        let mut indices: Vec<usize> = (0..entries.len()).collect();
+        indices.par_sort_by_key(|&i| sort_key(entries[i].0, entries[i].1));
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the map's key-value pairs in place and in parallel, using the comparison
+    /// function `cmp`, and return the permutation that was applied.
+    pub fn par_sort_unstable_by_with_permutation<F>(&mut self, cmp: F) -> Vec<usize>
+    where
+        F: Fn(&K, &V, &K, &V) -> Ordering + Sync,
+    {
+        let entries: Vec<(&K, &V)> = self.iter().collect();
+        let mut indices: Vec<usize> = (0..entries.len()).collect();
+        indices.par_sort_unstable_by(|&a, &b| {
+            let ((k1, v1), (k2, v2)) = (entries[a], entries[b]);
+            cmp(k1, v1, k2, v2)
+        });
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the map's key-value pairs in place and in parallel, using a sort-key extraction
+    /// function, and return the permutation that was applied.
+    pub fn par_sort_unstable_by_key_with_permutation<T, F>(&mut self, sort_key: F) -> Vec<usize>
+    where
+        T: Ord,
+        F: Fn(&K, &V) -> T + Sync,
+    {
+        let entries: Vec<(&K, &V)> = self.iter().collect();
+        let mut indices: Vec<usize> = (0..entries.len()).collect();
+        indices.par_sort_unstable_by_key(|&i| sort_key(entries[i].0, entries[i].1));
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the map's key-value pairs in place and in parallel, using a sort-key extraction
+    /// function, and return the permutation that was applied.
+    pub fn par_sort_by_cached_key_with_permutation<T, F>(&mut self, sort_key: F) -> Vec<usize>
+    where
+        T: Ord + Send,
+        F: Fn(&K, &V) -> T + Sync,
+    {
+        let entries: Vec<(&K, &V)> = self.iter().collect();
+        let mut indices: Vec<usize> = (0..entries.len()).collect();
+        indices.par_sort_by_cached_key(|&i| sort_key(entries[i].0, entries[i].1));
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+}
+
 impl<K, V, S> FromParallelIterator<(K, V)> for OrderMap<K, V, S>
 where
     K: Eq + Hash + Send,
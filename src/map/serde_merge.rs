@@ -0,0 +1,209 @@
+//! Functions to deserialize entries into an existing [`OrderMap`].
+//!
+//! This is useful for layering configuration, where each layer overrides some
+//! of the entries in the layers before it. Entries are inserted into the map
+//! as they are deserialized, without building a separate map first.
+//!
+//! Keys that are not in the input are left alone, and the [`MergeOrder`]
+//! decides where the merged keys end up. With [`merge_nested`], values that
+//! are maps themselves are merged one level deeper instead of being replaced.
+//!
+//! # Examples
+//!
+//! ```
+//! use ordermap::{OrderMap, ordermap};
+//! use ordermap::map::serde_merge::{self, MergeOrder};
+//! use serde::de::IntoDeserializer;
+//! use serde::de::value::Error;
+//!
+//! let mut map: OrderMap<char, u32> = ordermap! { 'a' => 1, 'b' => 2, 'c' => 3 };
+//!
+//! let layer = ordermap! { 'd' => 40, 'b' => 20 };
+//! let deserializer = IntoDeserializer::<Error>::into_deserializer(layer);
+//! serde_merge::merge(&mut map, deserializer, MergeOrder::KeepPositions).unwrap();
+//!
+//! assert!(map.into_iter().eq([('a', 1), ('b', 20), ('c', 3), ('d', 40)]));
+//! ```
+
+use super::{Entry, OrderMap};
+use crate::util::cautious_capacity;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use serde_core::de::{Deserialize, DeserializeSeed, Deserializer, MapAccess, Visitor};
+
+/// Where keys end up when they are merged into a map.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MergeOrder {
+    /// Existing keys keep their positions, and new keys are appended in the
+    /// order of the input.
+    #[default]
+    KeepPositions,
+    /// All merged keys are moved to the end of the map in the order of the
+    /// input, after the keys that were not in the input.
+    Append,
+}
+
+/// Deserializes a map into `map`, replacing the values of existing keys and
+/// inserting new keys, with the positions chosen by `order`.
+///
+/// If deserialization fails, the entries merged so far are kept in the map.
+pub fn merge<'de, D, K, V, S>(
+    map: &mut OrderMap<K, V, S>,
+    deserializer: D,
+    order: MergeOrder,
+) -> Result<(), D::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    S: BuildHasher,
+{
+    deserializer.deserialize_map(MergeVisitor { map, order })
+}
+
+/// Deserializes a map of maps into `map`, like [`merge`], except that the
+/// values of existing keys are merged into rather than replaced.
+///
+/// The nested maps are merged with the same `order`, and their own values
+/// are replaced.
+///
+/// # Examples
+///
+/// ```
+/// use ordermap::{OrderMap, ordermap};
+/// use ordermap::map::serde_merge::{self, MergeOrder};
+/// use serde::de::IntoDeserializer;
+/// use serde::de::value::Error;
+///
+/// let mut map: OrderMap<char, OrderMap<u32, u32>> = ordermap! {
+///     'a' => ordermap! { 1 => 10, 2 => 20 },
+///     'b' => ordermap! { 3 => 30 },
+/// };
+///
+/// let layer = ordermap! { 'a' => ordermap! { 2 => 21, 4 => 40 } };
+/// let deserializer = IntoDeserializer::<Error>::into_deserializer(layer);
+/// serde_merge::merge_nested(&mut map, deserializer, MergeOrder::Append).unwrap();
+///
+/// assert!(map.keys().eq(&['b', 'a']));
+/// assert!(map[&'a'].iter().eq(&ordermap! { 1 => 10, 2 => 21, 4 => 40 }));
+/// ```
+pub fn merge_nested<'de, D, K, K2, V2, S, S2>(
+    map: &mut OrderMap<K, OrderMap<K2, V2, S2>, S>,
+    deserializer: D,
+    order: MergeOrder,
+) -> Result<(), D::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de> + Eq + Hash,
+    K2: Deserialize<'de> + Eq + Hash,
+    V2: Deserialize<'de>,
+    S: BuildHasher,
+    S2: BuildHasher + Default,
+{
+    deserializer.deserialize_map(NestedVisitor { map, order })
+}
+
+fn insert<K, V, S>(map: &mut OrderMap<K, V, S>, key: K, value: V, order: MergeOrder)
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    match order {
+        MergeOrder::KeepPositions => {
+            map.insert(key, value);
+        }
+        MergeOrder::Append => {
+            map.insert_before(map.len(), key, value);
+        }
+    }
+}
+
+struct MergeVisitor<'a, K, V, S> {
+    map: &'a mut OrderMap<K, V, S>,
+    order: MergeOrder,
+}
+
+impl<'de, K, V, S> Visitor<'de> for MergeVisitor<'_, K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    S: BuildHasher,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<(), A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.map
+            .reserve(cautious_capacity::<K, V>(access.size_hint()));
+        while let Some((key, value)) = access.next_entry()? {
+            insert(self.map, key, value, self.order);
+        }
+        Ok(())
+    }
+}
+
+impl<'de, K, V, S> DeserializeSeed<'de> for MergeVisitor<'_, K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    S: BuildHasher,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+struct NestedVisitor<'a, K, V, S> {
+    map: &'a mut OrderMap<K, V, S>,
+    order: MergeOrder,
+}
+
+impl<'de, K, K2, V2, S, S2> Visitor<'de> for NestedVisitor<'_, K, OrderMap<K2, V2, S2>, S>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    K2: Deserialize<'de> + Eq + Hash,
+    V2: Deserialize<'de>,
+    S: BuildHasher,
+    S2: BuildHasher + Default,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map of maps")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<(), A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let order = self.order;
+        while let Some(key) = access.next_key()? {
+            let last = self.map.len().saturating_sub(1);
+            match self.map.entry(key) {
+                Entry::Occupied(mut entry) => {
+                    let map = entry.get_mut();
+                    access.next_value_seed(MergeVisitor { map, order })?;
+                    if order == MergeOrder::Append {
+                        entry.move_index(last);
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    // New keys are appended for either order.
+                    entry.insert(access.next_value()?);
+                }
+            }
+        }
+        Ok(())
+    }
+}
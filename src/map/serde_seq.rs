@@ -19,11 +19,19 @@
 //! ```
 
 use crate::OrderMap;
+use crate::util::cautious_capacity;
+use core::fmt;
 use core::hash::{BuildHasher, Hash};
+use core::marker::PhantomData;
 use indexmap::map::serde_seq as ix;
-use serde_core::de::{Deserialize, Deserializer};
+use serde_core::de::{Deserialize, DeserializeSeed, Deserializer, SeqAccess, Visitor};
 use serde_core::ser::{Serialize, Serializer};
 
+#[cfg(feature = "schemars")]
+use alloc::vec::Vec;
+#[cfg(feature = "schemars")]
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+
 /// Serializes an [`OrderMap`] as an ordered sequence.
 ///
 /// This function may be used in a field attribute for deriving [`Serialize`]:
@@ -72,3 +80,146 @@ where
         inner: ix::deserialize(deserializer)?,
     })
 }
+
+/// Generates the JSON Schema of an [`OrderMap`] in the ordered sequence
+/// format, which is an array of `[key, value]` pairs like `Vec<(K, V)>`.
+///
+/// This function may be used in a field attribute for deriving
+/// [`JsonSchema`], next to the attribute for `serde`:
+///
+/// ```
+/// # use ordermap::OrderMap;
+/// # use schemars::JsonSchema;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Deserialize, Serialize, JsonSchema)]
+/// struct Data {
+///     #[serde(with = "ordermap::map::serde_seq")]
+///     #[schemars(schema_with = "ordermap::map::serde_seq::json_schema::<i32, u64>")]
+///     map: OrderMap<i32, u64>,
+///     // ...
+/// }
+/// ```
+#[cfg(feature = "schemars")]
+#[cfg_attr(docsrs, doc(cfg(feature = "schemars")))]
+pub fn json_schema<K, V>(generator: &mut SchemaGenerator) -> Schema
+where
+    K: JsonSchema,
+    V: JsonSchema,
+{
+    Vec::<(K, V)>::json_schema(generator)
+}
+
+/// A [`DeserializeSeed`] for an [`OrderMap`] in the ordered sequence format,
+/// with a given hasher.
+///
+/// This is the counterpart of [`OrderMapSeed`][crate::map::OrderMapSeed] for
+/// [`deserialize`]: it reads a sequence of `(key, value)` elements, and
+/// carries the hasher instance instead of requiring `S: Default`.
+///
+/// # Examples
+///
+/// ```
+/// use ordermap::OrderMap;
+/// use ordermap::map::serde_seq::OrderMapSeqSeed;
+/// use serde::de::DeserializeSeed;
+/// use serde::de::value::{Error, MapDeserializer};
+/// use std::hash::RandomState;
+///
+/// // `MapDeserializer` can also be read as a sequence of pairs.
+/// let input = MapDeserializer::<_, Error>::new([(3, 'c'), (1, 'a')].into_iter());
+/// let map: OrderMap<i32, char> = OrderMapSeqSeed::new(RandomState::new()).deserialize(input).unwrap();
+/// assert!(map.into_iter().eq([(3, 'c'), (1, 'a')]));
+/// ```
+pub struct OrderMapSeqSeed<K, V, S> {
+    hasher: S,
+    capacity: Option<usize>,
+    marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<K, V, S> OrderMapSeqSeed<K, V, S> {
+    /// Creates a seed that deserializes a map using `hasher`.
+    pub fn new(hasher: S) -> Self {
+        Self::with_capacity_and_hasher(None, hasher)
+    }
+
+    /// Creates a seed that deserializes a map using `hasher`, with room for at
+    /// least `capacity` entries if that is `Some`.
+    ///
+    /// Without a capacity, this preallocates according to the deserializer's
+    /// size hint, within a cautious limit.
+    pub fn with_capacity_and_hasher(capacity: Option<usize>, hasher: S) -> Self {
+        OrderMapSeqSeed {
+            hasher,
+            capacity,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V, S: Clone> Clone for OrderMapSeqSeed<K, V, S> {
+    fn clone(&self) -> Self {
+        Self::with_capacity_and_hasher(self.capacity, self.hasher.clone())
+    }
+}
+
+impl<K, V, S: fmt::Debug> fmt::Debug for OrderMapSeqSeed<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrderMapSeqSeed")
+            .field("hasher", &self.hasher)
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+impl<'de, K, V, S> DeserializeSeed<'de> for OrderMapSeqSeed<K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    S: BuildHasher,
+{
+    type Value = OrderMap<K, V, S>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SeedSeqVisitor {
+            hasher: self.hasher,
+            capacity: self.capacity,
+            marker: PhantomData,
+        })
+    }
+}
+
+struct SeedSeqVisitor<K, V, S> {
+    hasher: S,
+    capacity: Option<usize>,
+    marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'de, K, V, S> Visitor<'de> for SeedSeqVisitor<K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    S: BuildHasher,
+{
+    type Value = OrderMap<K, V, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a sequenced map")
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let capacity = self
+            .capacity
+            .unwrap_or_else(|| cautious_capacity::<K, V>(access.size_hint()));
+        let mut map = OrderMap::with_capacity_and_hasher(capacity, self.hasher);
+        while let Some((key, value)) = access.next_element()? {
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+}
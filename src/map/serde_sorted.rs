@@ -0,0 +1,128 @@
+//! Functions to serialize an [`OrderMap`] with its keys in sorted order.
+//!
+//! This gives a canonical output that only depends on the contents of the
+//! map, not its insertion order, as needed for content hashing or reproducible
+//! output. The map itself is not modified: the entries are serialized through
+//! a temporary permutation of their indices, without cloning them.
+//!
+//! Deserialization is the same as the default, so the resulting map keeps the
+//! sorted order of its input.
+//!
+//! This module may be used in a field attribute for derived implementations:
+//!
+//! ```
+//! # use ordermap::OrderMap;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Deserialize, Serialize)]
+//! struct Data {
+//!     #[serde(with = "ordermap::map::serde_sorted")]
+//!     map: OrderMap<i32, u64>,
+//!     // ...
+//! }
+//! ```
+
+use super::OrderMap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::{BuildHasher, Hash};
+use serde_core::de::{Deserialize, Deserializer};
+use serde_core::ser::{Serialize, Serializer};
+
+/// Serializes an [`OrderMap`] as a map with its keys in sorted order.
+///
+/// This function may be used in a field attribute for deriving [`Serialize`]:
+///
+/// ```
+/// # use ordermap::OrderMap;
+/// # use serde::Serialize;
+/// #[derive(Serialize)]
+/// struct Data {
+///     #[serde(serialize_with = "ordermap::map::serde_sorted::serialize")]
+///     map: OrderMap<i32, u64>,
+///     // ...
+/// }
+/// ```
+///
+/// Computes in **O(n log n)** time and **O(n)** space.
+pub fn serialize<K, V, S, T>(map: &OrderMap<K, V, S>, serializer: T) -> Result<T::Ok, T::Error>
+where
+    K: Serialize + Ord,
+    V: Serialize,
+    T: Serializer,
+{
+    serialize_by(map, |k1, _, k2, _| Ord::cmp(k1, k2), serializer)
+}
+
+/// Serializes an [`OrderMap`] as a map with its entries sorted by the
+/// comparison function `cmp`.
+///
+/// The comparison function receives two key and value pairs to compare, like
+/// [`OrderMap::sort_by`]. The sort is stable, so entries that compare equal
+/// keep their relative order from the map.
+///
+/// This can be used for deriving [`Serialize`] through a small wrapper function:
+///
+/// ```
+/// # use ordermap::OrderMap;
+/// # use serde::{Serialize, Serializer};
+/// #[derive(Serialize)]
+/// struct Data {
+///     #[serde(serialize_with = "by_len")]
+///     map: OrderMap<String, u64>,
+///     // ...
+/// }
+///
+/// fn by_len<S: Serializer>(map: &OrderMap<String, u64>, serializer: S) -> Result<S::Ok, S::Error> {
+///     ordermap::map::serde_sorted::serialize_by(
+///         map,
+///         |k1, _, k2, _| k1.len().cmp(&k2.len()).then(k1.cmp(k2)),
+///         serializer,
+///     )
+/// }
+/// ```
+///
+/// Computes in **O(n log n)** time and **O(n)** space.
+pub fn serialize_by<K, V, S, F, T>(
+    map: &OrderMap<K, V, S>,
+    mut cmp: F,
+    serializer: T,
+) -> Result<T::Ok, T::Error>
+where
+    K: Serialize,
+    V: Serialize,
+    F: FnMut(&K, &V, &K, &V) -> Ordering,
+    T: Serializer,
+{
+    let entries = map.as_slice();
+    let mut order: Vec<usize> = (0..entries.len()).collect();
+    order.sort_by(|&i, &j| {
+        let (k1, v1) = entries.get_index(i).unwrap();
+        let (k2, v2) = entries.get_index(j).unwrap();
+        cmp(k1, v1, k2, v2)
+    });
+    serializer.collect_map(order.into_iter().map(|i| entries.get_index(i).unwrap()))
+}
+
+/// Deserializes an [`OrderMap`] from a map, the same as its [`Deserialize`] implementation.
+///
+/// This function may be used in a field attribute for deriving [`Deserialize`]:
+///
+/// ```
+/// # use ordermap::OrderMap;
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Data {
+///     #[serde(deserialize_with = "ordermap::map::serde_sorted::deserialize")]
+///     map: OrderMap<i32, u64>,
+///     // ...
+/// }
+/// ```
+pub fn deserialize<'de, D, K, V, S>(deserializer: D) -> Result<OrderMap<K, V, S>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    S: Default + BuildHasher,
+{
+    OrderMap::deserialize(deserializer)
+}
@@ -0,0 +1,198 @@
+//! Functions to serialize and deserialize an [`OrderMap`], rejecting duplicate keys.
+//!
+//! The default `serde` implementation quietly accepts input with duplicate
+//! keys, where a later value overwrites an earlier one and the key keeps its
+//! first position. This module fails deserialization instead, with an error
+//! naming the duplicate key and the positions where it appeared. Serialization
+//! is the same as the default.
+//!
+//! This module may be used in a field attribute for derived implementations:
+//!
+//! ```
+//! # use ordermap::OrderMap;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Deserialize, Serialize)]
+//! struct Data {
+//!     #[serde(with = "ordermap::map::serde_strict")]
+//!     map: OrderMap<i32, u64>,
+//!     // ...
+//! }
+//! ```
+//!
+//! The [`seq`] submodule does the same for the ordered sequence format of
+//! [`serde_seq`][super::serde_seq].
+
+use crate::OrderMap;
+use crate::util::cautious_capacity;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::marker::PhantomData;
+use serde_core::de::{Deserialize, Deserializer, Error, MapAccess, SeqAccess, Visitor};
+use serde_core::ser::{Serialize, Serializer};
+
+/// Serializes an [`OrderMap`] as a map, the same as its [`Serialize`] implementation.
+///
+/// This function may be used in a field attribute for deriving [`Serialize`]:
+///
+/// ```
+/// # use ordermap::OrderMap;
+/// # use serde::Serialize;
+/// #[derive(Serialize)]
+/// struct Data {
+///     #[serde(serialize_with = "ordermap::map::serde_strict::serialize")]
+///     map: OrderMap<i32, u64>,
+///     // ...
+/// }
+/// ```
+pub fn serialize<K, V, S, T>(map: &OrderMap<K, V, S>, serializer: T) -> Result<T::Ok, T::Error>
+where
+    K: Serialize,
+    V: Serialize,
+    T: Serializer,
+{
+    map.serialize(serializer)
+}
+
+/// Deserializes an [`OrderMap`] from a map, failing if any key is repeated.
+///
+/// This function may be used in a field attribute for deriving [`Deserialize`]:
+///
+/// ```
+/// # use ordermap::OrderMap;
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Data {
+///     #[serde(deserialize_with = "ordermap::map::serde_strict::deserialize")]
+///     map: OrderMap<i32, u64>,
+///     // ...
+/// }
+/// ```
+pub fn deserialize<'de, D, K, V, S>(deserializer: D) -> Result<OrderMap<K, V, S>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de> + Eq + Hash + fmt::Debug,
+    V: Deserialize<'de>,
+    S: Default + BuildHasher,
+{
+    deserializer.deserialize_map(StrictMapVisitor(PhantomData))
+}
+
+/// Functions to serialize and deserialize an [`OrderMap`] as an ordered
+/// sequence, rejecting duplicate keys.
+///
+/// This module may be used in a field attribute for derived implementations:
+///
+/// ```
+/// # use ordermap::OrderMap;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde(with = "ordermap::map::serde_strict::seq")]
+///     map: OrderMap<i32, u64>,
+///     // ...
+/// }
+/// ```
+pub mod seq {
+    use super::StrictSeqVisitor;
+    use crate::OrderMap;
+    use core::fmt;
+    use core::hash::{BuildHasher, Hash};
+    use core::marker::PhantomData;
+    use serde_core::de::{Deserialize, Deserializer};
+    use serde_core::ser::{Serialize, Serializer};
+
+    /// Serializes an [`OrderMap`] as an ordered sequence, the same as
+    /// [`serde_seq::serialize`][crate::map::serde_seq::serialize].
+    pub fn serialize<K, V, S, T>(map: &OrderMap<K, V, S>, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        K: Serialize,
+        V: Serialize,
+        T: Serializer,
+    {
+        crate::map::serde_seq::serialize(map, serializer)
+    }
+
+    /// Deserializes an [`OrderMap`] from an ordered sequence, failing if any
+    /// key is repeated.
+    pub fn deserialize<'de, D, K, V, S>(deserializer: D) -> Result<OrderMap<K, V, S>, D::Error>
+    where
+        D: Deserializer<'de>,
+        K: Deserialize<'de> + Eq + Hash + fmt::Debug,
+        V: Deserialize<'de>,
+        S: Default + BuildHasher,
+    {
+        deserializer.deserialize_seq(StrictSeqVisitor(PhantomData))
+    }
+}
+
+fn insert_unique<K, V, S, E>(map: &mut OrderMap<K, V, S>, key: K, value: V) -> Result<(), E>
+where
+    K: Eq + Hash + fmt::Debug,
+    S: BuildHasher,
+    E: Error,
+{
+    let position = map.len();
+    match map.insert_full(key, value) {
+        (_, None) => Ok(()),
+        (index, Some(_)) => {
+            let (key, _) = map.get_index(index).unwrap();
+            Err(E::custom(format_args!(
+                "duplicate key {key:?} at positions {index} and {position}"
+            )))
+        }
+    }
+}
+
+struct StrictMapVisitor<K, V, S>(PhantomData<(K, V, S)>);
+
+impl<'de, K, V, S> Visitor<'de> for StrictMapVisitor<K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash + fmt::Debug,
+    V: Deserialize<'de>,
+    S: Default + BuildHasher,
+{
+    type Value = OrderMap<K, V, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map without duplicate keys")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let capacity = cautious_capacity::<K, V>(access.size_hint());
+        let mut map = OrderMap::with_capacity_and_hasher(capacity, S::default());
+        while let Some((key, value)) = access.next_entry()? {
+            insert_unique(&mut map, key, value)?;
+        }
+        Ok(map)
+    }
+}
+
+struct StrictSeqVisitor<K, V, S>(PhantomData<(K, V, S)>);
+
+impl<'de, K, V, S> Visitor<'de> for StrictSeqVisitor<K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash + fmt::Debug,
+    V: Deserialize<'de>,
+    S: Default + BuildHasher,
+{
+    type Value = OrderMap<K, V, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a sequence of key-value pairs without duplicate keys")
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let capacity = cautious_capacity::<K, V>(access.size_hint());
+        let mut map = OrderMap::with_capacity_and_hasher(capacity, S::default());
+        while let Some((key, value)) = access.next_element()? {
+            insert_unique(&mut map, key, value)?;
+        }
+        Ok(map)
+    }
+}
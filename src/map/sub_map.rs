@@ -0,0 +1,225 @@
+use super::{Iter, Keys, OrderMap, Slice, Values};
+use crate::Equivalent;
+use crate::util::try_simplify_range;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::ops::{Index, Range, RangeBounds};
+
+/// A borrowed view of a range of entries in an [`OrderMap`], with hash
+/// lookups restricted to that range.
+///
+/// This is like the [`Slice`] of the same entries, except that it still has
+/// access to the map's hash table, so [`get`][Self::get] and the other
+/// lookups by key compute in **O(1)** time (average). Keys outside of the
+/// range are treated as if they were not in the map.
+///
+/// As for a `Slice`, all indices are relative to the start of the view.
+///
+/// This `struct` is created by the [`OrderMap::sub_map`] method.
+/// See its documentation for more.
+///
+/// # Examples
+///
+/// ```
+/// use ordermap::OrderMap;
+///
+/// let map: OrderMap<char, u32> = ('a'..='f').zip(0..).collect();
+/// let sub = map.sub_map(2..5).unwrap();
+/// assert_eq!(sub.len(), 3);
+/// assert_eq!(sub.get(&'d'), Some(&3));
+/// assert_eq!(sub.get_index_of(&'d'), Some(1));
+/// assert_eq!(sub.get(&'a'), None);
+/// assert!(sub.keys().eq(&['c', 'd', 'e']));
+/// ```
+pub struct SubMap<'a, K, V, S> {
+    map: &'a OrderMap<K, V, S>,
+    range: Range<usize>,
+}
+
+impl<'a, K, V, S> SubMap<'a, K, V, S> {
+    pub(super) fn new(map: &'a OrderMap<K, V, S>, range: Range<usize>) -> Self {
+        debug_assert!(range.start <= range.end && range.end <= map.len());
+        SubMap { map, range }
+    }
+
+    /// Returns the range of indices in the whole map that this view covers.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the entries of this view as a slice.
+    pub fn as_slice(&self) -> &'a Slice<K, V> {
+        &self.map.as_slice()[self.range.clone()]
+    }
+
+    /// Returns the whole map that this view borrows from.
+    pub fn map(&self) -> &'a OrderMap<K, V, S> {
+        self.map
+    }
+
+    /// Return the number of key-value pairs in the view.
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Returns true if the view contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    /// Get a key-value pair by index, relative to the start of the view.
+    pub fn get_index(&self, index: usize) -> Option<(&'a K, &'a V)> {
+        self.as_slice().get_index(index)
+    }
+
+    /// Get the first key-value pair.
+    pub fn first(&self) -> Option<(&'a K, &'a V)> {
+        self.as_slice().first()
+    }
+
+    /// Get the last key-value pair.
+    pub fn last(&self) -> Option<(&'a K, &'a V)> {
+        self.as_slice().last()
+    }
+
+    /// Returns a narrower view of the given range of indices, relative to
+    /// the start of this view, or `None` if the range is out of bounds.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn sub_map<R: RangeBounds<usize>>(&self, range: R) -> Option<Self> {
+        let range = try_simplify_range(range, self.len()).ok()?;
+        let start = self.range.start;
+        Some(SubMap::new(
+            self.map,
+            start + range.start..start + range.end,
+        ))
+    }
+
+    /// Return an iterator over the key-value pairs of the view, in order.
+    pub fn iter(&self) -> Iter<'a, K, V> {
+        self.as_slice().iter()
+    }
+
+    /// Return an iterator over the keys of the view, in order.
+    pub fn keys(&self) -> Keys<'a, K, V> {
+        self.as_slice().keys()
+    }
+
+    /// Return an iterator over the values of the view, in order.
+    pub fn values(&self) -> Values<'a, K, V> {
+        self.as_slice().values()
+    }
+}
+
+impl<'a, K, V, S> SubMap<'a, K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Return `true` if an equivalent to `key` exists in the view.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.get_index_of(key).is_some()
+    }
+
+    /// Return a reference to the value stored for `key`, if it is present in
+    /// the view, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get<Q>(&self, key: &Q) -> Option<&'a V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.get_full(key).map(|(_, _, value)| value)
+    }
+
+    /// Return references to the stored key-value pair for the lookup `key`,
+    /// if it is present in the view, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&'a K, &'a V)>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.get_full(key).map(|(_, key, value)| (key, value))
+    }
+
+    /// Return the index relative to the start of the view, with references
+    /// to the stored key-value pair for the lookup `key`, if it is present
+    /// in the view, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_full<Q>(&self, key: &Q) -> Option<(usize, &'a K, &'a V)>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let (index, key, value) = self.map.get_full(key)?;
+        self.range
+            .contains(&index)
+            .then(|| (index - self.range.start, key, value))
+    }
+
+    /// Return the index relative to the start of the view for `key`, if it
+    /// is present in the view, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.get_full(key).map(|(index, _, _)| index)
+    }
+}
+
+impl<K, V, S> Clone for SubMap<'_, K, V, S> {
+    fn clone(&self) -> Self {
+        SubMap {
+            map: self.map,
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for SubMap<'_, K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, Q, S> Index<&Q> for SubMap<'_, K, V, S>
+where
+    K: Hash + Eq,
+    Q: ?Sized + Hash + Equivalent<K>,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    /// Returns a reference to the value corresponding to the supplied `key`.
+    ///
+    /// ***Panics*** if `key` is not present in the view.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found")
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for SubMap<'a, K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &SubMap<'a, K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -1292,3 +1292,818 @@ fn is_sorted_trivial() {
     map.reverse();
     expect(&map, [false, true, false, true, false]);
 }
+
+#[test]
+fn try_positional_oob() {
+    let mut map: OrderMap<i32, i32> = (0..5).map(|i| (i, i * 10)).collect();
+    let copy = map.clone();
+    fn oob<T>(index: usize, len: usize) -> Result<T, IndexOutOfBounds> {
+        Err(IndexOutOfBounds { index, len })
+    }
+
+    fn rejected<T, I>(index: usize, len: usize, item: I) -> Result<T, InsertOutOfBounds<I>> {
+        let error = IndexOutOfBounds { index, len };
+        Err(InsertOutOfBounds { error, item })
+    }
+
+    assert_eq!(map.try_insert_before(6, 9, 90), rejected(6, 5, (9, 90)));
+    assert_eq!(map.try_shift_insert(6, 9, 90), rejected(6, 5, (9, 90)));
+    assert_eq!(map.try_shift_insert(5, 0, 1), rejected(5, 5, (0, 1)));
+    assert_eq!(map.try_move_index(5, 0), oob(5, 5));
+    assert_eq!(map.try_move_index(0, 5), oob(5, 5));
+    assert_eq!(map.try_swap_indices(0, 7), oob(7, 5));
+    assert_eq!(
+        map.try_split_off(6).unwrap_err(),
+        IndexOutOfBounds { index: 6, len: 5 }
+    );
+    assert_eq!(
+        map.try_drain(..=5).unwrap_err(),
+        IndexOutOfBounds { index: 5, len: 5 }
+    );
+    assert_eq!(
+        map.try_drain((core::ops::Bound::Included(3), core::ops::Bound::Excluded(2)))
+            .unwrap_err(),
+        IndexOutOfBounds { index: 3, len: 2 }
+    );
+    assert_eq!(
+        map.try_splice(6.., []).unwrap_err(),
+        IndexOutOfBounds { index: 6, len: 5 }
+    );
+    assert_eq!(map, copy);
+
+    assert_eq!(map.try_insert_before(5, 9, 90), Ok((5, None)));
+    assert_eq!(map.try_shift_insert(0, 9, 91), Ok(Some(90)));
+    assert_eq!(map.try_move_index(0, 5), Ok(()));
+    assert_eq!(map.try_swap_indices(0, 1), Ok(()));
+    assert_eq!(
+        map.try_drain(4..).unwrap().collect::<Vec<_>>(),
+        [(4, 40), (9, 91)]
+    );
+    assert_eq!(map.try_split_off(4).unwrap().len(), 0);
+    assert!(map.keys().eq(&[1, 0, 2, 3]));
+}
+
+#[test]
+fn try_entry_positional_oob() {
+    let mut map: OrderMap<i32, i32> = (0..5).map(|i| (i, i * 10)).collect();
+    fn oob<T>(index: usize, len: usize) -> Result<T, IndexOutOfBounds> {
+        Err(IndexOutOfBounds { index, len })
+    }
+
+    match map.entry(2) {
+        Entry::Occupied(e) => assert_eq!(e.try_move_index(5), oob(5, 5)),
+        Entry::Vacant(_) => unreachable!(),
+    }
+    match map.entry(2) {
+        Entry::Occupied(e) => assert_eq!(e.try_swap_indices(0), Ok(())),
+        Entry::Vacant(_) => unreachable!(),
+    }
+    match map.entry(9) {
+        Entry::Occupied(_) => unreachable!(),
+        Entry::Vacant(e) => {
+            let err = e.try_shift_insert(6, 90).unwrap_err();
+            assert_eq!(err.error, IndexOutOfBounds { index: 6, len: 5 });
+            assert_eq!(err.item, (9, 90));
+        }
+    }
+    match map.entry(9) {
+        Entry::Occupied(_) => unreachable!(),
+        Entry::Vacant(e) => assert_eq!(e.try_shift_insert(5, 90).copied(), Ok(90)),
+    }
+    let entry = map.get_index_entry(5).unwrap();
+    assert_eq!(entry.try_move_index(6), oob(6, 6));
+    let entry = map.get_index_entry(5).unwrap();
+    assert_eq!(entry.try_move_index(0), Ok(()));
+    let entry = map.first_entry().unwrap();
+    assert_eq!(entry.try_swap_indices(6), oob(6, 6));
+    let entry = OccupiedEntry::from(map.last_entry().unwrap());
+    assert_eq!(entry.try_swap_indices(0), Ok(()));
+    assert!(map.keys().eq(&[4, 2, 1, 0, 3, 9]));
+}
+
+#[test]
+fn relative_positioning() {
+    let mut map: OrderMap<i32, i32> = (0..6).map(|i| (i, i)).collect();
+
+    // Moving next to itself is a no-op.
+    assert_eq!(map.move_before(&2, &2), Ok(2));
+    assert_eq!(map.move_after(&2, &2), Ok(2));
+    assert_eq!(map.insert_before_key(&2, 2, 20), Ok((2, Some(2))));
+    assert_eq!(map.insert_after(&2, 2, 2), Ok((2, Some(20))));
+    assert!(map.keys().eq(&[0, 1, 2, 3, 4, 5]));
+
+    for key in 0..6 {
+        for anchor in 0..6 {
+            let mut before = map.clone();
+            let index = before.move_before(&key, &anchor).unwrap();
+            assert_eq!(before.get_index_of(&key), Some(index));
+            if key != anchor {
+                assert_eq!(before.get_index_of(&anchor), Some(index + 1));
+            }
+
+            let mut after = map.clone();
+            let index = after.move_after(&key, &anchor).unwrap();
+            assert_eq!(after.get_index_of(&key), Some(index));
+            if key != anchor {
+                assert_eq!(after.get_index_of(&anchor), Some(index - 1));
+            }
+        }
+    }
+
+    assert_eq!(map.swap_keys(&0, &5), Ok(()));
+    assert_eq!(map.swap_keys(&9, &5), Err(RelativePositionError::NotFound));
+    assert_eq!(
+        map.swap_keys(&0, &9),
+        Err(RelativePositionError::AnchorNotFound)
+    );
+    assert_eq!(
+        map.move_after(&0, &9),
+        Err(RelativePositionError::AnchorNotFound)
+    );
+    let err = map.insert_after(&9, 7, 7).unwrap_err();
+    assert_eq!(err.item, (7, 7));
+    assert_eq!(
+        RelativePositionError::from(err),
+        RelativePositionError::AnchorNotFound
+    );
+    assert!(map.keys().eq(&[5, 1, 2, 3, 4, 0]));
+}
+
+#[test]
+fn apply_permutation() {
+    let mut map: OrderMap<i32, i32> = (0..5).map(|i| (i, i * 10)).collect();
+    map.apply_permutation(&[3, 0, 4, 1, 2]).unwrap();
+    assert!(map.keys().eq(&[3, 0, 4, 1, 2]));
+    for (i, (k, v)) in map.iter().enumerate() {
+        assert_eq!(map.get_index_of(k), Some(i));
+        assert_eq!(*v, k * 10);
+    }
+
+    let before = map.clone();
+    assert_eq!(
+        map.apply_permutation(&[0, 1, 2, 3]),
+        Err(PermutationError::LengthMismatch {
+            len: 5,
+            perm_len: 4
+        })
+    );
+    assert_eq!(
+        map.apply_permutation(&[0, 1, 5, 3, 4]),
+        Err(PermutationError::IndexOutOfBounds { index: 5, len: 5 })
+    );
+    assert_eq!(
+        map.apply_permutation(&[0, 1, 2, 0, 4]),
+        Err(PermutationError::DuplicateIndex { index: 0 })
+    );
+    assert_eq!(map, before);
+
+    let mut empty = OrderMap::<i32, i32>::new();
+    empty.apply_permutation(&[]).unwrap();
+}
+
+#[test]
+fn rotate_and_move_range() {
+    let len = 7;
+    let fresh = || -> OrderMap<usize, usize> { (0..len).map(|i| (i, i)).collect() };
+    let check = |map: &OrderMap<usize, usize>, expected: &[usize]| {
+        assert!(map.keys().eq(expected));
+        for (i, k) in map.keys().enumerate() {
+            assert_eq!(map.get_index_of(k), Some(i));
+            assert_eq!(map[k], *k);
+        }
+    };
+
+    for mid in 0..=len {
+        let mut expected: Vec<usize> = (0..len).collect();
+        let mut map = fresh();
+        expected.rotate_left(mid);
+        map.rotate_left(mid);
+        check(&map, &expected);
+
+        let mut expected: Vec<usize> = (0..len).collect();
+        let mut map = fresh();
+        expected.rotate_right(mid);
+        map.rotate_right(mid);
+        check(&map, &expected);
+    }
+
+    for start in 0..=len {
+        for end in start..=len {
+            for to in 0..=len - (end - start) {
+                let mut expected: Vec<usize> = (0..len).collect();
+                let block: Vec<usize> = expected.drain(start..end).collect();
+                expected.splice(to..to, block);
+
+                let mut map = fresh();
+                map.move_range(start..end, to);
+                check(&map, &expected);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic = "destination index"]
+fn move_range_oob() {
+    let mut map: OrderMap<i32, ()> = (0..5).map(|i| (i, ())).collect();
+    map.move_range(1..3, 4);
+}
+
+#[test]
+fn sort_with_permutation() {
+    let pairs = [(5, 'a'), (3, 'b'), (8, 'c'), (1, 'd'), (3, 'e'), (9, 'f')];
+    let original: OrderMap<char, i32> = pairs.iter().map(|&(v, k)| (k, v)).collect();
+    let check = |map: &OrderMap<char, i32>, indices: &[usize]| {
+        let gathered: Vec<_> = indices
+            .iter()
+            .map(|&i| original.get_index(i).unwrap())
+            .collect();
+        assert!(map.iter().eq(gathered));
+        for (i, k) in map.keys().enumerate() {
+            assert_eq!(map.get_index_of(k), Some(i));
+        }
+    };
+
+    let indices = original.sorted_indices_by(|_, v1, _, v2| v1.cmp(v2));
+    assert_eq!(indices, [3, 1, 4, 0, 2, 5]);
+
+    let mut map = original.clone();
+    assert_eq!(
+        map.sort_by_with_permutation(|_, v1, _, v2| v1.cmp(v2)),
+        indices
+    );
+    check(&map, &indices);
+
+    let mut map = original.clone();
+    assert_eq!(map.sort_by_key_with_permutation(|_, &v| v), indices);
+    check(&map, &indices);
+
+    let mut map = original.clone();
+    assert_eq!(map.sort_by_cached_key_with_permutation(|_, &v| v), indices);
+    check(&map, &indices);
+
+    let mut map = original.clone();
+    let unstable = map.sort_unstable_by_with_permutation(|_, v1, _, v2| v2.cmp(v1));
+    check(&map, &unstable);
+    assert!(map.values().is_sorted_by(|a, b| a >= b));
+
+    let mut map = original.clone();
+    let unstable = map.sort_unstable_by_key_with_permutation(|&k, _| k);
+    check(&map, &unstable);
+    assert!(map.keys().is_sorted());
+
+    #[cfg(feature = "rayon")]
+    {
+        assert_eq!(
+            original.par_sorted_indices_by(|_, v1, _, v2| v1.cmp(v2)),
+            indices
+        );
+
+        let mut map = original.clone();
+        assert_eq!(
+            map.par_sort_by_with_permutation(|_, v1, _, v2| v1.cmp(v2)),
+            indices
+        );
+        check(&map, &indices);
+
+        let mut map = original.clone();
+        assert_eq!(
+            map.par_sort_by_cached_key_with_permutation(|_, &v| v),
+            indices
+        );
+        check(&map, &indices);
+    }
+}
+
+#[test]
+fn sort_by_reference() {
+    let original: OrderMap<i32, i32> = [7, 3, 9, 1, 4, 8, 2].iter().map(|&k| (k, -k)).collect();
+    let reference = OrderSet::from([2, 5, 9, 7, 1]);
+    let check = |map: &OrderMap<i32, i32>, expected: &[i32]| {
+        assert!(map.keys().eq(expected));
+        for (i, (k, v)) in map.iter().enumerate() {
+            assert_eq!(map.get_index_of(k), Some(i));
+            assert_eq!(*v, -k);
+        }
+    };
+
+    let mut map = original.clone();
+    map.sort_by_reference(&reference, ReorderPolicy::AbsentLast);
+    check(&map, &[2, 9, 7, 1, 3, 4, 8]);
+
+    let mut map = original.clone();
+    map.sort_by_reference(&reference, ReorderPolicy::AbsentFirst);
+    check(&map, &[3, 4, 8, 2, 9, 7, 1]);
+
+    let mut map = original.clone();
+    map.sort_by_reference(&reference, ReorderPolicy::AbsentInPlace);
+    check(&map, &[2, 3, 9, 7, 4, 8, 1]);
+
+    // Matches sorting by each key's position in the reference.
+    let mut expected = original.clone();
+    expected.sort_by_cached_key(|k, _| reference.get_index_of(k).unwrap_or(usize::MAX));
+    let mut map = original.clone();
+    let other: OrderMap<i32, ()> = reference.iter().map(|&k| (k, ())).collect();
+    map.reorder_like(&other, ReorderPolicy::AbsentLast);
+    assert_eq!(map, expected);
+
+    let mut map = original.clone();
+    map.sort_by_reference(&OrderSet::<i32>::new(), ReorderPolicy::AbsentFirst);
+    assert_eq!(map, original);
+}
+
+#[test]
+fn sort_topologically() {
+    let deps_of = |k: &u8| -> Vec<u8> {
+        match k {
+            b'a' => vec![b'c', b'x', b'c'], // missing and repeated dependencies
+            b'b' => vec![],
+            b'c' => vec![b'e'],
+            b'd' => vec![b'b'],
+            _ => vec![],
+        }
+    };
+    let mut map: OrderMap<u8, usize> = b"abcde".iter().enumerate().map(|(i, &k)| (k, i)).collect();
+    map.sort_topologically(|k, _| deps_of(k)).unwrap();
+    assert!(map.keys().eq(b"bdeca"));
+    for (i, k) in map.keys().enumerate() {
+        assert_eq!(map.get_index_of(k), Some(i));
+    }
+
+    // Already in a valid order, so nothing moves.
+    let before = map.clone();
+    map.sort_topologically(|k, _| deps_of(k)).unwrap();
+    assert_eq!(map, before);
+
+    // A self-dependency is a cycle of one.
+    let err = map.sort_topologically(|&k, _| [k]).unwrap_err();
+    assert_eq!(err.cycle, [&b'b']);
+
+    let err = map
+        .sort_topologically(|&k, _| match k {
+            b'c' => vec![b'a'],
+            _ => deps_of(&k),
+        })
+        .unwrap_err();
+    assert_eq!(err.cycle, [&b'c', &b'a']);
+    assert_eq!(map, before);
+
+    let empty = OrderMap::<u8, ()>::new();
+    assert_eq!(empty.topological_order(|_, _| &[] as &[u8]), Ok(vec![]));
+}
+
+#[test]
+fn tombstone_map_matches_order_map() {
+    use super::tombstone::TombstoneMap;
+
+    let mut rng = fastrand::Rng::with_seed(42);
+    let mut model = OrderMap::new();
+    let mut map = TombstoneMap::new();
+    for step in 0..5000 {
+        let key = rng.u32(0..200);
+        match rng.u8(0..4) {
+            0 | 1 => assert_eq!(map.insert(key, step), model.insert(key, step)),
+            2 => assert_eq!(map.remove_entry(&key), model.remove_entry(&key)),
+            _ => {
+                let index = rng.usize(0..=model.len());
+                assert_eq!(map.remove_index(index), model.remove_index(index));
+            }
+        }
+        assert!(map.tombstones() <= map.len().max(1));
+        assert_eq!(map.len(), model.len());
+        assert_eq!(map.get_full(&key), model.get_full(&key));
+
+        let index = rng.usize(0..=model.len());
+        assert_eq!(map.get_index(index), model.get_index(index));
+    }
+    assert!(map.iter().eq(&model));
+    assert!(map.iter().rev().eq(model.iter().rev()));
+    assert_eq!(map.first(), model.first());
+    assert_eq!(map.last(), model.last());
+    for (index, (key, value)) in model.iter().enumerate() {
+        assert_eq!(map.get_index_of(key), Some(index));
+        assert_eq!(map[key], *value);
+    }
+
+    let compacted = map.clone();
+    map.compact();
+    assert_eq!(map.tombstones(), 0);
+    assert_eq!(map, compacted);
+
+    map.retain(|&key, _| key % 2 == 0);
+    model.retain(|&key, _| key % 2 == 0);
+    assert!(map.iter().eq(&model));
+    assert_eq!(OrderMap::from(map), model);
+}
+
+#[test]
+fn tombstone_map_reinsert() {
+    use super::tombstone::TombstoneMap;
+
+    let mut map: TombstoneMap<&str, i32> = [("a", 1), ("b", 2), ("c", 3), ("d", 4)]
+        .into_iter()
+        .collect();
+    assert_eq!(map.remove(&"b"), Some(2));
+    assert_eq!(map.tombstones(), 1);
+    assert_eq!(map.get(&"b"), None);
+    assert!(!map.contains_key(&"b"));
+
+    // A removed key is appended like any new key.
+    assert_eq!(map.insert("b", 20), None);
+    assert!(map.keys().eq(&["a", "c", "d", "b"]));
+    assert_eq!(map.get_index_of(&"b"), Some(3));
+    assert_eq!(map.get_index_of(&"d"), Some(2));
+
+    // Tombstones are compacted once they outnumber the live entries.
+    map.remove(&"a");
+    map.remove(&"c");
+    assert_eq!(map.tombstones(), 0);
+    assert!(map.values().eq(&[4, 20]));
+    assert_eq!(std::format!("{map:?}"), r#"{"d": 4, "b": 20}"#);
+}
+
+#[test]
+fn rank_map_matches_order_map() {
+    use super::rank::RankMap;
+
+    let mut rng = fastrand::Rng::with_seed(42);
+    let mut model = OrderMap::new();
+    let mut map = RankMap::new();
+    for step in 0..5000 {
+        let key = rng.u32(0..200);
+        match rng.u8(0..7) {
+            0 | 1 => assert_eq!(map.insert(key, step), model.insert(key, step)),
+            2 => {
+                let index = rng.usize(0..=model.len());
+                if model.contains_key(&key) && index == model.len() {
+                    continue;
+                }
+                assert_eq!(
+                    map.shift_insert(index, key, step),
+                    model.shift_insert(index, key, step)
+                );
+            }
+            3 => assert_eq!(map.remove_entry(&key), model.remove_entry(&key)),
+            4 => {
+                let index = rng.usize(0..=model.len());
+                assert_eq!(map.remove_index(index), model.remove_index(index));
+            }
+            5 if !model.is_empty() => {
+                let (from, to) = (rng.usize(0..model.len()), rng.usize(0..model.len()));
+                map.move_index(from, to);
+                model.move_index(from, to);
+            }
+            _ if !model.is_empty() => {
+                let (a, b) = (rng.usize(0..model.len()), rng.usize(0..model.len()));
+                map.swap_indices(a, b);
+                model.swap_indices(a, b);
+            }
+            _ => {}
+        }
+        assert_eq!(map.len(), model.len());
+        assert_eq!(map.get_full(&key), model.get_full(&key));
+        if let Some(handle) = map.handle(&key) {
+            assert_eq!(Some(handle.index()), model.get_index_of(&key));
+        }
+
+        let index = rng.usize(0..=model.len());
+        assert_eq!(map.get_index(index), model.get_index(index));
+    }
+    assert!(map.iter().eq(&model));
+    assert!(map.iter().rev().eq(model.iter().rev()));
+    assert!(map.iter_mut().eq(model.iter_mut()));
+    assert_eq!(map.first(), model.first());
+    assert_eq!(map.last(), model.last());
+    for (index, (key, value)) in model.iter().enumerate() {
+        assert_eq!(map.get_index_of(key), Some(index));
+        assert_eq!(map[key], *value);
+        assert_eq!(map[index], *value);
+    }
+
+    map.retain(|&key, _| key % 2 == 0);
+    model.retain(|&key, _| key % 2 == 0);
+    assert!(map.iter().eq(&model));
+    assert_eq!(map.pop(), model.pop());
+    assert_eq!(RankMap::from(model.clone()), map);
+    assert_eq!(OrderMap::from(map), model);
+}
+
+#[test]
+fn rank_map_degenerate_order() {
+    use super::rank::RankMap;
+
+    // The priorities that the rank tree draws for its first inserts. Placing
+    // each key by its priority turns the tree into a single chain.
+    let mut seed = 0u64;
+    let priorities: Vec<u64> = (0..5000)
+        .map(|_| {
+            seed = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        })
+        .collect();
+
+    let check = move || {
+        let mut map = RankMap::new();
+        let mut sorted = Vec::new();
+        for (key, &priority) in priorities.iter().enumerate() {
+            let index = sorted.partition_point(|&p| p < priority);
+            sorted.insert(index, priority);
+            map.shift_insert(index, key, ());
+        }
+
+        let mut expected: Vec<usize> = (0..priorities.len()).collect();
+        expected.sort_by_key(|&key| priorities[key]);
+        assert!(map.keys().eq(&expected));
+        for (index, key) in expected.iter().enumerate().step_by(97) {
+            assert_eq!(map.get_index_of(key), Some(index));
+        }
+
+        map.move_index(0, expected.len() - 1);
+        expected.rotate_left(1);
+        assert_eq!(map.remove_index(0), Some((expected.remove(0), ())));
+        assert!(map.keys().eq(&expected));
+    };
+
+    // The chain is far deeper than this stack allows for a recursive walk.
+    std::thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(check)
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn remove_indices() {
+    let mut rng = fastrand::Rng::with_seed(7);
+    for len in 0..40 {
+        let mut map: OrderMap<usize, usize> = (0..len).map(|i| (i, i * 10)).collect();
+        let mut indices: Vec<usize> = (0..len).filter(|_| rng.bool()).collect();
+        rng.shuffle(&mut indices);
+
+        let mut expected = map.clone();
+        let mut sorted = indices.clone();
+        sorted.sort_unstable();
+        let removed: Vec<_> = (sorted.iter().rev())
+            .map(|&i| expected.remove_index(i).unwrap())
+            .collect();
+
+        let result = map.remove_indices(indices.iter().copied()).unwrap();
+        assert!(result.iter().eq(removed.iter().rev()));
+        assert_eq!(map, expected);
+        for (i, key) in map.keys().enumerate() {
+            assert_eq!(map.get_index_of(key), Some(i));
+        }
+    }
+
+    let mut map: OrderMap<i32, i32> = (0..5).map(|i| (i, i)).collect();
+    assert_eq!(
+        map.remove_indices([1, 3, 1]),
+        Err(RemoveIndicesError::DuplicateIndex { index: 1 })
+    );
+    assert_eq!(
+        map.remove_indices([0, 5]),
+        Err(RemoveIndicesError::IndexOutOfBounds { index: 5, len: 5 })
+    );
+    assert_eq!(map.len(), 5);
+    assert_eq!(map.remove_indices([]), Ok(Vec::new()));
+
+    assert_eq!(map.remove_keys(&[4, 0, 9, 0]), [(0, 0), (4, 4)]);
+    assert!(map.keys().eq(&[1, 2, 3]));
+    assert_eq!(map.get_index_of(&3), Some(2));
+}
+
+#[test]
+fn handle_map_handles_follow_entries() {
+    use super::handle::HandleMap;
+
+    let mut rng = fastrand::Rng::with_seed(3);
+    let mut model = OrderMap::new();
+    let mut map = HandleMap::new();
+    let mut removed = Vec::new();
+    for step in 0..3000 {
+        let key = rng.u32(0..100);
+        match rng.u8(0..8) {
+            0 | 1 => {
+                let (handle, old) = map.insert_full(key, step);
+                assert_eq!(old, model.insert(key, step));
+                assert_eq!(map.index_of(handle), model.get_index_of(&key));
+            }
+            2 => {
+                let handle = map.handle(&key);
+                assert_eq!(map.remove(&key), model.remove(&key));
+                removed.extend(handle);
+            }
+            3 => {
+                let index = rng.usize(0..=model.len());
+                let handle = map.handle_at(index);
+                assert_eq!(map.swap_remove_index(index), model.swap_remove_index(index));
+                removed.extend(handle);
+            }
+            4 if !model.is_empty() => {
+                let (from, to) = (rng.usize(0..model.len()), rng.usize(0..model.len()));
+                map.move_index(from, to);
+                model.move_index(from, to);
+            }
+            5 if !model.is_empty() => {
+                let (a, b) = (rng.usize(0..model.len()), rng.usize(0..model.len()));
+                map.swap_indices(a, b);
+                model.swap_indices(a, b);
+            }
+            6 => {
+                map.sort_by(|_, v1, _, v2| (v1 % 7).cmp(&(v2 % 7)));
+                model.sort_by(|_, v1, _, v2| (v1 % 7).cmp(&(v2 % 7)));
+            }
+            _ => {
+                map.retain(|&key, _| key != step % 100);
+                model.retain(|&key, _| key != step % 100);
+            }
+        }
+        assert!(map.iter().eq(&model));
+    }
+
+    for (index, handle) in map.handles().enumerate() {
+        assert_eq!(map.index_of(handle), Some(index));
+        let (key, value) = map.get_by_handle(handle).unwrap();
+        assert_eq!(model.get_full(key), Some((index, key, value)));
+        assert_eq!(map.handle(key), Some(handle));
+        assert_eq!(map[handle], *value);
+    }
+    for handle in removed {
+        assert!(!map.contains_handle(handle));
+        assert_eq!(map.get_by_handle(handle), None);
+    }
+
+    let handles: Vec<_> = map.handles().collect();
+    map.reverse();
+    assert!(map.handles().eq(handles.iter().rev().copied()));
+    map.clear();
+    assert!(handles.iter().all(|&handle| map.index_of(handle).is_none()));
+}
+
+#[test]
+fn cursor_mut() {
+    let mut map: OrderMap<i32, i32> = (0..4).map(|i| (i, i)).collect();
+    let mut cursor = map.cursor_front_mut();
+    assert_eq!(cursor.peek_prev(), None);
+    cursor.move_prev();
+    assert_eq!(cursor.index(), None);
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.peek_next(), Some((&0, &mut 0)));
+    assert_eq!(cursor.peek_prev(), Some((&3, &mut 3)));
+
+    // From the ghost, insert_after goes first and insert_before goes last.
+    assert_eq!(cursor.insert_after(-1, -1), None);
+    assert_eq!(cursor.insert_before(4, 4), None);
+    assert_eq!(cursor.index(), None);
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some((&-1, &mut -1)));
+    assert!(cursor.as_map().keys().eq(&[-1, 0, 1, 2, 3, 4]));
+
+    // Existing keys move next to the cursor, which follows its entry.
+    let mut cursor = map.cursor_at_key(&2).unwrap();
+    assert_eq!(cursor.insert_before(4, 40), Some(4));
+    assert_eq!(cursor.insert_after(0, 10), Some(0));
+    assert_eq!(cursor.insert_after(2, 20), Some(2));
+    assert_eq!(cursor.current(), Some((&2, &mut 20)));
+    assert_eq!(cursor.index(), Some(3));
+    assert!(cursor.as_map().keys().eq(&[-1, 1, 4, 2, 0, 3]));
+
+    assert_eq!(cursor.remove_current(), Some((2, 20)));
+    assert_eq!(cursor.current(), Some((&0, &mut 10)));
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.remove_current(), None);
+    assert!(
+        map.iter()
+            .eq([(&-1, &-1), (&1, &1), (&4, &40), (&0, &10), (&3, &3)])
+    );
+    for (i, key) in map.keys().enumerate() {
+        assert_eq!(map.get_index_of(key), Some(i));
+    }
+
+    let mut cursor = map.cursor_at(5);
+    assert_eq!(cursor.index(), None);
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some((&3, &mut 3)));
+}
+
+#[test]
+#[should_panic = "index out of bounds"]
+fn cursor_at_oob() {
+    let mut map: OrderMap<i32, i32> = (0..4).map(|i| (i, i)).collect();
+    map.cursor_at(5);
+}
+
+#[test]
+fn range_between_keys() {
+    let map: OrderMap<i32, i32> = (0..10).map(|i| (i * 10, i)).collect();
+    assert!(
+        map.range_between(&20, &50)
+            .unwrap()
+            .keys()
+            .eq(&[20, 30, 40])
+    );
+    assert_eq!(map.range_between_inclusive(&20, &50).unwrap().len(), 4);
+    assert!(map.range_between(&20, &20).unwrap().is_empty());
+    assert_eq!(map.range_between_inclusive(&20, &20).unwrap().len(), 1);
+    assert_eq!(map.range_between(&50, &20), None);
+    assert_eq!(map.range_between_inclusive(&50, &40), None);
+    assert_eq!(map.range_between(&20, &55), None);
+    assert!(map.range_from_key(&80).unwrap().values().eq(&[8, 9]));
+    assert_eq!(map.range_to_key(&0).unwrap().len(), 0);
+    assert_eq!(map.range_to_key(&5), None);
+}
+
+#[test]
+fn sub_map() {
+    let map: OrderMap<i32, i32> = (0..10).map(|i| (i * 10, i)).collect();
+    assert!(map.sub_map(5..11).is_none());
+    let sub = map.sub_map(2..8).unwrap();
+    assert_eq!(sub.range(), 2..8);
+    assert_eq!(sub.get(&20), Some(&2));
+    assert_eq!(sub.get(&10), None);
+    assert_eq!(sub.get(&80), None);
+    assert_eq!(sub.get_full(&70), Some((5, &70, &7)));
+    assert!(!sub.contains_key(&90));
+    assert_eq!(sub[&40], 4);
+    assert_eq!(sub.first(), Some((&20, &2)));
+    assert_eq!(sub.last(), Some((&70, &7)));
+
+    let narrower = sub.sub_map(1..=2).unwrap();
+    assert_eq!(narrower.range(), 3..5);
+    assert_eq!(narrower.get_index_of(&40), Some(1));
+    assert_eq!(narrower.get(&20), None);
+    assert!(narrower.into_iter().eq([(&30, &3), (&40, &4)]));
+    assert!(sub.sub_map(..7).is_none());
+    assert!(map.sub_map(10..).unwrap().is_empty());
+}
+
+#[test]
+fn order_predicates() {
+    let map: OrderMap<i32, char> = (1..=5).zip('a'..).collect();
+    let prefix: OrderMap<i32, char> = (1..=2).zip('a'..).collect();
+    let suffix: OrderMap<i32, char> = (4..=5).zip('d'..).collect();
+    let other_values: OrderMap<i32, ()> = (1..=5).map(|i| (i, ())).collect();
+    let empty = OrderMap::<i32, char>::new();
+
+    assert!(map.starts_with(&prefix) && map.starts_with(&empty) && map.starts_with(&map));
+    assert!(!prefix.starts_with(&map));
+    assert!(map.ends_with(&suffix) && !map.ends_with(&prefix) && !suffix.ends_with(&map));
+    assert!(prefix.is_prefix_of(&map) && !suffix.is_prefix_of(&map));
+    assert_eq!(map.common_prefix_len(&prefix), 2);
+    assert_eq!(map.common_prefix_len(&suffix), 0);
+
+    let mut sub: OrderMap<i32, char> = [(2, 'b'), (5, 'e')].into();
+    assert!(sub.is_subsequence_of(&map) && empty.is_subsequence_of(&map));
+    assert!(!map.is_subsequence_of(&sub));
+    sub.reverse();
+    assert!(!sub.is_subsequence_of(&map));
+    assert!(!OrderMap::from([(2, 'x')]).is_subsequence_of(&map));
+
+    let mut shuffled = map.clone();
+    shuffled.reverse();
+    assert!(shuffled.is_permutation_of(&map) && !prefix.is_permutation_of(&map));
+    shuffled[&3] = 'z';
+    assert!(!shuffled.is_permutation_of(&map));
+
+    // Key-only variants ignore values, which may differ in type.
+    assert!(map.keys_start_with(&other_values) && other_values.keys_end_with(&suffix));
+    assert!(prefix.keys_are_prefix_of(&other_values));
+    assert!(!map.starts_with(&shuffled) && map.common_key_prefix_len(&shuffled) == 0);
+    assert!(shuffled.keys_are_permutation_of(&other_values));
+    assert!(OrderMap::from([(1, 0), (3, 0)]).keys_are_subsequence_of(&map));
+    assert!(!OrderMap::from([(3, 0), (1, 0)]).keys_are_subsequence_of(&map));
+}
+
+#[test]
+fn partition_and_chunks() {
+    let map: OrderMap<i32, i32> = (0..10).map(|i| (i, i * i)).collect();
+    let (small, large) = map.clone().partition(|_, &v| v < 20);
+    assert!(small.keys().eq(&[0, 1, 2, 3, 4]));
+    assert!(large.keys().eq(&[5, 6, 7, 8, 9]));
+    assert_eq!(large.get_index_of(&7), Some(2));
+    assert_eq!(large[&7], 49);
+
+    let mut head = map.clone();
+    let tail = head.split_by_key(&6).unwrap();
+    assert!(head.keys().copied().eq(0..6));
+    assert!(tail.keys().copied().eq(6..10));
+    assert_eq!(head.split_by_key(&6), None);
+
+    for size in 1..12 {
+        let chunks = map.clone().chunk_into(size);
+        assert_eq!(chunks.len(), map.len().div_ceil(size));
+        assert!(
+            chunks
+                .iter()
+                .all(|chunk| 0 < chunk.len() && chunk.len() <= size)
+        );
+        assert!(chunks.iter().all(|chunk| chunk.capacity() <= size));
+        assert!(chunks.iter().flatten().eq(&map));
+    }
+    assert!(OrderMap::<i32, i32>::new().chunk_into(3).is_empty());
+}
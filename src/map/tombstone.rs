@@ -0,0 +1,866 @@
+//! [`TombstoneMap`] is an order-preserving hash map with lazy removal.
+//!
+//! Removing from an [`OrderMap`] shifts all later entries, so removing many
+//! keys one at a time from a large map is quadratic. A `TombstoneMap` instead
+//! leaves a *tombstone* in place of each removed entry, so removal doesn't
+//! move anything, and the tombstones are cleaned out later by
+//! [`compact`][TombstoneMap::compact] or automatically.
+//!
+//! # Logical indices
+//!
+//! Tombstones are invisible: iteration and lookups skip them, and every
+//! index-based method uses *logical* indices, counting only live entries in
+//! the range `0..map.len()`. Converting between logical indices and internal
+//! positions takes **O(log n)** time, so index-based methods are slower than
+//! their [`OrderMap`] counterparts.
+
+use super::OrderMap;
+use crate::Equivalent;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{BuildHasher, Hash, Hasher};
+use core::iter::FusedIterator;
+use core::ops::{Index, IndexMut};
+use indexmap::IndexMap;
+use indexmap::map::MutableKeys;
+
+#[cfg(feature = "std")]
+use std::hash::RandomState;
+
+/// A hash table with consistent order and lazy, order-preserving removal.
+///
+/// This is like an [`OrderMap`], except that removal leaves a tombstone in
+/// the order instead of shifting all later entries. See the
+/// [module documentation](self) for how this affects indices.
+///
+/// Tombstones are removed by [`compact`][Self::compact], or automatically
+/// once they outnumber the live entries, so their memory overhead is bounded
+/// and removal is amortized **O(log n)**.
+///
+/// Re-inserting a removed key appends it at the end, as for any new key.
+///
+/// # Examples
+///
+/// ```
+/// use ordermap::map::TombstoneMap;
+///
+/// let mut map: TombstoneMap<i32, i32> = (0..10).map(|i| (i, i * i)).collect();
+/// for key in [1, 2, 5, 8] {
+///     map.remove(&key);
+/// }
+/// assert_eq!(map.len(), 6);
+/// assert_eq!(map.tombstones(), 4);
+/// assert!(map.keys().eq(&[0, 3, 4, 6, 7, 9]));
+///
+/// // Indices are logical, skipping the tombstones.
+/// assert_eq!(map.get_index_of(&6), Some(3));
+/// assert_eq!(map.get_index(3), Some((&6, &36)));
+///
+/// map.compact();
+/// assert_eq!(map.tombstones(), 0);
+/// assert!(map.keys().eq(&[0, 3, 4, 6, 7, 9]));
+/// ```
+#[cfg(feature = "std")]
+pub struct TombstoneMap<K, V, S = RandomState> {
+    inner: IndexMap<Slot<K>, Option<V>, S>,
+    dead: DeadCounts,
+}
+#[cfg(not(feature = "std"))]
+pub struct TombstoneMap<K, V, S> {
+    inner: IndexMap<Slot<K>, Option<V>, S>,
+    dead: DeadCounts,
+}
+
+/// A key that is taken out when its entry is removed.
+///
+/// Live slots hash just like their key. A removed slot isn't equivalent to
+/// any key, so lookups pass over it, even though the table still holds the
+/// hash of its former key.
+#[derive(Clone, PartialEq, Eq)]
+struct Slot<K>(Option<K>);
+
+impl<K: Hash> Hash for Slot<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Only live slots are hashed, when they are inserted.
+        if let Some(key) = &self.0 {
+            key.hash(state);
+        }
+    }
+}
+
+/// A lookup key that only matches live slots.
+struct Live<'a, Q: ?Sized>(&'a Q);
+
+impl<Q: ?Sized + Hash> Hash for Live<'_, Q> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<Q, K> Equivalent<Slot<K>> for Live<'_, Q>
+where
+    Q: ?Sized + Equivalent<K>,
+{
+    fn equivalent(&self, slot: &Slot<K>) -> bool {
+        slot.0.as_ref().is_some_and(|key| self.0.equivalent(key))
+    }
+}
+
+/// A Fenwick tree counting the tombstones before each position, to convert
+/// between logical indices and positions.
+#[derive(Clone)]
+struct DeadCounts {
+    tree: Vec<usize>,
+    total: usize,
+}
+
+impl DeadCounts {
+    fn with_len(len: usize) -> Self {
+        DeadCounts {
+            tree: alloc::vec![0; len],
+            total: 0,
+        }
+    }
+
+    fn clear(&mut self) {
+        self.tree.clear();
+        self.total = 0;
+    }
+
+    /// Adds a live position at the end.
+    fn push(&mut self) {
+        let i = self.tree.len() + 1;
+        let lowbit = i & i.wrapping_neg();
+        let count = self.count_before(i - 1) - self.count_before(i - lowbit);
+        self.tree.push(count);
+    }
+
+    /// Marks a position as a tombstone.
+    fn mark(&mut self, pos: usize) {
+        self.total += 1;
+        let mut i = pos + 1;
+        while let Some(count) = self.tree.get_mut(i - 1) {
+            *count += 1;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Returns the number of tombstones before `pos`.
+    fn count_before(&self, pos: usize) -> usize {
+        let mut i = pos;
+        let mut count = 0;
+        while i > 0 {
+            count += self.tree[i - 1];
+            i &= i - 1;
+        }
+        count
+    }
+
+    /// Returns the position of the live entry at logical `index`, which must
+    /// be in bounds.
+    fn position(&self, index: usize) -> usize {
+        let len = self.tree.len();
+        let mut pos = 0;
+        let mut remaining = index;
+        let mut step = if len == 0 { 0 } else { 1 << len.ilog2() };
+        while step > 0 {
+            let next = pos + step;
+            if next <= len {
+                let live = step - self.tree[next - 1];
+                if live <= remaining {
+                    pos = next;
+                    remaining -= live;
+                }
+            }
+            step >>= 1;
+        }
+        pos
+    }
+}
+
+impl<K, V, S> Clone for TombstoneMap<K, V, S>
+where
+    K: Clone,
+    V: Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        TombstoneMap {
+            inner: self.inner.clone(),
+            dead: self.dead.clone(),
+        }
+    }
+}
+
+impl<K, V, S> fmt::Debug for TombstoneMap<K, V, S>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<K, V> TombstoneMap<K, V> {
+    /// Create a new map. (Does not allocate.)
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+
+    /// Create a new map with capacity for `n` key-value pairs. (Does not
+    /// allocate if `n` is zero.)
+    ///
+    /// Computes in **O(n)** time.
+    #[inline]
+    pub fn with_capacity(n: usize) -> Self {
+        Self::with_capacity_and_hasher(n, RandomState::new())
+    }
+}
+
+impl<K, V, S> TombstoneMap<K, V, S> {
+    /// Create a new map with capacity for `n` key-value pairs. (Does not
+    /// allocate if `n` is zero.)
+    ///
+    /// Computes in **O(n)** time.
+    #[inline]
+    pub fn with_capacity_and_hasher(n: usize, hash_builder: S) -> Self {
+        TombstoneMap {
+            inner: IndexMap::with_capacity_and_hasher(n, hash_builder),
+            dead: DeadCounts {
+                tree: Vec::with_capacity(n),
+                total: 0,
+            },
+        }
+    }
+
+    /// Create a new map with `hash_builder`. (Does not allocate.)
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_capacity_and_hasher(0, hash_builder)
+    }
+
+    /// Return the number of live key-value pairs in the map.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn len(&self) -> usize {
+        self.inner.len() - self.tombstones()
+    }
+
+    /// Returns true if the map contains no live key-value pairs.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the number of tombstones left by removals since the last
+    /// compaction.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn tombstones(&self) -> usize {
+        self.dead.total
+    }
+
+    /// Return a reference to the map's `BuildHasher`.
+    pub fn hasher(&self) -> &S {
+        self.inner.hasher()
+    }
+
+    /// Remove all tombstones, so that logical indices match the positions in
+    /// the underlying storage again. Live entries keep their order.
+    ///
+    /// Computes in **O(n)** time, where *n* includes the tombstones.
+    pub fn compact(&mut self) {
+        if self.tombstones() > 0 {
+            self.inner.retain(|_, value| value.is_some());
+            self.dead = DeadCounts::with_len(self.inner.len());
+        }
+    }
+
+    /// Remove all key-value pairs and tombstones in the map, while preserving
+    /// its capacity.
+    ///
+    /// Computes in **O(n)** time.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+        self.dead.clear();
+    }
+
+    /// Return an iterator over the live key-value pairs of the map, in their
+    /// order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            iter: self.inner.iter(),
+            len: self.len(),
+        }
+    }
+
+    /// Return an iterator over the live key-value pairs of the map, in their
+    /// order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        let len = self.len();
+        IterMut {
+            iter: self.inner.iter_mut(),
+            len,
+        }
+    }
+
+    /// Return an iterator over the live keys of the map, in their order.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// Return an iterator over the live values of the map, in their order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+
+    /// Return an iterator over mutable references to the live values of the
+    /// map, in their order.
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut {
+            iter: self.iter_mut(),
+        }
+    }
+
+    /// Get a key-value pair by logical index.
+    ///
+    /// Valid indices are `0 <= index < self.len()`.
+    ///
+    /// Computes in **O(log n)** time.
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        let pos = self.position(index)?;
+        let (slot, value) = self.inner.get_index(pos)?;
+        Some((slot.0.as_ref()?, value.as_ref()?))
+    }
+
+    /// Get a key-value pair by logical index, with mutable access to the
+    /// value.
+    ///
+    /// Valid indices are `0 <= index < self.len()`.
+    ///
+    /// Computes in **O(log n)** time.
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        let pos = self.position(index)?;
+        let (slot, value) = self.inner.get_index_mut(pos)?;
+        Some((slot.0.as_ref()?, value.as_mut()?))
+    }
+
+    /// Get the first live key-value pair.
+    ///
+    /// Computes in **O(log n)** time.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.get_index(0)
+    }
+
+    /// Get the last live key-value pair.
+    ///
+    /// Computes in **O(log n)** time.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.get_index(self.len().checked_sub(1)?)
+    }
+
+    /// Scan through each live key-value pair in the map and keep those where
+    /// the closure `keep` returns `true`, then remove all tombstones.
+    ///
+    /// The elements are visited in order, and remaining elements keep their
+    /// order.
+    ///
+    /// Computes in **O(n)** time, where *n* includes the tombstones.
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.inner.retain(|slot, value| match (&slot.0, value) {
+            (Some(key), Some(value)) => keep(key, value),
+            _ => false,
+        });
+        self.dead = DeadCounts::with_len(self.inner.len());
+    }
+
+    /// Returns the position of the live entry at logical `index`.
+    fn position(&self, index: usize) -> Option<usize> {
+        (index < self.len()).then(|| self.dead.position(index))
+    }
+
+    /// Records a tombstone at `pos`, and compacts if tombstones outnumber the
+    /// live entries.
+    fn bury(&mut self, pos: usize) {
+        self.dead.mark(pos);
+        if self.tombstones() > self.len() {
+            self.compact();
+        }
+    }
+}
+
+impl<K, V, S> TombstoneMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Insert a key-value pair in the map.
+    ///
+    /// If an equivalent key already exists in the map: the key remains and
+    /// retains in its place in the order, its corresponding value is updated
+    /// with `value`, and the older value is returned inside `Some(_)`.
+    ///
+    /// If no equivalent key existed in the map: the new key-value pair is
+    /// inserted, last in order, and `None` is returned. This is also the case
+    /// for a key that was removed and left a tombstone.
+    ///
+    /// Computes in **O(log n)** time (amortized average).
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.inner.entry(Slot(Some(key))) {
+            indexmap::map::Entry::Occupied(entry) => entry.into_mut().replace(value),
+            indexmap::map::Entry::Vacant(entry) => {
+                entry.insert(Some(value));
+                self.dead.push();
+                None
+            }
+        }
+    }
+
+    /// Return `true` if an equivalent to `key` exists in the map.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.inner.contains_key(&Live(key))
+    }
+
+    /// Return a reference to the value stored for `key`, if it is present,
+    /// else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.inner.get(&Live(key))?.as_ref()
+    }
+
+    /// Return references to the stored key-value pair for the lookup `key`,
+    /// if it is present, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let (slot, value) = self.inner.get_key_value(&Live(key))?;
+        Some((slot.0.as_ref()?, value.as_ref()?))
+    }
+
+    /// Return the logical index with references to the stored key-value pair
+    /// for the lookup `key`, if it is present, else `None`.
+    ///
+    /// Computes in **O(log n)** time (average).
+    pub fn get_full<Q>(&self, key: &Q) -> Option<(usize, &K, &V)>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let (pos, slot, value) = self.inner.get_full(&Live(key))?;
+        let index = pos - self.dead.count_before(pos);
+        Some((index, slot.0.as_ref()?, value.as_ref()?))
+    }
+
+    /// Return the logical index for `key`, if it is present, else `None`.
+    ///
+    /// Computes in **O(log n)** time (average).
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let pos = self.inner.get_index_of(&Live(key))?;
+        Some(pos - self.dead.count_before(pos))
+    }
+
+    /// Return a mutable reference to the value stored for `key`, if it is
+    /// present, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.inner.get_mut(&Live(key))?.as_mut()
+    }
+
+    /// Remove the key-value pair equivalent to `key` and return its value,
+    /// leaving a tombstone in its place.
+    ///
+    /// This preserves the order of the remaining entries without shifting
+    /// them, though the logical indices after it shift down by one.
+    ///
+    /// Return `None` if `key` is not in map.
+    ///
+    /// Computes in **O(log n)** time (amortized average).
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Remove and return the key-value pair equivalent to `key`, leaving a
+    /// tombstone in its place.
+    ///
+    /// See [`remove`][Self::remove] for details.
+    ///
+    /// Computes in **O(log n)** time (amortized average).
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let (pos, slot, value) = self.inner.get_full_mut2(&Live(key))?;
+        let entry = (slot.0.take()?, value.take()?);
+        self.bury(pos);
+        Some(entry)
+    }
+
+    /// Remove the key-value pair at logical `index`, leaving a tombstone.
+    ///
+    /// Like [`remove`][Self::remove], this preserves the order of the
+    /// remaining entries, and the following logical indices shift down by
+    /// one.
+    ///
+    /// Computes in **O(log n)** time (amortized).
+    pub fn remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let pos = self.position(index)?;
+        let (slot, value) = self.inner.get_index_mut2(pos)?;
+        let entry = (slot.0.take()?, value.take()?);
+        self.bury(pos);
+        Some(entry)
+    }
+}
+
+impl<K, V, Q, S> Index<&Q> for TombstoneMap<K, V, S>
+where
+    K: Hash + Eq,
+    Q: ?Sized + Hash + Equivalent<K>,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    /// Returns a reference to the value corresponding to the supplied `key`.
+    ///
+    /// ***Panics*** if `key` is not present in the map.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found")
+    }
+}
+
+impl<K, V, Q, S> IndexMut<&Q> for TombstoneMap<K, V, S>
+where
+    K: Hash + Eq,
+    Q: ?Sized + Hash + Equivalent<K>,
+    S: BuildHasher,
+{
+    /// Returns a mutable reference to the value corresponding to the supplied
+    /// `key`.
+    ///
+    /// ***Panics*** if `key` is not present in the map.
+    fn index_mut(&mut self, key: &Q) -> &mut V {
+        self.get_mut(key).expect("key not found")
+    }
+}
+
+impl<K, V, S> Default for TombstoneMap<K, V, S>
+where
+    S: Default,
+{
+    /// Return an empty [`TombstoneMap`]
+    fn default() -> Self {
+        Self::with_capacity_and_hasher(0, S::default())
+    }
+}
+
+impl<K, V1, S1, V2, S2> PartialEq<TombstoneMap<K, V2, S2>> for TombstoneMap<K, V1, S1>
+where
+    K: PartialEq,
+    V1: PartialEq<V2>,
+{
+    fn eq(&self, other: &TombstoneMap<K, V2, S2>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|((k1, v1), (k2, v2))| k1 == k2 && v1 == v2)
+    }
+}
+
+impl<K, V, S> Eq for TombstoneMap<K, V, S>
+where
+    K: Eq,
+    V: Eq,
+{
+}
+
+impl<K, V, S> FromIterator<(K, V)> for TombstoneMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iterable: I) -> Self {
+        let mut map = Self::default();
+        map.extend(iterable);
+        map
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for TombstoneMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iterable: I) {
+        let iter = iterable.into_iter();
+        self.inner.reserve(iter.size_hint().0);
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// Converts into a [`TombstoneMap`] with the same order and hasher.
+///
+/// Computes in **O(n)** time (average).
+impl<K, V, S> From<OrderMap<K, V, S>> for TombstoneMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Clone,
+{
+    fn from(map: OrderMap<K, V, S>) -> Self {
+        let mut tombstone_map = Self::with_capacity_and_hasher(map.len(), map.hasher().clone());
+        tombstone_map.extend(map);
+        tombstone_map
+    }
+}
+
+/// Converts into an [`OrderMap`] with the same order and hasher, leaving out
+/// the tombstones.
+///
+/// Computes in **O(n)** time (average).
+impl<K, V, S> From<TombstoneMap<K, V, S>> for OrderMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Clone,
+{
+    fn from(tombstone_map: TombstoneMap<K, V, S>) -> Self {
+        let mut map =
+            Self::with_capacity_and_hasher(tombstone_map.len(), tombstone_map.hasher().clone());
+        map.extend(tombstone_map);
+        map
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a TombstoneMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut TombstoneMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V, S> IntoIterator for TombstoneMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        IntoIter {
+            iter: self.inner.into_iter(),
+            len,
+        }
+    }
+}
+
+/// Implements the iterator traits for a wrapper of an `indexmap` iterator
+/// that skips the tombstones, with `$live` extracting a live item.
+macro_rules! live_iterator {
+    ($name:ident<$($lt:lifetime,)? $K:ident, $V:ident>, $item:ty, $live:expr) => {
+        impl<$($lt,)? $K, $V> Iterator for $name<$($lt,)? $K, $V> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let item = self.iter.by_ref().find_map($live)?;
+                self.len -= 1;
+                Some(item)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+
+        impl<$($lt,)? $K, $V> DoubleEndedIterator for $name<$($lt,)? $K, $V> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let item = self.iter.by_ref().rev().find_map($live)?;
+                self.len -= 1;
+                Some(item)
+            }
+        }
+
+        impl<$($lt,)? $K, $V> ExactSizeIterator for $name<$($lt,)? $K, $V> {}
+        impl<$($lt,)? $K, $V> FusedIterator for $name<$($lt,)? $K, $V> {}
+    };
+}
+
+/// An iterator over the entries of a [`TombstoneMap`].
+///
+/// This `struct` is created by the [`TombstoneMap::iter`] method.
+/// See its documentation for more.
+pub struct Iter<'a, K, V> {
+    iter: indexmap::map::Iter<'a, Slot<K>, Option<V>>,
+    len: usize,
+}
+
+live_iterator!(Iter<'a, K, V>, (&'a K, &'a V), |(slot, value)| Some((
+    slot.0.as_ref()?,
+    value.as_ref()?
+)));
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Iter {
+            iter: self.iter.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for Iter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// A mutable iterator over the entries of a [`TombstoneMap`].
+///
+/// This `struct` is created by the [`TombstoneMap::iter_mut`] method.
+/// See its documentation for more.
+pub struct IterMut<'a, K, V> {
+    iter: indexmap::map::IterMut<'a, Slot<K>, Option<V>>,
+    len: usize,
+}
+
+live_iterator!(IterMut<'a, K, V>, (&'a K, &'a mut V), |(slot, value)| Some(
+    (slot.0.as_ref()?, value.as_mut()?)
+));
+
+/// An owning iterator over the entries of a [`TombstoneMap`].
+///
+/// This `struct` is created by the [`into_iter`] method on [`TombstoneMap`]
+/// (provided by the [`IntoIterator`] trait). See its documentation for more.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+pub struct IntoIter<K, V> {
+    iter: indexmap::map::IntoIter<Slot<K>, Option<V>>,
+    len: usize,
+}
+
+live_iterator!(IntoIter<K, V>, (K, V), |(slot, value)| Some((slot.0?, value?)));
+
+/// An iterator over the keys of a [`TombstoneMap`].
+///
+/// This `struct` is created by the [`TombstoneMap::keys`] method.
+/// See its documentation for more.
+#[derive(Clone, Debug)]
+pub struct Keys<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Keys<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+impl<K, V> FusedIterator for Keys<'_, K, V> {}
+
+/// An iterator over the values of a [`TombstoneMap`].
+///
+/// This `struct` is created by the [`TombstoneMap::values`] method.
+/// See its documentation for more.
+#[derive(Clone, Debug)]
+pub struct Values<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Values<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+impl<K, V> FusedIterator for Values<'_, K, V> {}
+
+/// A mutable iterator over the values of a [`TombstoneMap`].
+///
+/// This `struct` is created by the [`TombstoneMap::values_mut`] method.
+/// See its documentation for more.
+pub struct ValuesMut<'a, K, V> {
+    iter: IterMut<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for ValuesMut<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<K, V> ExactSizeIterator for ValuesMut<'_, K, V> {}
+impl<K, V> FusedIterator for ValuesMut<'_, K, V> {}
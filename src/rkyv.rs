@@ -0,0 +1,738 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+
+use crate::{OrderMap, OrderSet};
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FusedIterator;
+use core::ops::{Index, Range};
+use indexmap::{IndexMap, IndexSet};
+use rkyv::bytecheck::CheckBytes;
+use rkyv::collections::swiss_table::{
+    ArchivedIndexMap, ArchivedIndexSet, IndexMapResolver, IndexSetResolver,
+};
+use rkyv::munge::munge;
+use rkyv::rancor::{Fallible, Source};
+use rkyv::ser::{Allocator, Writer};
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+/// An archived [`OrderMap`].
+///
+/// This is the form of an `OrderMap<K, V, S>` in an `rkyv` archive, with
+/// `K::Archived` keys and `V::Archived` values. It has the same order as the
+/// original map, and it supports lookups by key or by index without
+/// deserializing the map. Keys are hashed with `rkyv`'s own stable hasher,
+/// regardless of the hasher `S` of the original map.
+///
+/// Like `OrderMap`, it considers the order for [`PartialEq`] and [`Eq`].
+///
+/// # Examples
+///
+/// ```
+/// use ordermap::OrderMap;
+/// use ordermap::map::ArchivedOrderMap;
+/// use rkyv::rancor::Error;
+/// use rkyv::Archived;
+///
+/// let map: OrderMap<u32, u64> = [(3, 30), (1, 10), (2, 20)].into();
+/// let bytes = rkyv::to_bytes::<Error>(&map).unwrap();
+///
+/// let archived =
+///     rkyv::access::<ArchivedOrderMap<Archived<u32>, Archived<u64>>, Error>(&bytes).unwrap();
+/// assert_eq!(archived.get(&1.into()), Some(&10.into()));
+/// assert_eq!(archived.get_index(0), Some((&3.into(), &30.into())));
+/// assert!(archived.keys().eq([3, 1, 2].iter()));
+/// assert_eq!(*archived, map);
+/// ```
+#[derive(Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[repr(transparent)]
+pub struct ArchivedOrderMap<K, V> {
+    inner: ArchivedIndexMap<K, V>,
+}
+
+impl<K, V> ArchivedOrderMap<K, V> {
+    /// Returns the number of key-value pairs in the map.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the map contains no elements.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Return an iterator over the key-value pairs of the map, in their order
+    pub fn iter(&self) -> ArchivedIter<'_, K, V> {
+        ArchivedIter {
+            map: &self.inner,
+            range: 0..self.len(),
+        }
+    }
+
+    /// Return an iterator over the keys of the map, in their order
+    pub fn keys(&self) -> ArchivedKeys<'_, K, V> {
+        ArchivedKeys { iter: self.iter() }
+    }
+
+    /// Return an iterator over the values of the map, in their order
+    pub fn values(&self) -> ArchivedValues<'_, K, V> {
+        ArchivedValues { iter: self.iter() }
+    }
+
+    /// Return true if an equivalent to `key` exists in the map.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.inner.contains_key(key)
+    }
+
+    /// Return a reference to the value stored for `key`, if it is present,
+    /// else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.inner.get(key)
+    }
+
+    /// Return references to the stored key-value pair for the lookup `key`,
+    /// if it is present, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.inner.get_key_value(key)
+    }
+
+    /// Return the index with references to the stored key-value pair for the
+    /// lookup `key`, if it is present, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_full<Q>(&self, key: &Q) -> Option<(usize, &K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.inner.get_full(key)
+    }
+
+    /// Return the item index for `key`, if it is present, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.inner.get_index_of(key)
+    }
+
+    /// Get a key-value pair by index
+    ///
+    /// Valid indices are `0 <= index < self.len()`.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.inner.get_index(index)
+    }
+
+    /// Get the first key-value pair
+    ///
+    /// Computes in **O(1)** time.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.inner.get_index(0)
+    }
+
+    /// Get the last key-value pair
+    ///
+    /// Computes in **O(1)** time.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.inner.get_index(self.len().checked_sub(1)?)
+    }
+}
+
+impl<K, V, Q> Index<&Q> for ArchivedOrderMap<K, V>
+where
+    K: Borrow<Q>,
+    Q: ?Sized + Hash + Eq,
+{
+    type Output = V;
+
+    /// Returns a reference to the value corresponding to the supplied `key`.
+    ///
+    /// ***Panics*** if `key` is not present in the map.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<K, V> Index<usize> for ArchivedOrderMap<K, V> {
+    type Output = V;
+
+    /// Returns a reference to the value at the supplied `index`.
+    ///
+    /// ***Panics*** if `index` is out of bounds.
+    fn index(&self, index: usize) -> &V {
+        if let Some((_, value)) = self.get_index(index) {
+            value
+        } else {
+            panic!(
+                "index out of bounds: the len is {len} but the index is {index}",
+                len = self.len()
+            );
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a ArchivedOrderMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = ArchivedIter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for ArchivedOrderMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq for ArchivedOrderMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other)
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for ArchivedOrderMap<K, V> {}
+
+impl<K, V, UK, UV, S> PartialEq<OrderMap<UK, UV, S>> for ArchivedOrderMap<K, V>
+where
+    K: PartialEq<UK>,
+    V: PartialEq<UV>,
+{
+    fn eq(&self, other: &OrderMap<UK, UV, S>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|((ak, av), (bk, bv))| ak == bk && av == bv)
+    }
+}
+
+/// An iterator over the entries of an [`ArchivedOrderMap`].
+///
+/// This `struct` is created by the [`ArchivedOrderMap::iter`] method.
+/// See its documentation for more.
+pub struct ArchivedIter<'a, K, V> {
+    map: &'a ArchivedIndexMap<K, V>,
+    range: Range<usize>,
+}
+
+impl<'a, K, V> Iterator for ArchivedIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.map.get_index(self.range.next()?)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for ArchivedIter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.map.get_index(self.range.next_back()?)
+    }
+}
+
+impl<K, V> ExactSizeIterator for ArchivedIter<'_, K, V> {}
+impl<K, V> FusedIterator for ArchivedIter<'_, K, V> {}
+
+impl<K, V> Clone for ArchivedIter<'_, K, V> {
+    fn clone(&self) -> Self {
+        ArchivedIter {
+            map: self.map,
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for ArchivedIter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// An iterator over the keys of an [`ArchivedOrderMap`].
+///
+/// This `struct` is created by the [`ArchivedOrderMap::keys`] method.
+/// See its documentation for more.
+pub struct ArchivedKeys<'a, K, V> {
+    iter: ArchivedIter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for ArchivedKeys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for ArchivedKeys<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<K, V> ExactSizeIterator for ArchivedKeys<'_, K, V> {}
+impl<K, V> FusedIterator for ArchivedKeys<'_, K, V> {}
+
+impl<K, V> Clone for ArchivedKeys<'_, K, V> {
+    fn clone(&self) -> Self {
+        ArchivedKeys {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<K: fmt::Debug, V> fmt::Debug for ArchivedKeys<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// An iterator over the values of an [`ArchivedOrderMap`].
+///
+/// This `struct` is created by the [`ArchivedOrderMap::values`] method.
+/// See its documentation for more.
+pub struct ArchivedValues<'a, K, V> {
+    iter: ArchivedIter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for ArchivedValues<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for ArchivedValues<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<K, V> ExactSizeIterator for ArchivedValues<'_, K, V> {}
+impl<K, V> FusedIterator for ArchivedValues<'_, K, V> {}
+
+impl<K, V> Clone for ArchivedValues<'_, K, V> {
+    fn clone(&self) -> Self {
+        ArchivedValues {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<K, V: fmt::Debug> fmt::Debug for ArchivedValues<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<K: Archive, V: Archive, S> Archive for OrderMap<K, V, S> {
+    type Archived = ArchivedOrderMap<K::Archived, V::Archived>;
+    type Resolver = IndexMapResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedOrderMap { inner } = out);
+        self.inner.resolve(resolver, inner);
+    }
+}
+
+impl<K, V, S, Ser> Serialize<Ser> for OrderMap<K, V, S>
+where
+    K: Hash + Eq + Serialize<Ser>,
+    V: Serialize<Ser>,
+    Ser: Fallible + Allocator + Writer + ?Sized,
+    Ser::Error: Source,
+{
+    fn serialize(&self, serializer: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<K, V, S, D> Deserialize<OrderMap<K, V, S>, D> for ArchivedOrderMap<K::Archived, V::Archived>
+where
+    K: Archive + Hash + Eq,
+    K::Archived: Deserialize<K, D>,
+    V: Archive,
+    V::Archived: Deserialize<V, D>,
+    S: BuildHasher + Default,
+    D: Fallible + ?Sized,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<OrderMap<K, V, S>, D::Error> {
+        Ok(OrderMap {
+            inner: Deserialize::<IndexMap<K, V, S>, D>::deserialize(&self.inner, deserializer)?,
+        })
+    }
+}
+
+/// An archived [`OrderSet`].
+///
+/// This is the form of an `OrderSet<T, S>` in an `rkyv` archive, with
+/// `T::Archived` values. It has the same order as the original set, and it
+/// supports lookups by value or by index without deserializing the set.
+///
+/// Like `OrderSet`, it considers the order for [`PartialEq`] and [`Eq`].
+///
+/// # Examples
+///
+/// ```
+/// use ordermap::OrderSet;
+/// use ordermap::set::ArchivedOrderSet;
+/// use rkyv::rancor::Error;
+/// use rkyv::Archived;
+///
+/// let set: OrderSet<u32> = [3, 1, 2].into();
+/// let bytes = rkyv::to_bytes::<Error>(&set).unwrap();
+///
+/// let archived = rkyv::access::<ArchivedOrderSet<Archived<u32>>, Error>(&bytes).unwrap();
+/// assert!(archived.contains(&1.into()));
+/// assert_eq!(archived.get_index_of(&2.into()), Some(2));
+/// assert_eq!(*archived, set);
+/// ```
+#[derive(Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[repr(transparent)]
+pub struct ArchivedOrderSet<T> {
+    inner: ArchivedIndexSet<T>,
+}
+
+impl<T> ArchivedOrderSet<T> {
+    /// Return the number of elements in the set.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the set contains no elements.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Return an iterator over the values of the set, in their order
+    pub fn iter(&self) -> ArchivedSetIter<'_, T> {
+        ArchivedSetIter {
+            set: &self.inner,
+            range: 0..self.len(),
+        }
+    }
+
+    /// Return true if an equivalent to `value` exists in the set.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.inner.contains(value)
+    }
+
+    /// Return a reference to the value stored in the set, if it is present,
+    /// else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.inner.get(value)
+    }
+
+    /// Return item index and value
+    pub fn get_full<Q>(&self, value: &Q) -> Option<(usize, &T)>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.inner.get_full(value)
+    }
+
+    /// Return item index, if it exists in the set
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_index_of<Q>(&self, value: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.inner.get_index_of(value)
+    }
+
+    /// Get a value by index
+    ///
+    /// Valid indices are `0 <= index < self.len()`.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn get_index(&self, index: usize) -> Option<&T> {
+        self.inner.get_index(index)
+    }
+
+    /// Get the first value
+    ///
+    /// Computes in **O(1)** time.
+    pub fn first(&self) -> Option<&T> {
+        self.inner.get_index(0)
+    }
+
+    /// Get the last value
+    ///
+    /// Computes in **O(1)** time.
+    pub fn last(&self) -> Option<&T> {
+        self.inner.get_index(self.len().checked_sub(1)?)
+    }
+}
+
+impl<T> Index<usize> for ArchivedOrderSet<T> {
+    type Output = T;
+
+    /// Returns a reference to the value at the supplied `index`.
+    ///
+    /// ***Panics*** if `index` is out of bounds.
+    fn index(&self, index: usize) -> &T {
+        if let Some(value) = self.get_index(index) {
+            value
+        } else {
+            panic!(
+                "index out of bounds: the len is {len} but the index is {index}",
+                len = self.len()
+            );
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ArchivedOrderSet<T> {
+    type Item = &'a T;
+    type IntoIter = ArchivedSetIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArchivedOrderSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ArchivedOrderSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other)
+    }
+}
+
+impl<T: Eq> Eq for ArchivedOrderSet<T> {}
+
+impl<T, U, S> PartialEq<OrderSet<U, S>> for ArchivedOrderSet<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &OrderSet<U, S>) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+/// An iterator over the values of an [`ArchivedOrderSet`].
+///
+/// This `struct` is created by the [`ArchivedOrderSet::iter`] method.
+/// See its documentation for more.
+pub struct ArchivedSetIter<'a, T> {
+    set: &'a ArchivedIndexSet<T>,
+    range: Range<usize>,
+}
+
+impl<'a, T> Iterator for ArchivedSetIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.set.get_index(self.range.next()?)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for ArchivedSetIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.set.get_index(self.range.next_back()?)
+    }
+}
+
+impl<T> ExactSizeIterator for ArchivedSetIter<'_, T> {}
+impl<T> FusedIterator for ArchivedSetIter<'_, T> {}
+
+impl<T> Clone for ArchivedSetIter<'_, T> {
+    fn clone(&self) -> Self {
+        ArchivedSetIter {
+            set: self.set,
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArchivedSetIter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<T: Archive, S> Archive for OrderSet<T, S> {
+    type Archived = ArchivedOrderSet<T::Archived>;
+    type Resolver = IndexSetResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedOrderSet { inner } = out);
+        self.inner.resolve(resolver, inner);
+    }
+}
+
+impl<T, S, Ser> Serialize<Ser> for OrderSet<T, S>
+where
+    T: Hash + Eq + Serialize<Ser>,
+    Ser: Fallible + Allocator + Writer + ?Sized,
+    Ser::Error: Source,
+{
+    fn serialize(&self, serializer: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<T, S, D> Deserialize<OrderSet<T, S>, D> for ArchivedOrderSet<T::Archived>
+where
+    T: Archive + Hash + Eq,
+    T::Archived: Deserialize<T, D>,
+    S: BuildHasher + Default,
+    D: Fallible + ?Sized,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<OrderSet<T, S>, D::Error> {
+        Ok(OrderSet {
+            inner: Deserialize::<IndexSet<T, S>, D>::deserialize(&self.inner, deserializer)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod rkyv_tests {
+    use super::*;
+    use alloc::string::String;
+    use rkyv::rancor::Error;
+    use rkyv::string::ArchivedString;
+    use rkyv::{Archived, access};
+
+    #[test]
+    fn map_rkyv_roundtrip() {
+        let map: OrderMap<String, u32> = [("c", 3), ("a", 1), ("b", 2)]
+            .into_iter()
+            .map(|(k, v)| (String::from(k), v))
+            .collect();
+        let bytes = rkyv::to_bytes::<Error>(&map).unwrap();
+
+        let archived =
+            access::<ArchivedOrderMap<ArchivedString, Archived<u32>>, Error>(&bytes).unwrap();
+        assert_eq!(archived.len(), 3);
+        assert_eq!(*archived, map);
+        assert!(archived.keys().map(|k| k.as_str()).eq(["c", "a", "b"]));
+        assert!(archived.values().rev().eq(&[2, 1, 3]));
+        let mut iter = archived.iter();
+        assert_eq!(iter.next().map(|(k, _)| k.as_str()), Some("c"));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.clone().count(), 2);
+        assert_eq!(iter.next_back().map(|(k, _)| k.as_str()), Some("b"));
+        assert_eq!(iter.next().map(|(k, _)| k.as_str()), Some("a"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(archived.get("a"), Some(&1.into()));
+        assert_eq!(archived.get("d"), None);
+        assert_eq!(archived.get_full("b").map(|(i, _, _)| i), Some(2));
+        assert_eq!(archived["b"], 2);
+        assert_eq!(archived[0], 3);
+        assert_eq!(archived.first().map(|(k, _)| k.as_str()), Some("c"));
+        assert_eq!(archived.last().map(|(k, _)| k.as_str()), Some("b"));
+
+        let copy: OrderMap<String, u32> = rkyv::deserialize::<_, Error>(archived).unwrap();
+        assert_eq!(copy, map);
+    }
+
+    #[test]
+    fn set_rkyv_roundtrip() {
+        let set: OrderSet<u32> = [5, 1, 3].into();
+        let bytes = rkyv::to_bytes::<Error>(&set).unwrap();
+
+        let archived = access::<ArchivedOrderSet<Archived<u32>>, Error>(&bytes).unwrap();
+        assert_eq!(*archived, set);
+        assert!(archived.iter().eq(&[5, 1, 3]));
+        assert!(archived.iter().rev().eq(&[3, 1, 5]));
+        assert_eq!(archived.iter().len(), 3);
+        assert!(archived.contains(&3.into()));
+        assert!(!archived.contains(&4.into()));
+        assert_eq!(archived.get_index_of(&1.into()), Some(1));
+        assert_eq!(archived[2], 3);
+        assert_ne!(*archived, OrderSet::<u32>::from([1, 3, 5]));
+
+        let copy: OrderSet<u32> = rkyv::deserialize::<_, Error>(archived).unwrap();
+        assert_eq!(copy, set);
+    }
+
+    #[test]
+    fn rkyv_access_validates() {
+        let map: OrderMap<u32, u32> = (0..10).map(|i| (i, i * i)).collect();
+        let bytes = rkyv::to_bytes::<Error>(&map).unwrap();
+        let end = bytes.len();
+
+        let truncated = &bytes[..end - 4];
+        assert!(
+            access::<ArchivedOrderMap<Archived<u32>, Archived<u32>>, Error>(truncated).is_err()
+        );
+
+        // Claim far more entries than the buffer holds.
+        let mut corrupted = bytes.to_vec();
+        corrupted[end - 8..end - 4].copy_from_slice(&[0x00, 0x00, 0x00, 0x70]);
+        assert!(
+            access::<ArchivedOrderMap<Archived<u32>, Archived<u32>>, Error>(&corrupted).is_err()
+        );
+    }
+}
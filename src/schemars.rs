@@ -0,0 +1,42 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "schemars")))]
+
+use crate::{OrderMap, OrderSet};
+use alloc::borrow::Cow;
+use indexmap::{IndexMap, IndexSet};
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+
+impl<K: JsonSchema, V: JsonSchema, S> JsonSchema for OrderMap<K, V, S> {
+    fn inline_schema() -> bool {
+        IndexMap::<K, V, S>::inline_schema()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        IndexMap::<K, V, S>::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        IndexMap::<K, V, S>::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        IndexMap::<K, V, S>::json_schema(generator)
+    }
+}
+
+impl<T: JsonSchema, S> JsonSchema for OrderSet<T, S> {
+    fn inline_schema() -> bool {
+        IndexSet::<T, S>::inline_schema()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        IndexSet::<T, S>::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        IndexSet::<T, S>::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        IndexSet::<T, S>::json_schema(generator)
+    }
+}
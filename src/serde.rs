@@ -1,9 +1,15 @@
 #![cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 
+use crate::map::serde_merge::MergeOrder;
+use crate::util::cautious_capacity;
 use crate::{OrderMap, OrderSet};
+use core::fmt;
 use core::hash::{BuildHasher, Hash};
+use core::marker::PhantomData;
 use serde_core::de::value::{MapDeserializer, SeqDeserializer};
-use serde_core::de::{Deserialize, Deserializer, Error, IntoDeserializer};
+use serde_core::de::{
+    self, Deserialize, Deserializer, Error, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
 use serde_core::ser::{Serialize, Serializer};
 
 impl<K, V, S> Serialize for OrderMap<K, V, S>
@@ -33,6 +39,14 @@ where
             inner: <_>::deserialize(deserializer)?,
         })
     }
+
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        place.clear();
+        crate::map::serde_merge::merge(place, deserializer, MergeOrder::KeepPositions)
+    }
 }
 
 impl<'de, K, V, S, E> IntoDeserializer<'de, E> for OrderMap<K, V, S>
@@ -49,6 +63,19 @@ where
     }
 }
 
+impl<'de, 'a, K, V, S, E> IntoDeserializer<'de, E> for &'a OrderMap<K, V, S>
+where
+    &'a K: IntoDeserializer<'de, E>,
+    &'a V: IntoDeserializer<'de, E>,
+    E: Error,
+{
+    type Deserializer = MapDeserializer<'de, crate::map::Iter<'a, K, V>, E>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        MapDeserializer::new(self.iter())
+    }
+}
+
 impl<T, S> Serialize for OrderSet<T, S>
 where
     T: Serialize,
@@ -74,6 +101,40 @@ where
             inner: <_>::deserialize(deserializer)?,
         })
     }
+
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        place.clear();
+        deserializer.deserialize_seq(InPlaceSetVisitor(place))
+    }
+}
+
+struct InPlaceSetVisitor<'a, T, S>(&'a mut OrderSet<T, S>);
+
+impl<'de, T, S> Visitor<'de> for InPlaceSetVisitor<'_, T, S>
+where
+    T: Deserialize<'de> + Eq + Hash,
+    S: BuildHasher,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a set")
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<(), A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.0
+            .reserve(cautious_capacity::<T, ()>(access.size_hint()));
+        while let Some(value) = access.next_element()? {
+            self.0.insert(value);
+        }
+        Ok(())
+    }
 }
 
 impl<'de, T, S, E> IntoDeserializer<'de, E> for OrderSet<T, S>
@@ -88,3 +149,240 @@ where
         self.inner.into_deserializer()
     }
 }
+
+impl<'de, 'a, T, S, E> IntoDeserializer<'de, E> for &'a OrderSet<T, S>
+where
+    &'a T: IntoDeserializer<'de, E>,
+    E: Error,
+{
+    type Deserializer = SeqDeserializer<crate::set::Iter<'a, T>, E>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        SeqDeserializer::new(self.iter())
+    }
+}
+
+/// A [`DeserializeSeed`][de::DeserializeSeed] for an [`OrderMap`] with a given hasher.
+///
+/// The [`Deserialize`] implementation of `OrderMap` needs `S: Default` to
+/// create its hasher, which doesn't work for a keyed or runtime-seeded hasher.
+/// This seed carries the hasher instance instead, and optionally a capacity
+/// to reserve up front.
+///
+/// # Examples
+///
+/// ```
+/// use ordermap::map::OrderMapSeed;
+/// use serde::de::DeserializeSeed;
+/// use serde::de::value::{Error, MapDeserializer};
+/// use std::hash::RandomState;
+///
+/// let input = MapDeserializer::<_, Error>::new([(1, 'a'), (2, 'b')].into_iter());
+/// let map = OrderMapSeed::new(RandomState::new()).deserialize(input).unwrap();
+/// assert!(map.into_iter().eq([(1, 'a'), (2, 'b')]));
+/// ```
+pub struct OrderMapSeed<K, V, S> {
+    hasher: S,
+    capacity: Option<usize>,
+    marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<K, V, S> OrderMapSeed<K, V, S> {
+    /// Creates a seed that deserializes a map using `hasher`.
+    pub fn new(hasher: S) -> Self {
+        Self::with_capacity_and_hasher(None, hasher)
+    }
+
+    /// Creates a seed that deserializes a map using `hasher`, with room for at
+    /// least `capacity` entries if that is `Some`.
+    ///
+    /// Without a capacity, this preallocates according to the deserializer's
+    /// size hint, within a cautious limit.
+    pub fn with_capacity_and_hasher(capacity: Option<usize>, hasher: S) -> Self {
+        OrderMapSeed {
+            hasher,
+            capacity,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V, S: Clone> Clone for OrderMapSeed<K, V, S> {
+    fn clone(&self) -> Self {
+        Self::with_capacity_and_hasher(self.capacity, self.hasher.clone())
+    }
+}
+
+impl<K, V, S: fmt::Debug> fmt::Debug for OrderMapSeed<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrderMapSeed")
+            .field("hasher", &self.hasher)
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+impl<'de, K, V, S> de::DeserializeSeed<'de> for OrderMapSeed<K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    S: BuildHasher,
+{
+    type Value = OrderMap<K, V, S>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(SeedMapVisitor {
+            hasher: self.hasher,
+            capacity: self.capacity,
+            marker: PhantomData,
+        })
+    }
+}
+
+struct SeedMapVisitor<K, V, S> {
+    hasher: S,
+    capacity: Option<usize>,
+    marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'de, K, V, S> Visitor<'de> for SeedMapVisitor<K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    S: BuildHasher,
+{
+    type Value = OrderMap<K, V, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let capacity = self
+            .capacity
+            .unwrap_or_else(|| cautious_capacity::<K, V>(access.size_hint()));
+        let mut map = OrderMap::with_capacity_and_hasher(capacity, self.hasher);
+        while let Some((key, value)) = access.next_entry()? {
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+}
+
+/// A [`DeserializeSeed`][de::DeserializeSeed] for an [`OrderSet`] with a given hasher.
+///
+/// The [`Deserialize`] implementation of `OrderSet` needs `S: Default` to
+/// create its hasher, which doesn't work for a keyed or runtime-seeded hasher.
+/// This seed carries the hasher instance instead, and optionally a capacity
+/// to reserve up front.
+///
+/// # Examples
+///
+/// ```
+/// use ordermap::OrderSet;
+/// use ordermap::set::OrderSetSeed;
+/// use serde::de::DeserializeSeed;
+/// use serde::de::value::{Error, SeqDeserializer};
+/// use std::hash::RandomState;
+///
+/// let input = SeqDeserializer::<_, Error>::new([3, 1, 2].into_iter());
+/// let set: OrderSet<i32> = OrderSetSeed::new(RandomState::new()).deserialize(input).unwrap();
+/// assert_eq!(set.as_slice(), &[3, 1, 2]);
+/// ```
+pub struct OrderSetSeed<T, S> {
+    hasher: S,
+    capacity: Option<usize>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T, S> OrderSetSeed<T, S> {
+    /// Creates a seed that deserializes a set using `hasher`.
+    pub fn new(hasher: S) -> Self {
+        Self::with_capacity_and_hasher(None, hasher)
+    }
+
+    /// Creates a seed that deserializes a set using `hasher`, with room for at
+    /// least `capacity` values if that is `Some`.
+    ///
+    /// Without a capacity, this preallocates according to the deserializer's
+    /// size hint, within a cautious limit.
+    pub fn with_capacity_and_hasher(capacity: Option<usize>, hasher: S) -> Self {
+        OrderSetSeed {
+            hasher,
+            capacity,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, S: Clone> Clone for OrderSetSeed<T, S> {
+    fn clone(&self) -> Self {
+        Self::with_capacity_and_hasher(self.capacity, self.hasher.clone())
+    }
+}
+
+impl<T, S: fmt::Debug> fmt::Debug for OrderSetSeed<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrderSetSeed")
+            .field("hasher", &self.hasher)
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+impl<'de, T, S> de::DeserializeSeed<'de> for OrderSetSeed<T, S>
+where
+    T: Deserialize<'de> + Eq + Hash,
+    S: BuildHasher,
+{
+    type Value = OrderSet<T, S>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SeedSetVisitor {
+            hasher: self.hasher,
+            capacity: self.capacity,
+            marker: PhantomData,
+        })
+    }
+}
+
+struct SeedSetVisitor<T, S> {
+    hasher: S,
+    capacity: Option<usize>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<'de, T, S> Visitor<'de> for SeedSetVisitor<T, S>
+where
+    T: Deserialize<'de> + Eq + Hash,
+    S: BuildHasher,
+{
+    type Value = OrderSet<T, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a set")
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let capacity = self
+            .capacity
+            .unwrap_or_else(|| cautious_capacity::<T, ()>(access.size_hint()));
+        let mut set = OrderSet::with_capacity_and_hasher(capacity, self.hasher);
+        while let Some(value) = access.next_element()? {
+            set.insert(value);
+        }
+        Ok(set)
+    }
+}
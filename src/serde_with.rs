@@ -0,0 +1,199 @@
+//! Implementations of [`SerializeAs`] and [`DeserializeAs`] for [`OrderMap`]
+//! and [`OrderSet`], mostly forwarding to those of `IndexMap` and `IndexSet`.
+
+#![cfg_attr(docsrs, doc(cfg(feature = "serde_with")))]
+
+use crate::util::cautious_capacity;
+use crate::{OrderMap, OrderSet};
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::marker::PhantomData;
+use indexmap::{IndexMap, IndexSet};
+use serde_core::de::{Deserializer, Error, SeqAccess, Visitor};
+use serde_core::ser::Serializer;
+use serde_with::de::{DeserializeAs, DeserializeAsWrap};
+use serde_with::ser::SerializeAs;
+use serde_with::{
+    MapFirstKeyWins, MapPreventDuplicates, Seq, SetLastValueWins, SetPreventDuplicates,
+};
+
+/// Forwards `SerializeAs` and `DeserializeAs` of an `OrderMap` with the
+/// adapter `$as` to the adapter `$inner` for the inner `IndexMap`, which is
+/// the same adapter unless given.
+macro_rules! map_as {
+    ($as:ty) => {
+        map_as!($as => $as);
+    };
+    ($as:ty => $inner:ty) => {
+        impl<K, KAs, V, VAs, S> SerializeAs<OrderMap<K, V, S>> for $as
+        where
+            KAs: SerializeAs<K>,
+            VAs: SerializeAs<V>,
+        {
+            fn serialize_as<T>(source: &OrderMap<K, V, S>, serializer: T) -> Result<T::Ok, T::Error>
+            where
+                T: Serializer,
+            {
+                <$inner as SerializeAs<IndexMap<K, V, S>>>::serialize_as(&source.inner, serializer)
+            }
+        }
+
+        impl<'de, K, KAs, V, VAs, S> DeserializeAs<'de, OrderMap<K, V, S>> for $as
+        where
+            K: Eq + Hash,
+            KAs: DeserializeAs<'de, K>,
+            VAs: DeserializeAs<'de, V>,
+            S: BuildHasher + Default,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<OrderMap<K, V, S>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Ok(OrderMap {
+                    inner: <$inner as DeserializeAs<'de, IndexMap<K, V, S>>>::deserialize_as(
+                        deserializer,
+                    )?,
+                })
+            }
+        }
+    };
+}
+
+/// Forwards `SerializeAs` and `DeserializeAs` of an `OrderSet` with the
+/// adapter `$as` to the adapter `$inner` for the inner `IndexSet`, which is
+/// the same adapter unless given.
+macro_rules! set_as {
+    ($as:ty) => {
+        set_as!($as => $as);
+    };
+    ($as:ty => $inner:ty) => {
+        impl<T, TAs, S> SerializeAs<OrderSet<T, S>> for $as
+        where
+            TAs: SerializeAs<T>,
+        {
+            fn serialize_as<Ser>(source: &OrderSet<T, S>, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+            where
+                Ser: Serializer,
+            {
+                <$inner as SerializeAs<IndexSet<T, S>>>::serialize_as(&source.inner, serializer)
+            }
+        }
+
+        impl<'de, T, TAs, S> DeserializeAs<'de, OrderSet<T, S>> for $as
+        where
+            T: Eq + Hash,
+            TAs: DeserializeAs<'de, T>,
+            S: BuildHasher + Default,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<OrderSet<T, S>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Ok(OrderSet {
+                    inner: <$inner as DeserializeAs<'de, IndexSet<T, S>>>::deserialize_as(
+                        deserializer,
+                    )?,
+                })
+            }
+        }
+    };
+}
+
+map_as!(OrderMap<KAs, VAs, S> => IndexMap<KAs, VAs, S>);
+map_as!(Seq<(KAs, VAs)>);
+map_as!(Vec<(KAs, VAs)>);
+map_as!(MapPreventDuplicates<KAs, VAs>);
+map_as!(MapFirstKeyWins<KAs, VAs>);
+
+set_as!(OrderSet<TAs, S> => IndexSet<TAs, S>);
+set_as!(SetPreventDuplicates<TAs>);
+set_as!(SetLastValueWins<TAs>);
+
+/// An adapter for [`OrderMap`] to use with [`serde_with`]'s `serde_as`, which
+/// (de)serializes the map as an ordered sequence of `(key, value)` elements
+/// like [`Seq<(K, V)>`][Seq], but fails deserialization on a duplicate key.
+///
+/// This is the `serde_as` equivalent of [`serde_strict::seq`][crate::map::serde_strict::seq].
+///
+/// # Examples
+///
+/// ```
+/// use ordermap::OrderMap;
+/// use ordermap::map::SeqPreventDuplicates;
+/// use serde::{Deserialize, Serialize};
+/// use serde_with::{DisplayFromStr, serde_as};
+///
+/// #[serde_as]
+/// #[derive(Deserialize, Serialize)]
+/// struct Data {
+///     #[serde_as(as = "SeqPreventDuplicates<DisplayFromStr, _>")]
+///     map: OrderMap<u32, u64>,
+///     // ...
+/// }
+/// ```
+pub struct SeqPreventDuplicates<K, V>(PhantomData<(K, V)>);
+
+impl<K, KAs, V, VAs, S> SerializeAs<OrderMap<K, V, S>> for SeqPreventDuplicates<KAs, VAs>
+where
+    KAs: SerializeAs<K>,
+    VAs: SerializeAs<V>,
+{
+    fn serialize_as<T>(source: &OrderMap<K, V, S>, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: Serializer,
+    {
+        <Seq<(KAs, VAs)>>::serialize_as(source, serializer)
+    }
+}
+
+impl<'de, K, KAs, V, VAs, S> DeserializeAs<'de, OrderMap<K, V, S>>
+    for SeqPreventDuplicates<KAs, VAs>
+where
+    K: Eq + Hash,
+    KAs: DeserializeAs<'de, K>,
+    VAs: DeserializeAs<'de, V>,
+    S: BuildHasher + Default,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<OrderMap<K, V, S>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SeqPreventDuplicatesVisitor::<K, KAs, V, VAs, S>(
+            PhantomData,
+        ))
+    }
+}
+
+struct SeqPreventDuplicatesVisitor<K, KAs, V, VAs, S>(PhantomData<(K, KAs, V, VAs, S)>);
+
+impl<'de, K, KAs, V, VAs, S> Visitor<'de> for SeqPreventDuplicatesVisitor<K, KAs, V, VAs, S>
+where
+    K: Eq + Hash,
+    KAs: DeserializeAs<'de, K>,
+    VAs: DeserializeAs<'de, V>,
+    S: BuildHasher + Default,
+{
+    type Value = OrderMap<K, V, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a sequence of key-value pairs without duplicate keys")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let capacity = cautious_capacity::<K, V>(seq.size_hint());
+        let mut map = OrderMap::with_capacity_and_hasher(capacity, S::default());
+
+        while let Some(entry) = seq.next_element::<DeserializeAsWrap<(K, V), (KAs, VAs)>>()? {
+            let (key, value) = entry.into_inner();
+            if map.insert(key, value).is_some() {
+                return Err(Error::custom("invalid entry: found duplicate key"));
+            }
+        }
+
+        Ok(map)
+    }
+}
@@ -9,6 +9,7 @@
 //! Methods like [`OrderSet::remove`] use `IndexSet`'s "shift" semantics, so
 //! they preserve the relative order of remaining entries.
 
+mod cursor;
 mod iter;
 mod mutable;
 mod slice;
@@ -16,6 +17,7 @@ mod slice;
 #[cfg(test)]
 mod tests;
 
+pub use self::cursor::CursorMut;
 pub use self::mutable::MutableValues;
 pub use indexmap::set::{
     Difference, Drain, ExtractIf, Intersection, IntoIter, Iter, Slice, Splice, SymmetricDifference,
@@ -26,20 +28,40 @@ pub use indexmap::set::{
 #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
 pub mod rayon;
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde_sorted;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde_strict;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use crate::serde::OrderSetSeed;
+
+#[cfg(feature = "rkyv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+pub use crate::rkyv::{ArchivedOrderSet, ArchivedSetIter};
+
 use alloc::boxed::Box;
 use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{BuildHasher, Hash, Hasher};
-use core::ops::{BitAnd, BitOr, BitXor, Index, RangeBounds, Sub};
+use core::ops::{BitAnd, BitOr, BitXor, Index, Range, RangeBounds, Sub};
 use indexmap::IndexSet;
 
-#[cfg(doc)]
 use alloc::vec::Vec;
 
 #[cfg(feature = "std")]
 use std::hash::RandomState;
 
-use crate::{Equivalent, TryReserveError};
+use crate::OrderMap;
+use crate::util::{self, check_index_le, check_index_lt, try_simplify_range};
+use crate::{
+    AnchorNotFound, CycleError, Equivalent, IndexOutOfBounds, InsertOutOfBounds, PermutationError,
+    RelativePositionError, RemoveIndicesError, ReorderPolicy, TryReserveError,
+};
 
 /// A hash set where the iteration order of the values is independent of their
 /// hash values.
@@ -234,6 +256,21 @@ impl<T, S> OrderSet<T, S> {
         self.inner.drain(range)
     }
 
+    /// Clears the `OrderSet` in the given index range, returning those values
+    /// as a drain iterator, or an error if the range is invalid.
+    ///
+    /// This is a checked version of [`drain`][Self::drain], returning
+    /// [`IndexOutOfBounds`] if the starting point is greater than the end point
+    /// or if the end point is greater than the length of the set. The set is
+    /// unchanged in the error case.
+    pub fn try_drain<R>(&mut self, range: R) -> Result<Drain<'_, T>, IndexOutOfBounds>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = try_simplify_range(range, self.len())?;
+        Ok(self.inner.drain(range))
+    }
+
     /// Creates an iterator which uses a closure to determine if a value should be removed,
     /// for all values in the given range.
     ///
@@ -297,6 +334,63 @@ impl<T, S> OrderSet<T, S> {
         }
     }
 
+    /// Splits the collection into two at the given index, or returns an error
+    /// if `at > len`.
+    ///
+    /// This is a checked version of [`split_off`][Self::split_off].
+    /// The set is unchanged in the error case.
+    pub fn try_split_off(&mut self, at: usize) -> Result<Self, IndexOutOfBounds>
+    where
+        S: Clone,
+    {
+        check_index_le(at, self.len())?;
+        Ok(self.split_off(at))
+    }
+
+    /// Splits the collection into chunks of `size` elements each, in order,
+    /// except for the last chunk, which may be shorter.
+    ///
+    /// The elements are moved into the chunks, and each chunk gets a clone of
+    /// the hasher. The first chunk reuses the collection itself, shrunk to fit,
+    /// so it doesn't keep the whole original allocation. An empty collection
+    /// produces no chunks.
+    ///
+    /// ***Panics*** if `size` is zero.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderSet;
+    ///
+    /// let set: OrderSet<i32> = (0..5).collect();
+    /// let chunks = set.chunk_into(2);
+    /// assert_eq!(chunks.len(), 3);
+    /// assert_eq!(chunks[0].as_slice(), &[0, 1]);
+    /// assert_eq!(chunks[1].as_slice(), &[2, 3]);
+    /// assert_eq!(chunks[2].as_slice(), &[4]);
+    /// ```
+    #[track_caller]
+    pub fn chunk_into(mut self, size: usize) -> Vec<Self>
+    where
+        S: Clone,
+    {
+        assert!(size != 0, "chunk size must be non-zero");
+        let mut chunks = Vec::with_capacity(self.len().div_ceil(size));
+        while self.len() > size {
+            // Split off the last chunk, so that no value is moved twice.
+            let at = (self.len() - 1) / size * size;
+            chunks.push(self.split_off(at));
+        }
+        if !self.is_empty() {
+            self.shrink_to_fit();
+            chunks.push(self);
+        }
+        chunks.reverse();
+        chunks
+    }
+
     /// Reserve capacity for `additional` more values.
     ///
     /// Computes in **O(n)** time.
@@ -492,6 +586,101 @@ where
         self.inner.insert_before(index, value)
     }
 
+    /// Insert the value into the set before the value at the given index, or at the end,
+    /// or returns an error if `index` is out of bounds.
+    ///
+    /// This is a checked version of [`insert_before`][Self::insert_before].
+    /// Valid indices are `0..=set.len()` (inclusive).
+    /// The set is unchanged in the error case, and the error gives back the value.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn try_insert_before(
+        &mut self,
+        index: usize,
+        value: T,
+    ) -> Result<(usize, bool), InsertOutOfBounds<T>> {
+        match check_index_le(index, self.len()) {
+            Ok(()) => Ok(self.inner.insert_before(index, value)),
+            Err(error) => Err(InsertOutOfBounds { error, item: value }),
+        }
+    }
+
+    /// Insert the value into the set just before the value `anchor`.
+    ///
+    /// This is equivalent to finding the index of `anchor` with
+    /// [`get_index_of`][Self::get_index_of], then calling
+    /// [`insert_before`][Self::insert_before] with that index, so an existing
+    /// value is moved next to the anchor.
+    ///
+    /// Returns the new index and whether the value was newly inserted, or an
+    /// [`AnchorNotFound`] error holding the value if `anchor` is not in the set.
+    /// The set is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{AnchorNotFound, OrderSet};
+    /// let mut set = OrderSet::from(['a', 'b', 'c']);
+    ///
+    /// assert_eq!(set.insert_before_value(&'b', 'x'), Ok((1, true)));
+    /// assert_eq!(set.insert_before_value(&'b', 'c'), Ok((2, false)));
+    /// assert_eq!(set.insert_before_value(&'z', 'y'), Err(AnchorNotFound { item: 'y' }));
+    /// assert_eq!(set.as_slice(), &['a', 'x', 'c', 'b']);
+    /// ```
+    pub fn insert_before_value<Q>(
+        &mut self,
+        anchor: &Q,
+        value: T,
+    ) -> Result<(usize, bool), AnchorNotFound<T>>
+    where
+        Q: ?Sized + Hash + Equivalent<T>,
+    {
+        match self.get_index_of(anchor) {
+            Some(index) => Ok(self.inner.insert_before(index, value)),
+            None => Err(AnchorNotFound { item: value }),
+        }
+    }
+
+    /// Insert the value into the set just after the value `anchor`.
+    ///
+    /// This is equivalent to finding the index of `anchor` with
+    /// [`get_index_of`][Self::get_index_of], then calling
+    /// [`insert_before`][Self::insert_before] with the following index, so an
+    /// existing value is moved next to the anchor.
+    ///
+    /// Returns the new index and whether the value was newly inserted, or an
+    /// [`AnchorNotFound`] error holding the value if `anchor` is not in the set.
+    /// The set is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{AnchorNotFound, OrderSet};
+    /// let mut set = OrderSet::from(['a', 'b', 'c']);
+    ///
+    /// assert_eq!(set.insert_after(&'b', 'x'), Ok((2, true)));
+    /// assert_eq!(set.insert_after(&'b', 'a'), Ok((1, false)));
+    /// assert_eq!(set.insert_after(&'z', 'y'), Err(AnchorNotFound { item: 'y' }));
+    /// assert_eq!(set.as_slice(), &['b', 'a', 'x', 'c']);
+    /// ```
+    pub fn insert_after<Q>(
+        &mut self,
+        anchor: &Q,
+        value: T,
+    ) -> Result<(usize, bool), AnchorNotFound<T>>
+    where
+        Q: ?Sized + Hash + Equivalent<T>,
+    {
+        match self.get_index_of(anchor) {
+            Some(index) => Ok(self.inner.insert_before(index + 1, value)),
+            None => Err(AnchorNotFound { item: value }),
+        }
+    }
+
     /// Insert the value into the set at the given index.
     ///
     /// If an equivalent item already exists in the set, it returns `false` leaving
@@ -549,6 +738,45 @@ where
         self.inner.shift_insert(index, value)
     }
 
+    /// Insert the value into the set at the given index, or returns an error
+    /// if `index` is out of bounds.
+    ///
+    /// This is a checked version of [`shift_insert`][Self::shift_insert].
+    /// Valid indices are `0..set.len()` (exclusive) when moving an existing value, or
+    /// `0..=set.len()` (inclusive) when inserting a new value.
+    /// The set is unchanged in the error case, and the error gives back the value.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{IndexOutOfBounds, OrderSet};
+    /// let mut set: OrderSet<char> = ('a'..='z').collect();
+    ///
+    /// // Existing values can't move to the endpoint, but new values can insert there.
+    /// let err = set.try_shift_insert(set.len(), 'a').unwrap_err();
+    /// assert_eq!(err.error, IndexOutOfBounds { index: 26, len: 26 });
+    /// assert_eq!(err.item, 'a');
+    /// assert_eq!(set.try_shift_insert(set.len(), '*'), Ok(true));
+    /// assert_eq!(set.get_index_of(&'*'), Some(26));
+    /// ```
+    pub fn try_shift_insert(
+        &mut self,
+        index: usize,
+        value: T,
+    ) -> Result<bool, InsertOutOfBounds<T>> {
+        let len = self.len();
+        let checked = match self.inner.get_index_of(&value) {
+            Some(_) => check_index_lt(index, len),
+            None => check_index_le(index, len),
+        };
+        match checked {
+            Ok(()) => Ok(self.inner.shift_insert(index, value)),
+            Err(error) => Err(InsertOutOfBounds { error, item: value }),
+        }
+    }
+
     /// Adds a value to the set, replacing the existing value, if any, that is
     /// equal to the given one, without altering its insertion order. Returns
     /// the replaced value.
@@ -667,6 +895,27 @@ where
         self.inner.splice(range, replace_with)
     }
 
+    /// Creates a splicing iterator that replaces the specified range in the set
+    /// with the given `replace_with` iterator and yields the removed items, or
+    /// returns an error if the range is invalid.
+    ///
+    /// This is a checked version of [`splice`][Self::splice], returning
+    /// [`IndexOutOfBounds`] if the starting point is greater than the end point
+    /// or if the end point is greater than the length of the set. The set is
+    /// unchanged in the error case.
+    pub fn try_splice<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Result<Splice<'_, I::IntoIter, T, S>, IndexOutOfBounds>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let range = try_simplify_range(range, self.len())?;
+        Ok(self.inner.splice(range, replace_with))
+    }
+
     /// Moves all values from `other` into `self`, leaving `other` empty.
     ///
     /// This is equivalent to calling [`insert`][Self::insert] for each value
@@ -741,6 +990,151 @@ where
         self.inner.get_index_of(value)
     }
 
+    /// Get a cursor pointing at the value equivalent to `value`, if it is
+    /// present, else `None`.
+    ///
+    /// See [`CursorMut`] for how to move the cursor and edit the set with it.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn cursor_at_value<Q>(&mut self, value: &Q) -> Option<CursorMut<'_, T, S>>
+    where
+        Q: ?Sized + Hash + Equivalent<T>,
+    {
+        let index = self.get_index_of(value)?;
+        Some(CursorMut::new(self, index))
+    }
+
+    /// Returns a slice of the values from `start` up to, but not including,
+    /// `end`, in their current order.
+    ///
+    /// Returns `None` if either value is not in the set, or if `end` comes
+    /// before `start`. The slice is empty if they are the same value.
+    ///
+    /// Computes in **O(1)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderSet;
+    ///
+    /// let set: OrderSet<char> = ('a'..='f').collect();
+    /// assert_eq!(set.range_between(&'b', &'e').unwrap(), &['b', 'c', 'd']);
+    /// assert_eq!(set.range_between_inclusive(&'b', &'e').unwrap(), &['b', 'c', 'd', 'e']);
+    /// assert_eq!(set.range_from_value(&'e').unwrap(), &['e', 'f']);
+    /// assert_eq!(set.range_to_value(&'b').unwrap(), &['a']);
+    /// assert_eq!(set.range_between(&'e', &'b'), None);
+    /// ```
+    pub fn range_between<Q1, Q2>(&self, start: &Q1, end: &Q2) -> Option<&Slice<T>>
+    where
+        Q1: ?Sized + Hash + Equivalent<T>,
+        Q2: ?Sized + Hash + Equivalent<T>,
+    {
+        let start = self.get_index_of(start)?;
+        let end = self.get_index_of(end)?;
+        self.get_range(start..end)
+    }
+
+    /// Returns a slice of the values from `start` up to and including `end`,
+    /// in their current order.
+    ///
+    /// Returns `None` if either value is not in the set, or if `end` comes
+    /// before `start`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn range_between_inclusive<Q1, Q2>(&self, start: &Q1, end: &Q2) -> Option<&Slice<T>>
+    where
+        Q1: ?Sized + Hash + Equivalent<T>,
+        Q2: ?Sized + Hash + Equivalent<T>,
+    {
+        let start = self.get_index_of(start)?;
+        let end = self.get_index_of(end)?;
+        if end < start {
+            return None;
+        }
+        self.get_range(start..=end)
+    }
+
+    /// Returns a slice of the values from `start` to the end of the set, or
+    /// `None` if `start` is not in the set.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn range_from_value<Q>(&self, start: &Q) -> Option<&Slice<T>>
+    where
+        Q: ?Sized + Hash + Equivalent<T>,
+    {
+        let start = self.get_index_of(start)?;
+        Some(&self.as_slice()[start..])
+    }
+
+    /// Returns a slice of the values from the start of the set up to, but
+    /// not including, `end`, or `None` if `end` is not in the set.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn range_to_value<Q>(&self, end: &Q) -> Option<&Slice<T>>
+    where
+        Q: ?Sized + Hash + Equivalent<T>,
+    {
+        let end = self.get_index_of(end)?;
+        Some(&self.as_slice()[..end])
+    }
+
+    /// Splits the set into two at the position of `value`, or returns `None`
+    /// if `value` is not in the set.
+    ///
+    /// Returns a newly allocated set containing `value` and all values that
+    /// follow it. After the call, the original set will be left containing
+    /// the values before it, with its previous capacity unchanged.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderSet;
+    ///
+    /// let mut set: OrderSet<char> = ('a'..='e').collect();
+    /// let tail = set.split_by_value(&'c').unwrap();
+    /// assert_eq!(set.as_slice(), &['a', 'b']);
+    /// assert_eq!(tail.as_slice(), &['c', 'd', 'e']);
+    /// assert!(set.split_by_value(&'z').is_none());
+    /// ```
+    pub fn split_by_value<Q>(&mut self, value: &Q) -> Option<Self>
+    where
+        Q: ?Sized + Hash + Equivalent<T>,
+        S: Clone,
+    {
+        let at = self.get_index_of(value)?;
+        Some(self.split_off(at))
+    }
+
+    /// Splits the set into the values for which `pred` returns `true`, and
+    /// those for which it returns `false`, both in their original order.
+    ///
+    /// The values are moved, and the second set gets a clone of the hasher.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderSet;
+    ///
+    /// let set: OrderSet<i32> = (0..6).collect();
+    /// let (even, odd) = set.partition(|value| value % 2 == 0);
+    /// assert_eq!(even.as_slice(), &[0, 2, 4]);
+    /// assert_eq!(odd.as_slice(), &[1, 3, 5]);
+    /// ```
+    pub fn partition<F>(mut self, mut pred: F) -> (Self, Self)
+    where
+        T: Hash + Eq,
+        F: FnMut(&T) -> bool,
+        S: Clone,
+    {
+        let mut rejected = Self::with_hasher(self.hasher().clone());
+        rejected.extend(self.inner.extract_if(.., |value| !pred(value)));
+        (self, rejected)
+    }
+
     /// Remove the value from the set, and return `true` if it was present.
     ///
     /// **NOTE:** This is equivalent to [`IndexSet::shift_remove`], and
@@ -837,6 +1231,238 @@ where
     {
         self.inner.swap_remove_full(value)
     }
+
+    /// Remove the values equivalent to any of the given `values` in a single
+    /// pass, and return them in their original order.
+    ///
+    /// Values that are not in the set, or that are given more than once, are
+    /// ignored. The remaining values keep their relative order.
+    ///
+    /// Computes in **O(n + k)** time (average), for `k` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderSet;
+    ///
+    /// let mut set: OrderSet<char> = ('a'..='f').collect();
+    /// let removed = set.remove_values(&['e', 'b', 'z', 'b']);
+    /// assert_eq!(removed, ['b', 'e']);
+    /// assert!(set.iter().eq(&['a', 'c', 'd', 'f']));
+    /// ```
+    pub fn remove_values<'q, Q, I>(&mut self, values: I) -> Vec<T>
+    where
+        Q: ?Sized + 'q + Hash + Equivalent<T>,
+        I: IntoIterator<Item = &'q Q>,
+    {
+        let mut remove = alloc::vec![false; self.len()];
+        for value in values {
+            if let Some(index) = self.get_index_of(value) {
+                remove[index] = true;
+            }
+        }
+        self.remove_marked(&remove)
+    }
+
+    /// Moves `value` to the position just before `anchor`, shifting all other
+    /// values in-between.
+    ///
+    /// Returns the new index of the moved value, or an error if either `value`
+    /// or `anchor` is not in the set. The set is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{OrderSet, RelativePositionError};
+    /// let mut set: OrderSet<char> = ('a'..='e').collect();
+    ///
+    /// assert_eq!(set.move_before(&'a', &'d'), Ok(2));
+    /// assert_eq!(set.as_slice(), &['b', 'c', 'a', 'd', 'e']);
+    ///
+    /// assert_eq!(set.move_before(&'z', &'b'), Err(RelativePositionError::NotFound));
+    /// assert_eq!(set.move_before(&'b', &'z'), Err(RelativePositionError::AnchorNotFound));
+    /// ```
+    pub fn move_before<Q1, Q2>(
+        &mut self,
+        value: &Q1,
+        anchor: &Q2,
+    ) -> Result<usize, RelativePositionError>
+    where
+        Q1: ?Sized + Hash + Equivalent<T>,
+        Q2: ?Sized + Hash + Equivalent<T>,
+    {
+        let (from, anchor) = self.relative_indices(value, anchor)?;
+        let to = if from < anchor { anchor - 1 } else { anchor };
+        self.inner.move_index(from, to);
+        Ok(to)
+    }
+
+    /// Moves `value` to the position just after `anchor`, shifting all other
+    /// values in-between.
+    ///
+    /// Returns the new index of the moved value, or an error if either `value`
+    /// or `anchor` is not in the set. The set is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderSet;
+    /// let mut set: OrderSet<char> = ('a'..='e').collect();
+    ///
+    /// assert_eq!(set.move_after(&'e', &'b'), Ok(2));
+    /// assert_eq!(set.as_slice(), &['a', 'b', 'e', 'c', 'd']);
+    /// ```
+    pub fn move_after<Q1, Q2>(
+        &mut self,
+        value: &Q1,
+        anchor: &Q2,
+    ) -> Result<usize, RelativePositionError>
+    where
+        Q1: ?Sized + Hash + Equivalent<T>,
+        Q2: ?Sized + Hash + Equivalent<T>,
+    {
+        let (from, anchor) = self.relative_indices(value, anchor)?;
+        let to = if from > anchor { anchor + 1 } else { anchor };
+        self.inner.move_index(from, to);
+        Ok(to)
+    }
+
+    /// Swaps the positions of values `a` and `b`.
+    ///
+    /// Returns an error if either value is not in the set, treating `b` as the
+    /// anchor. The set is unchanged in the error case.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn swap_values<Q1, Q2>(&mut self, a: &Q1, b: &Q2) -> Result<(), RelativePositionError>
+    where
+        Q1: ?Sized + Hash + Equivalent<T>,
+        Q2: ?Sized + Hash + Equivalent<T>,
+    {
+        let (a, b) = self.relative_indices(a, b)?;
+        self.inner.swap_indices(a, b);
+        Ok(())
+    }
+
+    /// Returns an order of the set's values in which every value comes after
+    /// its dependencies, without modifying the set.
+    ///
+    /// The closure `deps` lists the values that each value depends on, which
+    /// may be borrowed from the set itself. Dependencies that are not in the
+    /// set are ignored. Wherever the dependencies allow it, values keep their
+    /// current relative order, so the result is deterministic.
+    ///
+    /// The result is a permutation in the form taken by
+    /// [`apply_permutation`][Self::apply_permutation]: `indices[i]` is the
+    /// current index of the value that goes to index `i`.
+    ///
+    /// Returns an error with the values of one cycle if there is no such order.
+    ///
+    /// Computes in **O(n log n + e)** time (average), where *e* is the number
+    /// of dependencies.
+    pub fn topological_order<'a, Q, I, F>(
+        &'a self,
+        mut deps: F,
+    ) -> Result<Vec<usize>, CycleError<&'a T>>
+    where
+        Q: 'a + ?Sized + Hash + Equivalent<T>,
+        I: IntoIterator<Item = &'a Q>,
+        F: FnMut(&'a T) -> I,
+    {
+        let edges: Vec<Vec<usize>> = self
+            .iter()
+            .map(|value| {
+                (deps(value).into_iter())
+                    .filter_map(|dep| self.get_index_of(dep))
+                    .collect()
+            })
+            .collect();
+        util::topological_order(&edges).map_err(|cycle| self.cycle_error(cycle))
+    }
+
+    /// Sorts the set's values in place so that every value comes after its
+    /// dependencies.
+    ///
+    /// The closure `deps` lists the values that each value depends on, as
+    /// owned values that are looked up by [`Equivalent`]. To borrow them from
+    /// the set instead, use [`topological_order`][Self::topological_order]
+    /// and then [`apply_permutation`][Self::apply_permutation].
+    ///
+    /// Dependencies that are not in the set are ignored. Wherever the
+    /// dependencies allow it, values keep their current relative order, so
+    /// the sort is stable and deterministic.
+    ///
+    /// Returns an error with the values of one cycle if there is no such
+    /// order, leaving the set unchanged.
+    ///
+    /// Computes in **O(n log n + e)** time (average), where *e* is the number
+    /// of dependencies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{CycleError, OrderSet};
+    ///
+    /// // Each number depends on its proper divisors.
+    /// let divisors = |&n: &u32| (1..n).filter(move |d| n % d == 0);
+    ///
+    /// let mut set = OrderSet::from([12, 5, 4, 1, 6, 2, 3]);
+    /// set.sort_topologically(divisors).unwrap();
+    /// assert_eq!(set.as_slice(), &[1, 5, 2, 4, 3, 6, 12]);
+    ///
+    /// let err = set.sort_topologically(|&n| [n % 3 + 1]).unwrap_err();
+    /// assert_eq!(err, CycleError { cycle: vec![&1, &2, &3] });
+    /// ```
+    pub fn sort_topologically<Q, I, F>(&mut self, mut deps: F) -> Result<(), CycleError<&T>>
+    where
+        Q: Hash + Equivalent<T>,
+        I: IntoIterator<Item = Q>,
+        F: FnMut(&T) -> I,
+    {
+        let edges: Vec<Vec<usize>> = self
+            .iter()
+            .map(|value| {
+                (deps(value).into_iter())
+                    .filter_map(|dep| self.get_index_of(&dep))
+                    .collect()
+            })
+            .collect();
+        match util::topological_order(&edges) {
+            Ok(order) => {
+                self.apply_sorted_indices(&order);
+                Ok(())
+            }
+            Err(cycle) => Err(self.cycle_error(cycle)),
+        }
+    }
+
+    fn cycle_error(&self, cycle: Vec<usize>) -> CycleError<&T> {
+        let values = self.as_slice();
+        CycleError {
+            cycle: cycle.into_iter().map(|i| &values[i]).collect(),
+        }
+    }
+
+    fn relative_indices<Q1, Q2>(
+        &self,
+        value: &Q1,
+        anchor: &Q2,
+    ) -> Result<(usize, usize), RelativePositionError>
+    where
+        Q1: ?Sized + Hash + Equivalent<T>,
+        Q2: ?Sized + Hash + Equivalent<T>,
+    {
+        let index = self
+            .get_index_of(value)
+            .ok_or(RelativePositionError::NotFound)?;
+        let anchor = self
+            .get_index_of(anchor)
+            .ok_or(RelativePositionError::AnchorNotFound)?;
+        Ok((index, anchor))
+    }
 }
 
 impl<T, S> OrderSet<T, S> {
@@ -990,6 +1616,148 @@ impl<T, S> OrderSet<T, S> {
         self.inner.sort_by_cached_key(sort_key)
     }
 
+    /// Returns the order that sorting with the comparison function `cmp`
+    /// would produce, without modifying the set.
+    ///
+    /// The result is a permutation in the form taken by
+    /// [`apply_permutation`][Self::apply_permutation]: `indices[i]` is the
+    /// current index of the value that sorts into index `i`.
+    ///
+    /// Computes in **O(n log n)** time and **O(n)** space. The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderSet;
+    ///
+    /// let set = OrderSet::from(['c', 'a', 'b']);
+    /// assert_eq!(set.sorted_indices_by(|a, b| a.cmp(b)), [1, 2, 0]);
+    /// ```
+    pub fn sorted_indices_by<F>(&self, mut cmp: F) -> Vec<usize>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let values = self.as_slice();
+        let mut indices: Vec<usize> = (0..values.len()).collect();
+        indices.sort_by(|&a, &b| cmp(&values[a], &values[b]));
+        indices
+    }
+
+    /// Sort the set's values in place using the comparison function `cmp`,
+    /// and return the permutation that was applied.
+    ///
+    /// The returned permutation is the same as from
+    /// [`sorted_indices_by`][Self::sorted_indices_by]: `indices[i]` is the old
+    /// index of the value that is now at index `i`.
+    ///
+    /// Computes in **O(n log n)** time and **O(n)** space. The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderSet;
+    ///
+    /// let mut set = OrderSet::from(["c", "a", "b"]);
+    /// let indices = set.sort_by_with_permutation(|a, b| a.cmp(b));
+    /// assert_eq!(indices, [1, 2, 0]);
+    /// assert_eq!(set.as_slice(), &["a", "b", "c"]);
+    /// ```
+    pub fn sort_by_with_permutation<F>(&mut self, cmp: F) -> Vec<usize>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let indices = self.sorted_indices_by(cmp);
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the set's values in place using a key extraction function, and
+    /// return the permutation that was applied.
+    ///
+    /// See [`sort_by_with_permutation`][Self::sort_by_with_permutation] for
+    /// the form of the permutation.
+    ///
+    /// Computes in **O(n log n)** time and **O(n)** space. The sort is stable.
+    pub fn sort_by_key_with_permutation<K, F>(&mut self, mut sort_key: F) -> Vec<usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let values = self.as_slice();
+        let mut indices: Vec<usize> = (0..values.len()).collect();
+        indices.sort_by_key(|&i| sort_key(&values[i]));
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the set's values in place using the comparison function `cmp`,
+    /// and return the permutation that was applied.
+    ///
+    /// See [`sort_by_with_permutation`][Self::sort_by_with_permutation] for
+    /// the form of the permutation.
+    ///
+    /// Computes in **O(n log n)** time and **O(n)** space. The sort is unstable.
+    pub fn sort_unstable_by_with_permutation<F>(&mut self, mut cmp: F) -> Vec<usize>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let values = self.as_slice();
+        let mut indices: Vec<usize> = (0..values.len()).collect();
+        indices.sort_unstable_by(|&a, &b| cmp(&values[a], &values[b]));
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the set's values in place using a key extraction function, and
+    /// return the permutation that was applied.
+    ///
+    /// See [`sort_by_with_permutation`][Self::sort_by_with_permutation] for
+    /// the form of the permutation.
+    ///
+    /// Computes in **O(n log n)** time and **O(n)** space. The sort is unstable.
+    pub fn sort_unstable_by_key_with_permutation<K, F>(&mut self, mut sort_key: F) -> Vec<usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let values = self.as_slice();
+        let mut indices: Vec<usize> = (0..values.len()).collect();
+        indices.sort_unstable_by_key(|&i| sort_key(&values[i]));
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the set's values in place using a key extraction function, and
+    /// return the permutation that was applied.
+    ///
+    /// During sorting, the function is called at most once per value, like
+    /// [`sort_by_cached_key`][Self::sort_by_cached_key]. See
+    /// [`sort_by_with_permutation`][Self::sort_by_with_permutation] for the
+    /// form of the permutation.
+    ///
+    /// Computes in **O(m n + n log n)** time and **O(n)** space, where the
+    /// function is **O(m)** and *n* is the length of the set. The sort is stable.
+    pub fn sort_by_cached_key_with_permutation<K, F>(&mut self, mut sort_key: F) -> Vec<usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let values = self.as_slice();
+        let mut indices: Vec<usize> = (0..values.len()).collect();
+        indices.sort_by_cached_key(|&i| sort_key(&values[i]));
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Reorders by a permutation that is known to be valid, i.e. from sorting
+    /// the indices `0..self.len()`.
+    pub(crate) fn apply_sorted_indices(&mut self, indices: &[usize]) {
+        let inverse = util::inverse_permutation(indices, self.len())
+            .expect("sorted indices should form a permutation");
+        let inner = &mut self.inner;
+        util::permute_by_swaps(inverse, |a, b| inner.swap_indices(a, b));
+    }
+
     /// Search over a sorted set for a value.
     ///
     /// Returns the position where that value is present, or the position where it can be inserted
@@ -1128,6 +1896,31 @@ impl<T, S> OrderSet<T, S> {
         self.inner.last()
     }
 
+    /// Get a cursor pointing at the first value of the set, or at the ghost
+    /// non-element if the set is empty.
+    ///
+    /// See [`CursorMut`] for how to move the cursor and edit the set with it.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, S> {
+        CursorMut::new(self, 0)
+    }
+
+    /// Get a cursor pointing at the value at `index`, or at the ghost
+    /// non-element if `index` is the length of the set.
+    ///
+    /// ***Panics*** if `index` is out of bounds.
+    /// Valid indices are `0..=set.len()` (inclusive).
+    ///
+    /// Computes in **O(1)** time.
+    #[track_caller]
+    pub fn cursor_at(&mut self, index: usize) -> CursorMut<'_, T, S> {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "index out of bounds: the len is {len} but the index is {index}"
+        );
+        CursorMut::new(self, index)
+    }
+
     /// Remove the value by index
     ///
     /// Valid indices are `0 <= index < self.len()`
@@ -1155,6 +1948,52 @@ impl<T, S> OrderSet<T, S> {
         self.inner.swap_remove_index(index)
     }
 
+    /// Remove the values at all of the given `indices` in a single pass,
+    /// and return them in their original order.
+    ///
+    /// The indices may be given in any order, and refer to the positions
+    /// before any removal. The remaining values keep their relative order.
+    ///
+    /// Returns an error if an index is out of bounds or appears more than
+    /// once. The set is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{OrderSet, RemoveIndicesError};
+    ///
+    /// let mut set: OrderSet<char> = ('a'..='f').collect();
+    /// assert_eq!(set.remove_indices([4, 1, 2]), Ok(vec!['b', 'c', 'e']));
+    /// assert!(set.iter().eq(&['a', 'd', 'f']));
+    ///
+    /// assert_eq!(
+    ///     set.remove_indices([1, 5]),
+    ///     Err(RemoveIndicesError::IndexOutOfBounds { index: 5, len: 3 }),
+    /// );
+    /// assert_eq!(set.len(), 3);
+    /// ```
+    pub fn remove_indices<I>(&mut self, indices: I) -> Result<Vec<T>, RemoveIndicesError>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let remove = util::removal_mask(self.len(), indices)?;
+        Ok(self.remove_marked(&remove))
+    }
+
+    /// Remove the values whose indices are marked in `remove`, in a single pass.
+    fn remove_marked(&mut self, remove: &[bool]) -> Vec<T> {
+        let start = remove
+            .iter()
+            .position(|&marked| marked)
+            .unwrap_or(remove.len());
+        let mut marks = remove[start..].iter();
+        self.inner
+            .extract_if(start.., |_| *marks.next().unwrap())
+            .collect()
+    }
+
     /// Moves the position of a value from one index to another
     /// by shifting all other values in-between.
     ///
@@ -1169,6 +2008,22 @@ impl<T, S> OrderSet<T, S> {
         self.inner.move_index(from, to)
     }
 
+    /// Moves the position of a value from one index to another
+    /// by shifting all other values in-between, or returns an error if
+    /// `from` or `to` are out of bounds.
+    ///
+    /// This is a checked version of [`move_index`][Self::move_index].
+    /// The set is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn try_move_index(&mut self, from: usize, to: usize) -> Result<(), IndexOutOfBounds> {
+        let len = self.len();
+        check_index_lt(from, len)?;
+        check_index_lt(to, len)?;
+        self.inner.move_index(from, to);
+        Ok(())
+    }
+
     /// Swaps the position of two values in the set.
     ///
     /// ***Panics*** if `a` or `b` are out of bounds.
@@ -1178,6 +2033,220 @@ impl<T, S> OrderSet<T, S> {
     pub fn swap_indices(&mut self, a: usize, b: usize) {
         self.inner.swap_indices(a, b)
     }
+
+    /// Swaps the position of two values in the set, or returns an error if
+    /// `a` or `b` are out of bounds.
+    ///
+    /// This is a checked version of [`swap_indices`][Self::swap_indices].
+    /// The set is unchanged in the error case.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn try_swap_indices(&mut self, a: usize, b: usize) -> Result<(), IndexOutOfBounds> {
+        let len = self.len();
+        check_index_lt(a, len)?;
+        check_index_lt(b, len)?;
+        self.inner.swap_indices(a, b);
+        Ok(())
+    }
+}
+
+impl<T, S> OrderSet<T, S>
+where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Reorders the values in the set by the permutation `perm`, so the
+    /// value at the old index `perm[i]` moves to the new index `i`.
+    ///
+    /// Returns an error if `perm` is not a permutation of `0..self.len()`,
+    /// i.e. if its length differs or if it has an out-of-bounds or repeated
+    /// index. The set is unchanged in the error case.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{OrderSet, PermutationError};
+    ///
+    /// let mut set = OrderSet::from(['a', 'b', 'c', 'd']);
+    /// set.apply_permutation(&[2, 0, 3, 1]).unwrap();
+    /// assert_eq!(set.as_slice(), &['c', 'a', 'd', 'b']);
+    ///
+    /// let err = set.apply_permutation(&[0, 1, 2]).unwrap_err();
+    /// assert_eq!(err, PermutationError::LengthMismatch { len: 4, perm_len: 3 });
+    /// ```
+    pub fn apply_permutation(&mut self, perm: &[usize]) -> Result<(), PermutationError> {
+        let inverse = util::inverse_permutation(perm, self.len())?;
+        self.reorder_by(|index| inverse[index]);
+        Ok(())
+    }
+
+    /// Rotates the set in-place such that the values are shifted `mid`
+    /// places to the left, like [`slice::rotate_left`]. The value at index
+    /// `mid` becomes the first.
+    ///
+    /// ***Panics*** if `mid` is greater than the length of the set.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderSet;
+    ///
+    /// let mut set: OrderSet<char> = ('a'..='f').collect();
+    /// set.rotate_left(2);
+    /// assert_eq!(set.as_slice(), &['c', 'd', 'e', 'f', 'a', 'b']);
+    /// ```
+    #[track_caller]
+    pub fn rotate_left(&mut self, mid: usize) {
+        let len = self.len();
+        assert!(mid <= len, "mid {mid} out of range for length {len}");
+        self.move_entries(mid..len, 0);
+    }
+
+    /// Rotates the set in-place such that the values are shifted `k`
+    /// places to the right, like [`slice::rotate_right`]. The last `k` values
+    /// become the first.
+    ///
+    /// ***Panics*** if `k` is greater than the length of the set.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderSet;
+    ///
+    /// let mut set: OrderSet<char> = ('a'..='f').collect();
+    /// set.rotate_right(2);
+    /// assert_eq!(set.as_slice(), &['e', 'f', 'a', 'b', 'c', 'd']);
+    /// ```
+    #[track_caller]
+    pub fn rotate_right(&mut self, k: usize) {
+        let len = self.len();
+        assert!(k <= len, "k {k} out of range for length {len}");
+        self.move_entries(len - k..len, 0);
+    }
+
+    /// Moves the values in the given index range, so that the first of them
+    /// moves to index `to`, shifting all other values in-between.
+    ///
+    /// This is like [`move_index`][Self::move_index] for a block of values:
+    /// the moved values keep their relative order, as do all others.
+    /// Valid destinations are `0..=self.len() - range.len()` (inclusive).
+    ///
+    /// ***Panics*** if the starting point is greater than the end point, if
+    /// the end point is greater than the length of the set, or if `to` is
+    /// out of bounds.
+    ///
+    /// Computes in **O(n)** time (average).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderSet;
+    ///
+    /// let mut set: OrderSet<char> = ('a'..='f').collect();
+    /// set.move_range(1..3, 3);
+    /// assert_eq!(set.as_slice(), &['a', 'd', 'e', 'b', 'c', 'f']);
+    /// ```
+    #[track_caller]
+    pub fn move_range<R>(&mut self, range: R, to: usize)
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let range = util::simplify_range(range, len);
+        util::check_move_range(&range, to, len);
+        self.move_entries(range, to);
+    }
+
+    /// Reorders the set's values so that the values found in `reference`
+    /// follow its order, and `policy` decides where the other values go.
+    ///
+    /// Each value of the reference is looked up as an [`Equivalent`] value,
+    /// so the reference may hold a different type than the set.
+    ///
+    /// Computes in **O(n + m)** time (average), where *m* is the length of
+    /// the reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::{OrderSet, ReorderPolicy};
+    ///
+    /// let mut set = OrderSet::from([5, 1, 4, 2, 3]);
+    /// let reference = OrderSet::from([1, 2, 3]);
+    ///
+    /// set.sort_by_reference(&reference, ReorderPolicy::AbsentInPlace);
+    /// assert_eq!(set.as_slice(), &[5, 1, 4, 2, 3]);
+    ///
+    /// set.sort_by_reference(&reference, ReorderPolicy::AbsentFirst);
+    /// assert_eq!(set.as_slice(), &[5, 4, 1, 2, 3]);
+    /// ```
+    pub fn sort_by_reference<Q, S2>(&mut self, reference: &OrderSet<Q, S2>, policy: ReorderPolicy)
+    where
+        Q: Hash + Equivalent<T>,
+    {
+        self.reorder_by_values(reference.iter(), policy);
+    }
+
+    /// Reorders the set's values so that the values found among the keys of
+    /// `map` follow its order, and `policy` decides where the other values go.
+    ///
+    /// This is like [`sort_by_reference`][Self::sort_by_reference], using the
+    /// keys of `map` as the reference.
+    ///
+    /// Computes in **O(n + m)** time (average), where *m* is the length of
+    /// `map`.
+    pub fn reorder_like<Q, V, S2>(&mut self, map: &OrderMap<Q, V, S2>, policy: ReorderPolicy)
+    where
+        Q: Hash + Equivalent<T>,
+    {
+        self.reorder_by_values(map.keys(), policy);
+    }
+
+    fn reorder_by_values<'a, Q, I>(&mut self, values: I, policy: ReorderPolicy)
+    where
+        Q: 'a + Hash + Equivalent<T>,
+        I: Iterator<Item = &'a Q>,
+    {
+        let found: Vec<usize> = values
+            .filter_map(|value| self.get_index_of(value))
+            .collect();
+        let perm = util::reference_permutation(self.len(), found, policy);
+        let inverse = util::inverse_permutation(&perm, self.len())
+            .expect("reference order should form a permutation");
+        self.reorder_by(|index| inverse[index]);
+    }
+
+    /// Moves the values in `range` to start at `to`, which must be in bounds.
+    fn move_entries(&mut self, range: Range<usize>, to: usize) {
+        if !range.is_empty() && to != range.start {
+            self.reorder_by(|index| util::moved_index(index, &range, to));
+        }
+    }
+
+    /// Moves the value at each index `i` to the new index `new_index(i)`,
+    /// which must be a permutation of `0..self.len()`.
+    ///
+    /// This takes out all values in order, and puts them back in their new
+    /// order, so the hash table is rebuilt once rather than fixed up per move.
+    fn reorder_by<F>(&mut self, mut new_index: F)
+    where
+        F: FnMut(usize) -> usize,
+    {
+        let mut slots: Vec<Option<T>> = (0..self.len()).map(|_| None).collect();
+        for (index, entry) in self.inner.drain(..).enumerate() {
+            slots[new_index(index)] = Some(entry);
+        }
+        let entries = slots
+            .into_iter()
+            .map(|slot| slot.expect("new indices should form a permutation"));
+        self.inner.extend(entries);
+    }
 }
 
 /// Access [`OrderSet`] values at indexed positions.
@@ -1370,6 +2439,80 @@ where
     {
         self.inner == other.inner
     }
+
+    /// Returns `true` if the first elements of `self` are all elements of
+    /// `other`, in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::OrderSet;
+    ///
+    /// let set = OrderSet::from([1, 2, 3, 4]);
+    /// assert!(set.starts_with(&OrderSet::from([1, 2])));
+    /// assert!(!set.starts_with(&OrderSet::from([2, 1])));
+    /// assert!(set.ends_with(&OrderSet::from([3, 4])));
+    /// assert!(OrderSet::from([1, 2]).is_prefix_of(&set));
+    /// assert!(OrderSet::from([1, 4]).is_subsequence_of(&set));
+    /// assert!(!OrderSet::from([4, 1]).is_subsequence_of(&set));
+    /// assert!(OrderSet::from([4, 3, 2, 1]).is_permutation_of(&set));
+    /// assert_eq!(set.common_prefix_len(&OrderSet::from([1, 2, 5])), 2);
+    /// ```
+    pub fn starts_with<S2>(&self, other: &OrderSet<T, S2>) -> bool {
+        other.len() <= self.len() && self.common_prefix_len(other) == other.len()
+    }
+
+    /// Returns `true` if the last elements of `self` are all elements of
+    /// `other`, in the same order.
+    pub fn ends_with<S2>(&self, other: &OrderSet<T, S2>) -> bool {
+        match self.len().checked_sub(other.len()) {
+            Some(start) => self.as_slice()[start..].iter().eq(other),
+            None => false,
+        }
+    }
+
+    /// Returns `true` if all elements of `self` are the first elements of
+    /// `other`, in the same order.
+    pub fn is_prefix_of<S2>(&self, other: &OrderSet<T, S2>) -> bool {
+        self.len() <= other.len() && self.common_prefix_len(other) == self.len()
+    }
+
+    /// Returns `true` if all elements of `self` are contained in `other`, in
+    /// the same relative order.
+    ///
+    /// Computes in **O(n)** time (average), where *n* is the length of `self`.
+    pub fn is_subsequence_of<S2>(&self, other: &OrderSet<T, S2>) -> bool
+    where
+        S2: BuildHasher,
+    {
+        let mut next = 0;
+        self.len() <= other.len()
+            && self.iter().all(|value| match other.get_index_of(value) {
+                Some(index) if index >= next => {
+                    next = index + 1;
+                    true
+                }
+                _ => false,
+            })
+    }
+
+    /// Returns `true` if `self` and `other` contain exactly the same elements,
+    /// even if they are not in the same order.
+    ///
+    /// This is the same as [`set_eq`][Self::set_eq], since a set can't
+    /// contain any element more than once.
+    pub fn is_permutation_of<S2>(&self, other: &OrderSet<T, S2>) -> bool
+    where
+        S2: BuildHasher,
+    {
+        self.set_eq(other)
+    }
+
+    /// Returns the number of leading elements that are equal in `self` and
+    /// `other`.
+    pub fn common_prefix_len<S2>(&self, other: &OrderSet<T, S2>) -> usize {
+        self.iter().zip(other).take_while(|(a, b)| a == b).count()
+    }
 }
 
 impl<T, S1, S2> BitAnd<&OrderSet<T, S2>> for &OrderSet<T, S1>
@@ -0,0 +1,197 @@
+use super::OrderSet;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+
+/// A cursor over an [`OrderSet`] with editing operations.
+///
+/// A cursor is like an iterator, except that it can freely seek back and
+/// forth, and it can insert and remove values around its position. It is
+/// modeled on the `CursorMut` of [`LinkedList`][std_cursor].
+///
+/// A cursor points either at a value, or at a "ghost" non-element between
+/// the last and the first value. The ghost is at index `set.len()`, and
+/// [`current`][Self::current] returns `None` there. Moving past either end
+/// of the set lands on the ghost, and moving again wraps around to the other
+/// end.
+///
+/// This `struct` is created by the [`OrderSet::cursor_front_mut`],
+/// [`OrderSet::cursor_at`], and [`OrderSet::cursor_at_value`] methods.
+///
+/// [std_cursor]: https://doc.rust-lang.org/std/collections/linked_list/struct.CursorMut.html
+///
+/// # Examples
+///
+/// ```
+/// use ordermap::OrderSet;
+///
+/// let mut set: OrderSet<i32> = (1..=5).collect();
+/// let mut cursor = set.cursor_at_value(&3).unwrap();
+/// cursor.insert_before(30);
+/// cursor.insert_after(300);
+/// assert_eq!(cursor.remove_current(), Some(3));
+/// assert_eq!(cursor.current(), Some(&300));
+/// assert_eq!(cursor.peek_prev(), Some(&30));
+/// assert_eq!(set.as_slice(), &[1, 2, 30, 300, 4, 5]);
+/// ```
+pub struct CursorMut<'a, T, S> {
+    set: &'a mut OrderSet<T, S>,
+    index: usize,
+}
+
+impl<'a, T, S> CursorMut<'a, T, S> {
+    pub(super) fn new(set: &'a mut OrderSet<T, S>, index: usize) -> Self {
+        debug_assert!(index <= set.len());
+        CursorMut { set, index }
+    }
+
+    fn is_ghost(&self) -> bool {
+        self.index == self.set.len()
+    }
+
+    /// Returns the index of the cursor's current value, or `None` if it is
+    /// pointing at the ghost non-element.
+    pub fn index(&self) -> Option<usize> {
+        (!self.is_ghost()).then_some(self.index)
+    }
+
+    /// Moves the cursor to the next value.
+    ///
+    /// From the last value, this moves to the ghost non-element, and from the
+    /// ghost, this moves to the first value of the set.
+    pub fn move_next(&mut self) {
+        if self.is_ghost() {
+            self.index = 0;
+        } else {
+            self.index += 1;
+        }
+    }
+
+    /// Moves the cursor to the previous value.
+    ///
+    /// From the first value, this moves to the ghost non-element, and from the
+    /// ghost, this moves to the last value of the set.
+    pub fn move_prev(&mut self) {
+        self.index = match self.index.checked_sub(1) {
+            Some(index) => index,
+            None => self.set.len(),
+        };
+    }
+
+    /// Returns a reference to the current value, or `None` if the cursor is
+    /// pointing at the ghost non-element.
+    pub fn current(&self) -> Option<&T> {
+        self.set.get_index(self.index)
+    }
+
+    /// Returns a reference to the next value, without moving the cursor.
+    ///
+    /// From the ghost non-element, this peeks at the first value, and from the
+    /// last value, this returns `None`.
+    pub fn peek_next(&self) -> Option<&T> {
+        let next = if self.is_ghost() { 0 } else { self.index + 1 };
+        self.set.get_index(next)
+    }
+
+    /// Returns a reference to the previous value, without moving the cursor.
+    ///
+    /// From the ghost non-element, this peeks at the last value, and from the
+    /// first value, this returns `None`.
+    pub fn peek_prev(&self) -> Option<&T> {
+        self.set.get_index(self.index.checked_sub(1)?)
+    }
+
+    /// Removes the current value and returns it, moving the cursor to the
+    /// next value, or returns `None` if the cursor is pointing at the ghost
+    /// non-element.
+    ///
+    /// Like [`OrderSet::remove_index`], this shifts all of the following
+    /// values.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.set.remove_index(self.index)
+    }
+
+    /// Returns a read-only view of the whole set.
+    pub fn as_set(&self) -> &OrderSet<T, S> {
+        self.set
+    }
+}
+
+impl<T, S> CursorMut<'_, T, S>
+where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Inserts a value just before the current value, or last in the set if
+    /// the cursor is pointing at the ghost non-element. The cursor keeps
+    /// pointing at the same value, or at the ghost.
+    ///
+    /// If an equivalent value already exists in the set, it is moved to that
+    /// position instead, unless it is the current value, and this returns
+    /// `false`. Otherwise this returns `true`.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn insert_before(&mut self, value: T) -> bool {
+        let index = self.index;
+        match self.set.get_index_of(&value) {
+            Some(from) => {
+                if from < index {
+                    self.set.move_index(from, index - 1);
+                } else if from > index {
+                    self.set.move_index(from, index);
+                    self.index += 1;
+                }
+                false
+            }
+            None => {
+                self.set.shift_insert(index, value);
+                self.index += 1;
+                true
+            }
+        }
+    }
+
+    /// Inserts a value just after the current value, or first in the set if
+    /// the cursor is pointing at the ghost non-element. The cursor keeps
+    /// pointing at the same value, or at the ghost.
+    ///
+    /// If an equivalent value already exists in the set, it is moved to that
+    /// position instead, unless it is the current value, and this returns
+    /// `false`. Otherwise this returns `true`.
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn insert_after(&mut self, value: T) -> bool {
+        let ghost = self.is_ghost();
+        let after = if ghost { 0 } else { self.index + 1 };
+        match self.set.get_index_of(&value) {
+            Some(from) => {
+                if from < after {
+                    if from != self.index {
+                        self.set.move_index(from, after - 1);
+                        self.index -= 1;
+                    }
+                } else if from > after {
+                    self.set.move_index(from, after);
+                }
+                false
+            }
+            None => {
+                self.set.shift_insert(after, value);
+                if ghost {
+                    self.index += 1;
+                }
+                true
+            }
+        }
+    }
+}
+
+impl<T: fmt::Debug, S> fmt::Debug for CursorMut<'_, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CursorMut")
+            .field("index", &self.index())
+            .field("current", &self.current())
+            .finish()
+    }
+}
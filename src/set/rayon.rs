@@ -9,6 +9,7 @@ pub use indexmap::set::rayon::{
 };
 
 use super::OrderSet;
+use alloc::vec::Vec;
 use core::cmp::Ordering;
 use core::hash::{BuildHasher, Hash};
 use core::ops::RangeBounds;
@@ -254,6 +255,98 @@ where
     }
 }
 
+/// Parallel sorting methods that report the resulting permutation.
+///
+/// The following methods **require crate feature `"rayon"`**.
+impl<T, S> OrderSet<T, S>
+where
+    T: Sync,
+{
+    /// Returns the order that sorting in parallel with the comparison function
+    /// `cmp` would produce, without modifying the set.
+    ///
+    /// See [`sorted_indices_by`][Self::sorted_indices_by] for the form of the
+    /// result.
+    pub fn par_sorted_indices_by<F>(&self, cmp: F) -> Vec<usize>
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        let values = self.as_slice();
+        let mut indices: Vec<usize> = (0..values.len()).collect();
+        indices.par_sort_by(|&a, &b| cmp(&values[a], &values[b]));
+        indices
+    }
+
+    /// Sort the set's values in place and in parallel, using the comparison function `cmp`,
+    /// and return the permutation that was applied.
+    ///
+    /// See [`sort_by_with_permutation`][Self::sort_by_with_permutation] for the
+    /// form of the permutation.
+    pub fn par_sort_by_with_permutation<F>(&mut self, cmp: F) -> Vec<usize>
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        let indices = self.par_sorted_indices_by(cmp);
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the set's values in place and in parallel, using a key extraction function,
+    /// and return the permutation that was applied.
+    pub fn par_sort_by_key_with_permutation<K, F>(&mut self, sort_key: F) -> Vec<usize>
+    where
+        K: Ord,
+        F: Fn(&T) -> K + Sync,
+    {
+        let values = self.as_slice();
+        let mut indices: Vec<usize> = (0..values.len()).collect();
+        indices.par_sort_by_key(|&i| sort_key(&values[i]));
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the set's values in place and in parallel, using the comparison function `cmp`,
+    /// and return the permutation that was applied.
+    pub fn par_sort_unstable_by_with_permutation<F>(&mut self, cmp: F) -> Vec<usize>
+    where
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        let values = self.as_slice();
+        let mut indices: Vec<usize> = (0..values.len()).collect();
+        indices.par_sort_unstable_by(|&a, &b| cmp(&values[a], &values[b]));
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the set's values in place and in parallel, using a key extraction function,
+    /// and return the permutation that was applied.
+    pub fn par_sort_unstable_by_key_with_permutation<K, F>(&mut self, sort_key: F) -> Vec<usize>
+    where
+        K: Ord,
+        F: Fn(&T) -> K + Sync,
+    {
+        let values = self.as_slice();
+        let mut indices: Vec<usize> = (0..values.len()).collect();
+        indices.par_sort_unstable_by_key(|&i| sort_key(&values[i]));
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+
+    /// Sort the set's values in place and in parallel, using a key extraction function,
+    /// and return the permutation that was applied.
+    pub fn par_sort_by_cached_key_with_permutation<K, F>(&mut self, sort_key: F) -> Vec<usize>
+    where
+        K: Ord + Send,
+        F: Fn(&T) -> K + Sync,
+    {
+        let values = self.as_slice();
+        let mut indices: Vec<usize> = (0..values.len()).collect();
+        indices.par_sort_by_cached_key(|&i| sort_key(&values[i]));
+        self.apply_sorted_indices(&indices);
+        indices
+    }
+}
+
 impl<T, S> FromParallelIterator<T> for OrderSet<T, S>
 where
     T: Eq + Hash + Send,
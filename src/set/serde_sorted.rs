@@ -0,0 +1,102 @@
+//! Functions to serialize an [`OrderSet`] with its values in sorted order.
+//!
+//! This gives a canonical output that only depends on the contents of the
+//! set, not its insertion order, as needed for content hashing or reproducible
+//! output. The set itself is not modified: the values are serialized through
+//! a temporary permutation of their indices, without cloning them.
+//!
+//! Deserialization is the same as the default, so the resulting set keeps the
+//! sorted order of its input.
+//!
+//! This module may be used in a field attribute for derived implementations:
+//!
+//! ```
+//! # use ordermap::OrderSet;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Deserialize, Serialize)]
+//! struct Data {
+//!     #[serde(with = "ordermap::set::serde_sorted")]
+//!     set: OrderSet<i32>,
+//!     // ...
+//! }
+//! ```
+
+use super::OrderSet;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::{BuildHasher, Hash};
+use serde_core::de::{Deserialize, Deserializer};
+use serde_core::ser::{Serialize, Serializer};
+
+/// Serializes an [`OrderSet`] as a sequence with its values in sorted order.
+///
+/// This function may be used in a field attribute for deriving [`Serialize`]:
+///
+/// ```
+/// # use ordermap::OrderSet;
+/// # use serde::Serialize;
+/// #[derive(Serialize)]
+/// struct Data {
+///     #[serde(serialize_with = "ordermap::set::serde_sorted::serialize")]
+///     set: OrderSet<i32>,
+///     // ...
+/// }
+/// ```
+///
+/// Computes in **O(n log n)** time and **O(n)** space.
+pub fn serialize<T, S, Se>(set: &OrderSet<T, S>, serializer: Se) -> Result<Se::Ok, Se::Error>
+where
+    T: Serialize + Ord,
+    Se: Serializer,
+{
+    serialize_by(set, T::cmp, serializer)
+}
+
+/// Serializes an [`OrderSet`] as a sequence with its values sorted by the
+/// comparison function `cmp`.
+///
+/// The sort is stable, so values that compare equal keep their relative order
+/// from the set. This can be used for deriving [`Serialize`] through a small
+/// wrapper function, as shown for [`map::serde_sorted::serialize_by`].
+///
+/// [`map::serde_sorted::serialize_by`]: crate::map::serde_sorted::serialize_by
+///
+/// Computes in **O(n log n)** time and **O(n)** space.
+pub fn serialize_by<T, S, F, Se>(
+    set: &OrderSet<T, S>,
+    mut cmp: F,
+    serializer: Se,
+) -> Result<Se::Ok, Se::Error>
+where
+    T: Serialize,
+    F: FnMut(&T, &T) -> Ordering,
+    Se: Serializer,
+{
+    let values = set.as_slice();
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&i, &j| cmp(&values[i], &values[j]));
+    serializer.collect_seq(order.into_iter().map(|i| &values[i]))
+}
+
+/// Deserializes an [`OrderSet`] from a sequence, the same as its [`Deserialize`] implementation.
+///
+/// This function may be used in a field attribute for deriving [`Deserialize`]:
+///
+/// ```
+/// # use ordermap::OrderSet;
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Data {
+///     #[serde(deserialize_with = "ordermap::set::serde_sorted::deserialize")]
+///     set: OrderSet<i32>,
+///     // ...
+/// }
+/// ```
+pub fn deserialize<'de, D, T, S>(deserializer: D) -> Result<OrderSet<T, S>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Eq + Hash,
+    S: Default + BuildHasher,
+{
+    OrderSet::deserialize(deserializer)
+}
@@ -0,0 +1,105 @@
+//! Functions to serialize and deserialize an [`OrderSet`], rejecting duplicate values.
+//!
+//! The default `serde` implementation quietly accepts input with duplicate
+//! values, where a repeated value keeps its first position. This module fails
+//! deserialization instead, with an error naming the duplicate value and the
+//! positions where it appeared. Serialization is the same as the default.
+//!
+//! This module may be used in a field attribute for derived implementations:
+//!
+//! ```
+//! # use ordermap::OrderSet;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Deserialize, Serialize)]
+//! struct Data {
+//!     #[serde(with = "ordermap::set::serde_strict")]
+//!     set: OrderSet<i32>,
+//!     // ...
+//! }
+//! ```
+
+use crate::OrderSet;
+use crate::util::cautious_capacity;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::marker::PhantomData;
+use serde_core::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use serde_core::ser::{Serialize, Serializer};
+
+/// Serializes an [`OrderSet`] as a sequence, the same as its [`Serialize`] implementation.
+///
+/// This function may be used in a field attribute for deriving [`Serialize`]:
+///
+/// ```
+/// # use ordermap::OrderSet;
+/// # use serde::Serialize;
+/// #[derive(Serialize)]
+/// struct Data {
+///     #[serde(serialize_with = "ordermap::set::serde_strict::serialize")]
+///     set: OrderSet<i32>,
+///     // ...
+/// }
+/// ```
+pub fn serialize<T, S, Se>(set: &OrderSet<T, S>, serializer: Se) -> Result<Se::Ok, Se::Error>
+where
+    T: Serialize,
+    Se: Serializer,
+{
+    set.serialize(serializer)
+}
+
+/// Deserializes an [`OrderSet`] from a sequence, failing if any value is repeated.
+///
+/// This function may be used in a field attribute for deriving [`Deserialize`]:
+///
+/// ```
+/// # use ordermap::OrderSet;
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Data {
+///     #[serde(deserialize_with = "ordermap::set::serde_strict::deserialize")]
+///     set: OrderSet<i32>,
+///     // ...
+/// }
+/// ```
+pub fn deserialize<'de, D, T, S>(deserializer: D) -> Result<OrderSet<T, S>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Eq + Hash + fmt::Debug,
+    S: Default + BuildHasher,
+{
+    deserializer.deserialize_seq(StrictVisitor(PhantomData))
+}
+
+struct StrictVisitor<T, S>(PhantomData<(T, S)>);
+
+impl<'de, T, S> Visitor<'de> for StrictVisitor<T, S>
+where
+    T: Deserialize<'de> + Eq + Hash + fmt::Debug,
+    S: Default + BuildHasher,
+{
+    type Value = OrderSet<T, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a sequence without duplicate values")
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let capacity = cautious_capacity::<T, ()>(access.size_hint());
+        let mut set = OrderSet::with_capacity_and_hasher(capacity, S::default());
+        while let Some(value) = access.next_element()? {
+            let position = set.len();
+            let (index, inserted) = set.insert_full(value);
+            if !inserted {
+                let value = &set[index];
+                return Err(A::Error::custom(format_args!(
+                    "duplicate value {value:?} at positions {index} and {position}"
+                )));
+            }
+        }
+        Ok(set)
+    }
+}
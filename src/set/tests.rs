@@ -1065,3 +1065,308 @@ fn is_sorted_trivial() {
     set.reverse();
     expect(&set, [false, true, false, true, false]);
 }
+
+#[test]
+fn try_positional_oob() {
+    let mut set: OrderSet<i32> = (0..5).collect();
+    let copy = set.clone();
+    fn oob<T>(index: usize, len: usize) -> Result<T, IndexOutOfBounds> {
+        Err(IndexOutOfBounds { index, len })
+    }
+
+    fn rejected<T, I>(index: usize, len: usize, item: I) -> Result<T, InsertOutOfBounds<I>> {
+        let error = IndexOutOfBounds { index, len };
+        Err(InsertOutOfBounds { error, item })
+    }
+
+    assert_eq!(set.try_insert_before(6, 9), rejected(6, 5, 9));
+    assert_eq!(set.try_shift_insert(6, 9), rejected(6, 5, 9));
+    assert_eq!(set.try_shift_insert(5, 0), rejected(5, 5, 0));
+    assert_eq!(set.try_move_index(5, 0), oob(5, 5));
+    assert_eq!(set.try_swap_indices(7, 0), oob(7, 5));
+    assert_eq!(
+        set.try_split_off(6).unwrap_err(),
+        IndexOutOfBounds { index: 6, len: 5 }
+    );
+    assert_eq!(
+        set.try_drain(..7).unwrap_err(),
+        IndexOutOfBounds { index: 7, len: 5 }
+    );
+    assert_eq!(
+        set.try_splice(
+            (core::ops::Bound::Included(4), core::ops::Bound::Excluded(3)),
+            []
+        )
+        .unwrap_err(),
+        IndexOutOfBounds { index: 4, len: 3 }
+    );
+    assert_eq!(set, copy);
+
+    assert_eq!(set.try_insert_before(5, 9), Ok((5, true)));
+    assert_eq!(set.try_shift_insert(0, 9), Ok(false));
+    assert_eq!(set.try_move_index(0, 5), Ok(()));
+    assert_eq!(set.try_swap_indices(0, 1), Ok(()));
+    assert_eq!(set.try_drain(4..).unwrap().collect::<Vec<_>>(), [4, 9]);
+    assert_eq!(set.try_split_off(4).unwrap().len(), 0);
+    assert_eq!(set.as_slice(), &[1, 0, 2, 3]);
+}
+
+#[test]
+fn relative_positioning() {
+    let mut set: OrderSet<i32> = (0..6).collect();
+
+    for value in 0..6 {
+        for anchor in 0..6 {
+            let mut before = set.clone();
+            let index = before.move_before(&value, &anchor).unwrap();
+            assert_eq!(before.get_index_of(&value), Some(index));
+            if value != anchor {
+                assert_eq!(before.get_index_of(&anchor), Some(index + 1));
+            }
+
+            let mut after = set.clone();
+            let index = after.move_after(&value, &anchor).unwrap();
+            assert_eq!(after.get_index_of(&value), Some(index));
+            if value != anchor {
+                assert_eq!(after.get_index_of(&anchor), Some(index - 1));
+            }
+        }
+    }
+
+    assert_eq!(set.swap_values(&0, &5), Ok(()));
+    assert_eq!(
+        set.swap_values(&9, &5),
+        Err(RelativePositionError::NotFound)
+    );
+    assert_eq!(
+        set.move_before(&0, &9),
+        Err(RelativePositionError::AnchorNotFound)
+    );
+    let err = set.insert_before_value(&9, 7).unwrap_err();
+    assert_eq!(err.item, 7);
+    assert_eq!(
+        RelativePositionError::from(err),
+        RelativePositionError::AnchorNotFound
+    );
+    assert_eq!(set.as_slice(), &[5, 1, 2, 3, 4, 0]);
+}
+
+#[test]
+fn apply_permutation() {
+    let mut set: OrderSet<i32> = (0..5).collect();
+    set.apply_permutation(&[3, 0, 4, 1, 2]).unwrap();
+    assert_eq!(set.as_slice(), &[3, 0, 4, 1, 2]);
+    for (i, value) in set.iter().enumerate() {
+        assert_eq!(set.get_index_of(value), Some(i));
+    }
+
+    assert_eq!(
+        set.apply_permutation(&[0, 1, 2, 2, 4]),
+        Err(PermutationError::DuplicateIndex { index: 2 })
+    );
+    assert_eq!(set.as_slice(), &[3, 0, 4, 1, 2]);
+}
+
+#[test]
+fn rotate_and_move_range() {
+    let mut set: OrderSet<i32> = (0..6).collect();
+    set.rotate_left(4);
+    assert_eq!(set.as_slice(), &[4, 5, 0, 1, 2, 3]);
+    set.rotate_right(4);
+    assert_eq!(set.as_slice(), &[0, 1, 2, 3, 4, 5]);
+
+    set.move_range(..2, 4);
+    assert_eq!(set.as_slice(), &[2, 3, 4, 5, 0, 1]);
+    set.move_range(2..=3, 0);
+    assert_eq!(set.as_slice(), &[4, 5, 2, 3, 0, 1]);
+    for (i, value) in set.iter().enumerate() {
+        assert_eq!(set.get_index_of(value), Some(i));
+    }
+}
+
+#[test]
+fn sort_with_permutation() {
+    let original = OrderSet::from(["pear", "fig", "apple", "kiwi", "date"]);
+
+    let mut set = original.clone();
+    let indices = set.sort_by_key_with_permutation(|s| s.len());
+    assert_eq!(indices, [1, 0, 3, 4, 2]);
+    assert_eq!(set.as_slice(), &["fig", "pear", "kiwi", "date", "apple"]);
+    for (i, &value) in set.iter().enumerate() {
+        assert_eq!(original[indices[i]], value);
+        assert_eq!(set.get_index_of(value), Some(i));
+    }
+
+    let mut set = original.clone();
+    let indices = set.sort_unstable_by_with_permutation(|a, b| a.cmp(b));
+    assert_eq!(indices, [2, 4, 1, 3, 0]);
+    assert!(set.iter().is_sorted());
+}
+
+#[test]
+fn sort_by_reference() {
+    let original = OrderSet::from(["d", "a", "c", "b"]);
+    let reference = OrderSet::from(["a", "b", "c", "d"]);
+
+    let mut set = original.clone();
+    set.sort_by_reference(&reference, ReorderPolicy::AbsentLast);
+    assert_eq!(set.as_slice(), &["a", "b", "c", "d"]);
+    for (i, value) in set.iter().enumerate() {
+        assert_eq!(set.get_index_of(value), Some(i));
+    }
+
+    let map = OrderMap::from([("b", 1), ("z", 2), ("d", 3)]);
+    let mut set = original.clone();
+    set.reorder_like(&map, ReorderPolicy::AbsentInPlace);
+    assert_eq!(set.as_slice(), &["b", "a", "c", "d"]);
+    set.reorder_like(&map, ReorderPolicy::AbsentFirst);
+    assert_eq!(set.as_slice(), &["a", "c", "b", "d"]);
+}
+
+#[test]
+fn sort_topologically() {
+    let deps: OrderMap<&str, &[&str]> = OrderMap::from([
+        ("serde", &["serde_core"][..]),
+        ("indexmap", &["hashbrown", "equivalent"]),
+        ("ordermap", &["indexmap", "serde"]),
+    ]);
+    let mut set = OrderSet::from([
+        "ordermap",
+        "equivalent",
+        "indexmap",
+        "serde",
+        "hashbrown",
+        "serde_core",
+    ]);
+
+    let order = set
+        .topological_order(|name| deps.get(name).copied().unwrap_or_default())
+        .unwrap();
+    set.apply_permutation(&order).unwrap();
+    assert_eq!(
+        set.as_slice(),
+        &[
+            "equivalent",
+            "hashbrown",
+            "indexmap",
+            "serde_core",
+            "serde",
+            "ordermap"
+        ]
+    );
+
+    // Close a cycle through the existing `ordermap -> indexmap` dependency.
+    let before = set.clone();
+    let err = set
+        .sort_topologically(|name| {
+            let mut name_deps = deps.get(name).copied().unwrap_or_default().to_vec();
+            if *name == "indexmap" {
+                name_deps.push("ordermap");
+            }
+            name_deps
+        })
+        .unwrap_err();
+    assert_eq!(err.cycle, [&"indexmap", &"ordermap"]);
+    assert_eq!(
+        std::format!("{err}"),
+        r#"dependency cycle between ["indexmap", "ordermap"]"#
+    );
+    assert_eq!(set, before);
+}
+
+#[test]
+fn remove_indices() {
+    let mut set: OrderSet<i32> = (0..8).collect();
+    assert_eq!(set.remove_indices([6, 0, 3]), Ok(std::vec![0, 3, 6]));
+    assert_eq!(set.as_slice(), &[1, 2, 4, 5, 7]);
+    for (i, value) in set.iter().enumerate() {
+        assert_eq!(set.get_index_of(value), Some(i));
+    }
+
+    assert_eq!(
+        set.remove_indices([2, 2]),
+        Err(RemoveIndicesError::DuplicateIndex { index: 2 })
+    );
+    assert_eq!(
+        set.remove_indices([5]),
+        Err(RemoveIndicesError::IndexOutOfBounds { index: 5, len: 5 })
+    );
+    assert_eq!(set.len(), 5);
+
+    assert_eq!(set.remove_values(&[7, 1, 3]), [1, 7]);
+    assert_eq!(set.as_slice(), &[2, 4, 5]);
+    assert_eq!(set.get_index_of(&5), Some(2));
+}
+
+#[test]
+fn cursor_mut() {
+    let mut set: OrderSet<i32> = (0..4).collect();
+    let mut cursor = set.cursor_at(2);
+    assert_eq!(cursor.peek_next(), Some(&3));
+    assert_eq!(cursor.peek_prev(), Some(&1));
+    assert!(!cursor.insert_before(3));
+    assert!(!cursor.insert_after(0));
+    assert!(!cursor.insert_before(2));
+    assert!(cursor.insert_after(5));
+    assert_eq!(cursor.current(), Some(&2));
+    assert_eq!(cursor.as_set().as_slice(), &[1, 3, 2, 5, 0]);
+
+    cursor.move_next();
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.current(), None);
+    assert!(cursor.insert_after(-1));
+    assert!(cursor.insert_before(9));
+    cursor.move_next();
+    assert_eq!(cursor.remove_current(), Some(-1));
+    assert_eq!(set.as_slice(), &[1, 3, 2, 5, 0, 9]);
+}
+
+#[test]
+fn range_between_values() {
+    let set: OrderSet<i32> = (0..10).rev().collect();
+    assert_eq!(set.range_between(&7, &4).unwrap(), &[7, 6, 5]);
+    assert_eq!(set.range_between_inclusive(&7, &4).unwrap(), &[7, 6, 5, 4]);
+    assert_eq!(set.range_between(&4, &7), None);
+    assert_eq!(set.range_between(&4, &10), None);
+    assert_eq!(set.range_from_value(&1).unwrap(), &[1, 0]);
+    assert_eq!(set.range_to_value(&8).unwrap(), &[9]);
+}
+
+#[test]
+fn order_predicates() {
+    let set: OrderSet<i32> = (1..=5).collect();
+    let empty = OrderSet::<i32>::new();
+
+    assert!(set.starts_with(&OrderSet::from([1, 2])) && set.starts_with(&empty));
+    assert!(!set.starts_with(&OrderSet::from([1, 3])));
+    assert!(set.ends_with(&OrderSet::from([4, 5])) && !empty.ends_with(&set));
+    assert!(OrderSet::from([1, 2, 3]).is_prefix_of(&set));
+    assert!(!OrderSet::from([1, 2, 6]).is_prefix_of(&set));
+    assert!(OrderSet::from([2, 3, 5]).is_subsequence_of(&set));
+    assert!(!OrderSet::from([2, 5, 3]).is_subsequence_of(&set));
+    assert!(!OrderSet::from([2, 6]).is_subsequence_of(&set));
+    assert!(OrderSet::from([5, 3, 1, 2, 4]).is_permutation_of(&set));
+    assert!(!OrderSet::from([5, 3, 1, 2]).is_permutation_of(&set));
+    assert_eq!(set.common_prefix_len(&OrderSet::from([1, 2, 4])), 2);
+    assert_eq!(set.common_prefix_len(&set), 5);
+}
+
+#[test]
+fn partition_and_chunks() {
+    let set: OrderSet<i32> = (0..10).collect();
+    let (even, odd) = set.clone().partition(|v| v % 2 == 0);
+    assert_eq!(even.as_slice(), &[0, 2, 4, 6, 8]);
+    assert_eq!(odd.as_slice(), &[1, 3, 5, 7, 9]);
+    assert_eq!(odd.get_index_of(&7), Some(3));
+
+    let mut head = set.clone();
+    let tail = head.split_by_value(&3).unwrap();
+    assert_eq!(head.as_slice(), &[0, 1, 2]);
+    assert_eq!(tail.len(), 7);
+
+    let chunks = set.chunk_into(4);
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[2].as_slice(), &[8, 9]);
+    assert!(chunks.iter().all(|chunk| chunk.capacity() <= 4));
+}
@@ -0,0 +1,278 @@
+use crate::{IndexOutOfBounds, PermutationError, RemoveIndicesError, ReorderPolicy};
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::ops::{Bound, Range, RangeBounds};
+
+#[inline]
+pub(crate) fn check_index_lt(index: usize, len: usize) -> Result<(), IndexOutOfBounds> {
+    if index < len {
+        Ok(())
+    } else {
+        Err(IndexOutOfBounds { index, len })
+    }
+}
+
+#[inline]
+pub(crate) fn check_index_le(index: usize, len: usize) -> Result<(), IndexOutOfBounds> {
+    if index <= len {
+        Ok(())
+    } else {
+        Err(IndexOutOfBounds { index, len })
+    }
+}
+
+#[track_caller]
+pub(crate) fn simplify_range<R>(range: R, len: usize) -> Range<usize>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Unbounded => 0,
+        Bound::Included(&i) if i <= len => i,
+        Bound::Excluded(&i) if i < len => i + 1,
+        Bound::Included(i) | Bound::Excluded(i) => {
+            panic!("range start index {i} out of range for slice of length {len}")
+        }
+    };
+    let end = match range.end_bound() {
+        Bound::Unbounded => len,
+        Bound::Excluded(&i) if i <= len => i,
+        Bound::Included(&i) if i < len => i + 1,
+        Bound::Included(i) | Bound::Excluded(i) => {
+            panic!("range end index {i} out of range for slice of length {len}")
+        }
+    };
+    if start > end {
+        panic!(
+            "range start index {:?} should be <= range end index {:?}",
+            range.start_bound(),
+            range.end_bound()
+        );
+    }
+    start..end
+}
+
+/// Like [`simplify_range`], but reporting the offending bound instead of panicking.
+///
+/// A start that is greater than the end is reported with the end as its `len`.
+pub(crate) fn try_simplify_range<R>(range: R, len: usize) -> Result<Range<usize>, IndexOutOfBounds>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Unbounded => 0,
+        Bound::Included(&i) if i <= len => i,
+        Bound::Excluded(&i) if i < len => i + 1,
+        Bound::Included(&index) | Bound::Excluded(&index) => {
+            return Err(IndexOutOfBounds { index, len });
+        }
+    };
+    let end = match range.end_bound() {
+        Bound::Unbounded => len,
+        Bound::Excluded(&i) if i <= len => i,
+        Bound::Included(&i) if i < len => i + 1,
+        Bound::Included(&index) | Bound::Excluded(&index) => {
+            return Err(IndexOutOfBounds { index, len });
+        }
+    };
+    if start > end {
+        return Err(IndexOutOfBounds {
+            index: start,
+            len: end,
+        });
+    }
+    Ok(start..end)
+}
+
+/// Limit our preallocated capacity from a deserializer `size_hint()`,
+/// or from a length prefix that hasn't been checked against the input yet.
+///
+/// This is based on the internal `serde::de::size_hint::cautious(hint)` function.
+#[cfg(any(feature = "serde", feature = "borsh", feature = "bincode"))]
+pub(crate) fn cautious_capacity<K, V>(hint: Option<usize>) -> usize {
+    const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+
+    Ord::min(
+        hint.unwrap_or(0),
+        MAX_PREALLOC_BYTES / Ord::max(size_of::<(K, V)>(), 1),
+    )
+}
+
+/// Validates that `perm` is a permutation of `0..len`, and returns its inverse:
+/// the new index of the item at each old index, where the item at the old
+/// index `perm[i]` goes to the new index `i`.
+pub(crate) fn inverse_permutation(
+    perm: &[usize],
+    len: usize,
+) -> Result<Vec<usize>, PermutationError> {
+    if perm.len() != len {
+        return Err(PermutationError::LengthMismatch {
+            len,
+            perm_len: perm.len(),
+        });
+    }
+
+    // Every slot is unset until its item's new index is known.
+    const UNSET: usize = usize::MAX;
+    let mut inverse = vec![UNSET; len];
+    for (new_index, &index) in perm.iter().enumerate() {
+        match inverse.get_mut(index) {
+            None => return Err(PermutationError::IndexOutOfBounds { index, len }),
+            Some(slot) if *slot != UNSET => return Err(PermutationError::DuplicateIndex { index }),
+            Some(slot) => *slot = new_index,
+        }
+    }
+    Ok(inverse)
+}
+
+/// Moves the item at each old index `i` to the new index `inverse[i]` using
+/// `swap`, which is called at most once per item.
+pub(crate) fn permute_by_swaps<F>(mut inverse: Vec<usize>, mut swap: F)
+where
+    F: FnMut(usize, usize),
+{
+    for i in 0..inverse.len() {
+        // Each swap moves the item at `i` to its final place `j`, and the item
+        // brought back to `i` takes its new index along with it.
+        while inverse[i] != i {
+            let j = inverse[i];
+            swap(i, j);
+            inverse.swap(i, j);
+        }
+    }
+}
+
+/// Validates that `indices` are distinct and less than `len`, and marks them
+/// for removal.
+pub(crate) fn removal_mask<I>(len: usize, indices: I) -> Result<Vec<bool>, RemoveIndicesError>
+where
+    I: IntoIterator<Item = usize>,
+{
+    let mut remove = vec![false; len];
+    for index in indices {
+        match remove.get_mut(index) {
+            None => return Err(RemoveIndicesError::IndexOutOfBounds { index, len }),
+            Some(true) => return Err(RemoveIndicesError::DuplicateIndex { index }),
+            Some(marked) => *marked = true,
+        }
+    }
+    Ok(remove)
+}
+
+/// Checks that the items in `range` can be moved to start at `to` in a length of `len`.
+#[track_caller]
+pub(crate) fn check_move_range(range: &Range<usize>, to: usize, len: usize) {
+    let count = range.end - range.start;
+    assert!(
+        to <= len - count,
+        "destination index {to} out of range for moving {count} items in a length of {len}"
+    );
+}
+
+/// Returns the new index of the item at `index` after the items in `range`
+/// are moved to start at `to`, shifting the items in-between.
+pub(crate) fn moved_index(index: usize, range: &Range<usize>, to: usize) -> usize {
+    let count = range.end - range.start;
+    if range.contains(&index) {
+        index - range.start + to
+    } else if to < range.start && (to..range.start).contains(&index) {
+        index + count
+    } else if to > range.start && (range.end..to + count).contains(&index) {
+        index - count
+    } else {
+        index
+    }
+}
+
+/// Builds the permutation for reordering `len` items to follow a reference,
+/// given the indices of the found items in reference order.
+///
+/// The result is in the form taken by [`inverse_permutation`]. Repeated indices
+/// in `found` are ignored after their first occurrence.
+pub(crate) fn reference_permutation<I>(len: usize, found: I, policy: ReorderPolicy) -> Vec<usize>
+where
+    I: IntoIterator<Item = usize>,
+{
+    let mut is_found = vec![false; len];
+    let found: Vec<usize> = found
+        .into_iter()
+        .filter(|&i| !core::mem::replace(&mut is_found[i], true))
+        .collect();
+    let absent = (0..len).filter(|&i| !is_found[i]);
+
+    let mut perm = Vec::with_capacity(len);
+    match policy {
+        ReorderPolicy::AbsentFirst => {
+            perm.extend(absent);
+            perm.extend(found);
+        }
+        ReorderPolicy::AbsentLast => {
+            perm.extend(found);
+            perm.extend(absent);
+        }
+        ReorderPolicy::AbsentInPlace => {
+            let mut found = found.into_iter();
+            perm.extend((0..len).map(|i| {
+                if is_found[i] {
+                    found.next().expect("each found index fills one slot")
+                } else {
+                    i
+                }
+            }));
+        }
+    }
+    perm
+}
+
+/// Orders the indices `0..deps.len()` so that every index comes after those it
+/// depends on, where `deps[i]` lists the dependencies of index `i`.
+///
+/// Among the indices that are ready at any point, the lowest comes first, so
+/// the existing order is kept wherever the dependencies allow it. The result
+/// is in the form taken by [`inverse_permutation`].
+///
+/// On failure, returns the indices of one cycle, each depending on the next.
+pub(crate) fn topological_order(deps: &[Vec<usize>]) -> Result<Vec<usize>, Vec<usize>> {
+    let len = deps.len();
+    let mut waiting: Vec<usize> = deps.iter().map(Vec::len).collect();
+    let mut dependents = vec![Vec::new(); len];
+    for (index, index_deps) in deps.iter().enumerate() {
+        for &dep in index_deps {
+            dependents[dep].push(index);
+        }
+    }
+
+    let mut ready: BinaryHeap<Reverse<usize>> =
+        (0..len).filter(|&i| waiting[i] == 0).map(Reverse).collect();
+    let mut order = Vec::with_capacity(len);
+    while let Some(Reverse(index)) = ready.pop() {
+        order.push(index);
+        for &dependent in &dependents[index] {
+            waiting[dependent] -= 1;
+            if waiting[dependent] == 0 {
+                ready.push(Reverse(dependent));
+            }
+        }
+    }
+    if order.len() == len {
+        return Ok(order);
+    }
+
+    // Every index left waiting has a dependency that is also waiting, so
+    // following those from any of them must eventually revisit an index.
+    let mut path_pos = vec![usize::MAX; len];
+    let mut path = Vec::new();
+    let mut index = (0..len).find(|&i| waiting[i] > 0).unwrap();
+    while path_pos[index] == usize::MAX {
+        path_pos[index] = path.len();
+        path.push(index);
+        index = *deps[index]
+            .iter()
+            .find(|&&dep| waiting[dep] > 0)
+            .expect("a waiting index has a waiting dependency");
+    }
+    path.drain(..path_pos[index]);
+    Err(path)
+}
@@ -0,0 +1,418 @@
+//! A dynamically typed, order-preserving [`Value`] for self-describing data.
+//!
+//! [`Value`] can represent any JSON-like document: null, booleans, numbers,
+//! strings, arrays, and objects. Objects are [`OrderMap`]s, so their fields
+//! keep the order in which they were deserialized, inserted, or serialized.
+//!
+//! Values can be built with the [`value!`][crate::value!] macro, converted
+//! from any `T: Serialize` with [`to_value`], and (de)serialized with any
+//! `serde` format.
+//!
+//! # Examples
+//!
+//! ```
+//! use ordermap::value;
+//! use ordermap::value::Value;
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize)]
+//! struct Server {
+//!     name: &'static str,
+//!     ports: [u16; 2],
+//! }
+//!
+//! let server: Value = value::to_value(&Server { name: "web", ports: [80, 443] }).unwrap();
+//! assert_eq!(server, value!({ "name": "web", "ports": [80, 443] }));
+//! assert_eq!(server.get_path("/ports/1"), Some(&Value::from(443)));
+//!
+//! // Objects keep their field order.
+//! let object = server.as_object().unwrap();
+//! assert!(object.keys().eq(["name", "ports"]));
+//! ```
+
+mod de;
+mod number;
+mod ser;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::number::Number;
+pub use self::ser::{Error, SerializeArray, SerializeObject, Serializer, to_value};
+
+use crate::OrderMap;
+use alloc::borrow::{Cow, ToOwned};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::BuildHasher;
+
+#[cfg(feature = "std")]
+use std::hash::RandomState;
+
+/// The object representation of a [`Value`]: an [`OrderMap`] from string keys
+/// to values.
+#[cfg(feature = "std")]
+pub type Map<S = RandomState> = OrderMap<String, Value<S>, S>;
+/// The object representation of a [`Value`]: an [`OrderMap`] from string keys
+/// to values.
+#[cfg(not(feature = "std"))]
+pub type Map<S> = OrderMap<String, Value<S>, S>;
+
+/// A dynamically typed value, with objects that keep their field order.
+///
+/// The hasher `S` is used for the [`Map`] of every nested object. It
+/// defaults to [`RandomState`] with the `std` feature, and must be chosen
+/// explicitly without it.
+///
+/// [`RandomState`]: std::hash::RandomState
+#[cfg(feature = "std")]
+pub enum Value<S = RandomState> {
+    /// The null value.
+    Null,
+    /// A boolean.
+    Bool(bool),
+    /// An integer or floating-point number.
+    Number(Number),
+    /// A string.
+    String(String),
+    /// An array of values.
+    Array(Vec<Value<S>>),
+    /// An object with string keys, in order.
+    Object(Map<S>),
+}
+/// A dynamically typed value, with objects that keep their field order.
+///
+/// The hasher `S` is used for the [`Map`] of every nested object. It
+/// defaults to [`RandomState`] with the `std` feature, and must be chosen
+/// explicitly without it.
+///
+/// [`RandomState`]: https://doc.rust-lang.org/std/hash/struct.RandomState.html
+#[cfg(not(feature = "std"))]
+pub enum Value<S> {
+    /// The null value.
+    Null,
+    /// A boolean.
+    Bool(bool),
+    /// An integer or floating-point number.
+    Number(Number),
+    /// A string.
+    String(String),
+    /// An array of values.
+    Array(Vec<Value<S>>),
+    /// An object with string keys, in order.
+    Object(Map<S>),
+}
+
+impl<S> Value<S> {
+    /// Returns `true` if the value is `Null`.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+
+    /// Returns the boolean if the value is a `Bool`, else `None`.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            Value::Bool(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// Returns the number if the value is a `Number`, else `None`.
+    pub fn as_number(&self) -> Option<&Number> {
+        match self {
+            Value::Number(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Returns the number as an `i64` if the value is a `Number` that fits,
+    /// else `None`.
+    pub fn as_i64(&self) -> Option<i64> {
+        self.as_number()?.as_i64()
+    }
+
+    /// Returns the number as a `u64` if the value is a `Number` that fits,
+    /// else `None`.
+    pub fn as_u64(&self) -> Option<u64> {
+        self.as_number()?.as_u64()
+    }
+
+    /// Returns the number as an `f64` if the value is a `Number`, else `None`.
+    pub fn as_f64(&self) -> Option<f64> {
+        Some(self.as_number()?.as_f64())
+    }
+
+    /// Returns the string if the value is a `String`, else `None`.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements if the value is an `Array`, else `None`.
+    pub fn as_array(&self) -> Option<&Vec<Value<S>>> {
+        match self {
+            Value::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements mutably if the value is an `Array`, else `None`.
+    pub fn as_array_mut(&mut self) -> Option<&mut Vec<Value<S>>> {
+        match self {
+            Value::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    /// Returns the fields if the value is an `Object`, else `None`.
+    pub fn as_object(&self) -> Option<&Map<S>> {
+        match self {
+            Value::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// Returns the fields mutably if the value is an `Object`, else `None`.
+    pub fn as_object_mut(&mut self) -> Option<&mut Map<S>> {
+        match self {
+            Value::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// Takes the value out, leaving `Null` in its place.
+    pub fn take(&mut self) -> Self {
+        core::mem::replace(self, Value::Null)
+    }
+}
+
+impl<S: BuildHasher> Value<S> {
+    /// Looks up a nested value by a JSON Pointer, as in [RFC 6901].
+    ///
+    /// The `path` is either empty, for the value itself, or a sequence of
+    /// `/`-prefixed tokens, each of which selects an object field by key or an
+    /// array element by index. Within a token, `~1` stands for `/` and `~0`
+    /// for `~`. Returns `None` if the path is malformed or doesn't exist.
+    ///
+    /// Each object lookup computes in **O(1)** time (average).
+    ///
+    /// [RFC 6901]: https://www.rfc-editor.org/rfc/rfc6901
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ordermap::value::Value;
+    /// use ordermap::value;
+    ///
+    /// let doc: Value = value!({ "a/b": [10, { "~c": true }] });
+    /// assert_eq!(doc.get_path("/a~1b/0"), Some(&Value::from(10)));
+    /// assert_eq!(doc.get_path("/a~1b/1/~0c"), Some(&Value::Bool(true)));
+    /// assert_eq!(doc.get_path(""), Some(&doc));
+    /// assert_eq!(doc.get_path("/a~1b/2"), None);
+    /// assert_eq!(doc.get_path("a~1b"), None);
+    /// ```
+    pub fn get_path(&self, path: &str) -> Option<&Value<S>> {
+        let mut target = self;
+        for token in path_tokens(path)? {
+            target = match target {
+                Value::Object(object) => object.get(&*token?)?,
+                Value::Array(array) => array.get(parse_index(&token?)?)?,
+                _ => return None,
+            };
+        }
+        Some(target)
+    }
+
+    /// Looks up a nested value mutably by a JSON Pointer, like
+    /// [`get_path`][Self::get_path].
+    pub fn get_path_mut(&mut self, path: &str) -> Option<&mut Value<S>> {
+        let mut target = self;
+        for token in path_tokens(path)? {
+            target = match target {
+                Value::Object(object) => object.get_mut(&*token?)?,
+                Value::Array(array) => array.get_mut(parse_index(&token?)?)?,
+                _ => return None,
+            };
+        }
+        Some(target)
+    }
+}
+
+/// Splits a JSON Pointer into its unescaped reference tokens, or returns
+/// `None` if it doesn't start with `/`. Each token is `None` if it has an
+/// invalid escape.
+fn path_tokens(path: &str) -> Option<impl Iterator<Item = Option<Cow<'_, str>>>> {
+    let mut tokens = path.split('/');
+    if !tokens.next()?.is_empty() {
+        return None;
+    }
+    Some(tokens.map(|token| {
+        if !token.contains('~') {
+            return Some(Cow::Borrowed(token));
+        }
+        let mut unescaped = String::with_capacity(token.len());
+        let mut chars = token.chars();
+        while let Some(c) = chars.next() {
+            unescaped.push(match c {
+                '~' => match chars.next()? {
+                    '0' => '~',
+                    '1' => '/',
+                    _ => return None,
+                },
+                c => c,
+            });
+        }
+        Some(Cow::Owned(unescaped))
+    }))
+}
+
+/// Parses an array index token, which must be a decimal number without
+/// leading zeros.
+fn parse_index(token: &str) -> Option<usize> {
+    if token.starts_with('+') || (token.starts_with('0') && token.len() > 1) {
+        return None;
+    }
+    token.parse().ok()
+}
+
+impl<S: Clone> Clone for Value<S> {
+    fn clone(&self) -> Self {
+        match self {
+            Value::Null => Value::Null,
+            Value::Bool(b) => Value::Bool(*b),
+            Value::Number(n) => Value::Number(*n),
+            Value::String(s) => Value::String(s.clone()),
+            Value::Array(array) => Value::Array(array.clone()),
+            Value::Object(object) => Value::Object(object.clone()),
+        }
+    }
+}
+
+impl<S> fmt::Debug for Value<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => f.write_str("Null"),
+            Value::Bool(b) => f.debug_tuple("Bool").field(b).finish(),
+            Value::Number(n) => f.debug_tuple("Number").field(n).finish(),
+            Value::String(s) => f.debug_tuple("String").field(s).finish(),
+            Value::Array(array) => f.debug_tuple("Array").field(array).finish(),
+            Value::Object(object) => f.debug_tuple("Object").field(object).finish(),
+        }
+    }
+}
+
+impl<S> Default for Value<S> {
+    /// Returns `Null`.
+    fn default() -> Self {
+        Value::Null
+    }
+}
+
+impl<S> PartialEq for Value<S> {
+    /// Compares values structurally, including the order of object fields.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Null, Value::Null) => true,
+            (Value::Bool(a), Value::Bool(b)) => a == b,
+            (Value::Number(a), Value::Number(b)) => a == b,
+            (Value::String(a), Value::String(b)) => a == b,
+            (Value::Array(a), Value::Array(b)) => a == b,
+            (Value::Object(a), Value::Object(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<S> Eq for Value<S> {}
+
+impl<S> From<bool> for Value<S> {
+    fn from(b: bool) -> Self {
+        Value::Bool(b)
+    }
+}
+
+impl<S> From<Number> for Value<S> {
+    fn from(n: Number) -> Self {
+        Value::Number(n)
+    }
+}
+
+macro_rules! from_integer {
+    ($($ty:ty)*) => {$(
+        impl<S> From<$ty> for Value<S> {
+            fn from(n: $ty) -> Self {
+                Value::Number(n.into())
+            }
+        }
+    )*};
+}
+
+from_integer! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize }
+
+impl<S> From<f32> for Value<S> {
+    /// Converts a finite float to a `Number`, or a NaN or infinite float to `Null`.
+    fn from(f: f32) -> Self {
+        Self::from(f64::from(f))
+    }
+}
+
+impl<S> From<f64> for Value<S> {
+    /// Converts a finite float to a `Number`, or a NaN or infinite float to `Null`.
+    fn from(f: f64) -> Self {
+        Number::from_f64(f).map_or(Value::Null, Value::Number)
+    }
+}
+
+impl<S> From<&str> for Value<S> {
+    fn from(s: &str) -> Self {
+        Value::String(s.to_owned())
+    }
+}
+
+impl<S> From<String> for Value<S> {
+    fn from(s: String) -> Self {
+        Value::String(s)
+    }
+}
+
+impl<S> From<()> for Value<S> {
+    fn from((): ()) -> Self {
+        Value::Null
+    }
+}
+
+impl<S, T: Into<Value<S>>> From<Option<T>> for Value<S> {
+    /// Converts `None` to `Null`, and `Some(value)` to the converted `value`.
+    fn from(option: Option<T>) -> Self {
+        option.map_or(Value::Null, Into::into)
+    }
+}
+
+impl<S> From<Vec<Value<S>>> for Value<S> {
+    fn from(array: Vec<Value<S>>) -> Self {
+        Value::Array(array)
+    }
+}
+
+impl<S> From<Map<S>> for Value<S> {
+    fn from(object: Map<S>) -> Self {
+        Value::Object(object)
+    }
+}
+
+impl<S> FromIterator<Value<S>> for Value<S> {
+    /// Collects an `Array`.
+    fn from_iter<I: IntoIterator<Item = Value<S>>>(iter: I) -> Self {
+        Value::Array(iter.into_iter().collect())
+    }
+}
+
+impl<S: BuildHasher + Default> FromIterator<(String, Value<S>)> for Value<S> {
+    /// Collects an `Object`, in order.
+    fn from_iter<I: IntoIterator<Item = (String, Value<S>)>>(iter: I) -> Self {
+        Value::Object(iter.into_iter().collect())
+    }
+}
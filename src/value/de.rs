@@ -0,0 +1,178 @@
+use super::{Map, Number, Value};
+use crate::util::cautious_capacity;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::BuildHasher;
+use core::marker::PhantomData;
+use serde_core::de::{
+    Deserialize, DeserializeSeed, Deserializer, Error, MapAccess, SeqAccess, Visitor,
+};
+
+impl<'de> Deserialize<'de> for Number {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct NumberVisitor;
+
+        impl Visitor<'_> for NumberVisitor {
+            type Value = Number;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a number")
+            }
+
+            fn visit_i64<E: Error>(self, v: i64) -> Result<Number, E> {
+                Ok(v.into())
+            }
+
+            fn visit_u64<E: Error>(self, v: u64) -> Result<Number, E> {
+                Ok(v.into())
+            }
+
+            fn visit_f64<E: Error>(self, v: f64) -> Result<Number, E> {
+                Number::from_f64(v).ok_or_else(|| E::custom("number must be finite"))
+            }
+        }
+
+        deserializer.deserialize_any(NumberVisitor)
+    }
+}
+
+impl<'de, S> Deserialize<'de> for Value<S>
+where
+    S: BuildHasher + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ValueVisitor(PhantomData))
+    }
+}
+
+struct ValueVisitor<S>(PhantomData<S>);
+
+impl<'de, S> Visitor<'de> for ValueVisitor<S>
+where
+    S: BuildHasher + Default,
+{
+    type Value = Value<S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("any valid value")
+    }
+
+    fn visit_bool<E: Error>(self, v: bool) -> Result<Value<S>, E> {
+        Ok(Value::Bool(v))
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Value<S>, E> {
+        Ok(v.into())
+    }
+
+    fn visit_i128<E: Error>(self, v: i128) -> Result<Value<S>, E> {
+        if let Ok(v) = u64::try_from(v) {
+            Ok(v.into())
+        } else if let Ok(v) = i64::try_from(v) {
+            Ok(v.into())
+        } else {
+            Err(E::custom("number out of range"))
+        }
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Value<S>, E> {
+        Ok(v.into())
+    }
+
+    fn visit_u128<E: Error>(self, v: u128) -> Result<Value<S>, E> {
+        match u64::try_from(v) {
+            Ok(v) => Ok(v.into()),
+            Err(_) => Err(E::custom("number out of range")),
+        }
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Value<S>, E> {
+        Ok(v.into())
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Value<S>, E> {
+        Ok(v.into())
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Value<S>, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Value<S>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Value<S>, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Value<S>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<Value<S>, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let capacity = cautious_capacity::<Value<S>, ()>(access.size_hint());
+        let mut array = Vec::with_capacity(capacity);
+        while let Some(element) = access.next_element()? {
+            array.push(element);
+        }
+        Ok(Value::Array(array))
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<Value<S>, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let capacity = cautious_capacity::<String, Value<S>>(access.size_hint());
+        let mut object = Map::with_capacity_and_hasher(capacity, S::default());
+        while let Some(key) = access.next_key_seed(KeySeed)? {
+            let value = access.next_value()?;
+            object.insert(key, value);
+        }
+        Ok(Value::Object(object))
+    }
+}
+
+/// Deserializes an object key as a `String`, without relying on `serde`'s
+/// `alloc` feature.
+struct KeySeed;
+
+impl<'de> DeserializeSeed<'de> for KeySeed {
+    type Value = String;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl Visitor<'_> for KeySeed {
+    type Value = String;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a string key")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<String, E> {
+        Ok(v.to_owned())
+    }
+}
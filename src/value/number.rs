@@ -0,0 +1,130 @@
+use core::fmt;
+
+/// A number in a [`Value`][super::Value]: an integer that fits in `i64` or
+/// `u64`, or a finite float.
+///
+/// Integers are kept exactly, rather than being converted to floats.
+#[derive(Clone, Copy)]
+pub struct Number {
+    n: N,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum N {
+    /// Always non-negative.
+    PosInt(u64),
+    /// Always negative.
+    NegInt(i64),
+    /// Always finite.
+    Float(f64),
+}
+
+impl Number {
+    /// Converts a finite `f64` to a `Number`, or returns `None` for NaN or
+    /// infinity.
+    pub fn from_f64(f: f64) -> Option<Number> {
+        f.is_finite().then_some(Number { n: N::Float(f) })
+    }
+
+    /// Returns `true` if the number is an integer that fits in `i64`.
+    pub fn is_i64(&self) -> bool {
+        self.as_i64().is_some()
+    }
+
+    /// Returns `true` if the number is a non-negative integer that fits in `u64`.
+    pub fn is_u64(&self) -> bool {
+        matches!(self.n, N::PosInt(_))
+    }
+
+    /// Returns `true` if the number is a float.
+    ///
+    /// This is `false` for integers, even though they can also be converted by
+    /// [`as_f64`][Self::as_f64].
+    pub fn is_f64(&self) -> bool {
+        matches!(self.n, N::Float(_))
+    }
+
+    /// Returns the number as an `i64` if it is an integer that fits, else `None`.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self.n {
+            N::PosInt(n) => i64::try_from(n).ok(),
+            N::NegInt(n) => Some(n),
+            N::Float(_) => None,
+        }
+    }
+
+    /// Returns the number as a `u64` if it is a non-negative integer, else `None`.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self.n {
+            N::PosInt(n) => Some(n),
+            N::NegInt(_) | N::Float(_) => None,
+        }
+    }
+
+    /// Returns the number as an `f64`, which may lose precision for large
+    /// integers.
+    pub fn as_f64(&self) -> f64 {
+        match self.n {
+            N::PosInt(n) => n as f64,
+            N::NegInt(n) => n as f64,
+            N::Float(f) => f,
+        }
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        self.n == other.n
+    }
+}
+
+// Floats are always finite, so equality is reflexive.
+impl Eq for Number {}
+
+impl fmt::Debug for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.n {
+            N::PosInt(n) => fmt::Debug::fmt(&n, f),
+            N::NegInt(n) => fmt::Debug::fmt(&n, f),
+            N::Float(n) => fmt::Debug::fmt(&n, f),
+        }
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.n {
+            N::PosInt(n) => fmt::Display::fmt(&n, f),
+            N::NegInt(n) => fmt::Display::fmt(&n, f),
+            N::Float(n) => fmt::Display::fmt(&n, f),
+        }
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($ty:ty)*) => {$(
+        impl From<$ty> for Number {
+            fn from(n: $ty) -> Self {
+                Number { n: N::PosInt(n as u64) }
+            }
+        }
+    )*};
+}
+
+macro_rules! from_signed {
+    ($($ty:ty)*) => {$(
+        impl From<$ty> for Number {
+            fn from(n: $ty) -> Self {
+                let n = if n < 0 {
+                    N::NegInt(n as i64)
+                } else {
+                    N::PosInt(n as u64)
+                };
+                Number { n }
+            }
+        }
+    )*};
+}
+
+from_unsigned! { u8 u16 u32 u64 usize }
+from_signed! { i8 i16 i32 i64 isize }
@@ -0,0 +1,721 @@
+use super::{Map, Number, Value};
+use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::BuildHasher;
+use core::marker::PhantomData;
+use serde_core::ser::{self, Impossible, Serialize};
+
+impl Serialize for Number {
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: ser::Serializer,
+    {
+        if let Some(n) = self.as_u64() {
+            serializer.serialize_u64(n)
+        } else if let Some(n) = self.as_i64() {
+            serializer.serialize_i64(n)
+        } else {
+            serializer.serialize_f64(self.as_f64())
+        }
+    }
+}
+
+impl<S> Serialize for Value<S> {
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: ser::Serializer,
+    {
+        match self {
+            Value::Null => serializer.serialize_unit(),
+            Value::Bool(b) => serializer.serialize_bool(*b),
+            Value::Number(n) => n.serialize(serializer),
+            Value::String(s) => serializer.serialize_str(s),
+            Value::Array(array) => serializer.collect_seq(array),
+            Value::Object(object) => {
+                use serde_core::ser::SerializeMap;
+                let mut map = serializer.serialize_map(Some(object.len()))?;
+                for (key, value) in object {
+                    map.serialize_entry(key.as_str(), value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+/// Converts any `T: Serialize` into a [`Value`].
+///
+/// Structs and maps become objects with their fields in serialization order,
+/// sequences and tuples become arrays, and `None` and unit types become
+/// `Null`. Enum variants are represented externally tagged, as an object
+/// with the variant name as the single key, except for unit variants which
+/// become just the name as a string.
+///
+/// This fails if a map has keys that can't be represented as strings, or for
+/// integers that don't fit in `i64` or `u64`. Non-finite floats become `Null`.
+///
+/// # Examples
+///
+/// ```
+/// use ordermap::value::Value;
+/// use ordermap::{ordermap, value};
+///
+/// let map = ordermap! { 'z' => Some(1.5), 'a' => None };
+/// let value: Value = value::to_value(&map).unwrap();
+/// assert_eq!(value, value!({ "z": 1.5, "a": null }));
+///
+/// let err = value::to_value::<_, std::hash::RandomState>(&ordermap! { [1] => 1 });
+/// assert_eq!(err.unwrap_err().to_string(), "key must be a string");
+/// ```
+pub fn to_value<T, S>(value: &T) -> Result<Value<S>, Error>
+where
+    T: ?Sized + Serialize,
+    S: BuildHasher + Default,
+{
+    value.serialize(Serializer::new())
+}
+
+/// An error from converting a value with [`to_value`] or a [`Serializer`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    message: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error {
+            message: msg.to_string(),
+        }
+    }
+}
+
+fn key_must_be_a_string() -> Error {
+    Error {
+        message: "key must be a string".to_owned(),
+    }
+}
+
+/// A [`Serializer`][ser::Serializer] whose output is a [`Value`].
+///
+/// This is used by [`to_value`], where its behavior is described.
+pub struct Serializer<S> {
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<S> Serializer<S> {
+    /// Creates a new `Serializer`.
+    pub fn new() -> Self {
+        Serializer {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<S> Default for Serializer<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Clone for Serializer<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for Serializer<S> {}
+
+impl<S> fmt::Debug for Serializer<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Serializer")
+    }
+}
+
+fn tagged<S>(variant: &'static str, value: Value<S>) -> Value<S>
+where
+    S: BuildHasher + Default,
+{
+    let mut object = Map::with_capacity_and_hasher(1, S::default());
+    object.insert(variant.to_owned(), value);
+    Value::Object(object)
+}
+
+impl<S> ser::Serializer for Serializer<S>
+where
+    S: BuildHasher + Default,
+{
+    type Ok = Value<S>;
+    type Error = Error;
+
+    type SerializeSeq = SerializeArray<S>;
+    type SerializeTuple = SerializeArray<S>;
+    type SerializeTupleStruct = SerializeArray<S>;
+    type SerializeTupleVariant = SerializeArray<S>;
+    type SerializeMap = SerializeObject<S>;
+    type SerializeStruct = SerializeObject<S>;
+    type SerializeStructVariant = SerializeObject<S>;
+
+    fn serialize_bool(self, v: bool) -> Result<Value<S>, Error> {
+        Ok(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value<S>, Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value<S>, Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value<S>, Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value<S>, Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Value<S>, Error> {
+        if let Ok(v) = u64::try_from(v) {
+            Ok(v.into())
+        } else if let Ok(v) = i64::try_from(v) {
+            Ok(v.into())
+        } else {
+            Err(ser::Error::custom("number out of range"))
+        }
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value<S>, Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value<S>, Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value<S>, Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value<S>, Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Value<S>, Error> {
+        match u64::try_from(v) {
+            Ok(v) => Ok(v.into()),
+            Err(_) => Err(ser::Error::custom("number out of range")),
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value<S>, Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value<S>, Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value<S>, Error> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value<S>, Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value<S>, Error> {
+        Ok(v.iter().map(|&b| Value::from(b)).collect())
+    }
+
+    fn serialize_none(self) -> Result<Value<S>, Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Value<S>, Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value<S>, Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value<S>, Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Value<S>, Error> {
+        Ok(variant.into())
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<Value<S>, Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value<S>, Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        Ok(tagged(variant, value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeArray<S>, Error> {
+        Ok(SerializeArray {
+            array: Vec::with_capacity(len.unwrap_or(0)),
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeArray<S>, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeArray<S>, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeArray<S>, Error> {
+        Ok(SerializeArray {
+            array: Vec::with_capacity(len),
+            variant: Some(variant),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeObject<S>, Error> {
+        Ok(SerializeObject {
+            object: Map::with_capacity_and_hasher(len.unwrap_or(0), S::default()),
+            next_key: None,
+            variant: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeObject<S>, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeObject<S>, Error> {
+        Ok(SerializeObject {
+            object: Map::with_capacity_and_hasher(len, S::default()),
+            next_key: None,
+            variant: Some(variant),
+        })
+    }
+
+    fn collect_str<T>(self, value: &T) -> Result<Value<S>, Error>
+    where
+        T: ?Sized + fmt::Display,
+    {
+        Ok(Value::String(value.to_string()))
+    }
+}
+
+/// The state of a [`Serializer`] while serializing a sequence, tuple, or
+/// tuple variant.
+pub struct SerializeArray<S> {
+    array: Vec<Value<S>>,
+    variant: Option<&'static str>,
+}
+
+impl<S> SerializeArray<S>
+where
+    S: BuildHasher + Default,
+{
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.array.push(value.serialize(Serializer::new())?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value<S>, Error> {
+        let array = Value::Array(self.array);
+        Ok(match self.variant {
+            Some(variant) => tagged(variant, array),
+            None => array,
+        })
+    }
+}
+
+impl<S> ser::SerializeSeq for SerializeArray<S>
+where
+    S: BuildHasher + Default,
+{
+    type Ok = Value<S>;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value<S>, Error> {
+        self.finish()
+    }
+}
+
+impl<S> ser::SerializeTuple for SerializeArray<S>
+where
+    S: BuildHasher + Default,
+{
+    type Ok = Value<S>;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value<S>, Error> {
+        self.finish()
+    }
+}
+
+impl<S> ser::SerializeTupleStruct for SerializeArray<S>
+where
+    S: BuildHasher + Default,
+{
+    type Ok = Value<S>;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value<S>, Error> {
+        self.finish()
+    }
+}
+
+impl<S> ser::SerializeTupleVariant for SerializeArray<S>
+where
+    S: BuildHasher + Default,
+{
+    type Ok = Value<S>;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value<S>, Error> {
+        self.finish()
+    }
+}
+
+/// The state of a [`Serializer`] while serializing a map, struct, or struct
+/// variant.
+pub struct SerializeObject<S> {
+    object: Map<S>,
+    next_key: Option<String>,
+    variant: Option<&'static str>,
+}
+
+impl<S> SerializeObject<S>
+where
+    S: BuildHasher + Default,
+{
+    fn insert<T: ?Sized + Serialize>(&mut self, key: String, value: &T) -> Result<(), Error> {
+        let value = value.serialize(Serializer::new())?;
+        self.object.insert(key, value);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value<S>, Error> {
+        let object = Value::Object(self.object);
+        Ok(match self.variant {
+            Some(variant) => tagged(variant, object),
+            None => object,
+        })
+    }
+}
+
+impl<S> ser::SerializeMap for SerializeObject<S>
+where
+    S: BuildHasher + Default,
+{
+    type Ok = Value<S>;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+        self.next_key = Some(key.serialize(KeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .next_key
+            .take()
+            .expect("serialize_value called before serialize_key");
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Value<S>, Error> {
+        self.finish()
+    }
+}
+
+impl<S> ser::SerializeStruct for SerializeObject<S>
+where
+    S: BuildHasher + Default,
+{
+    type Ok = Value<S>;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.insert(key.to_owned(), value)
+    }
+
+    fn end(self) -> Result<Value<S>, Error> {
+        self.finish()
+    }
+}
+
+impl<S> ser::SerializeStructVariant for SerializeObject<S>
+where
+    S: BuildHasher + Default,
+{
+    type Ok = Value<S>;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.insert(key.to_owned(), value)
+    }
+
+    fn end(self) -> Result<Value<S>, Error> {
+        self.finish()
+    }
+}
+
+/// Serializes map keys, which must be strings or string-like scalars.
+struct KeySerializer;
+
+impl ser::Serializer for KeySerializer {
+    type Ok = String;
+    type Error = Error;
+
+    type SerializeSeq = Impossible<String, Error>;
+    type SerializeTuple = Impossible<String, Error>;
+    type SerializeTupleStruct = Impossible<String, Error>;
+    type SerializeTupleVariant = Impossible<String, Error>;
+    type SerializeMap = Impossible<String, Error>;
+    type SerializeStruct = Impossible<String, Error>;
+    type SerializeStructVariant = Impossible<String, Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<String, Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<String, Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_char(self, v: char) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<String, Error> {
+        Ok(v.to_owned())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<String, Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_none(self) -> Result<String, Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_some<T>(self, _value: &T) -> Result<String, Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit(self) -> Result<String, Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<String, Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<String, Error> {
+        Ok(variant.to_owned())
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<String, Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<String, Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Err(key_must_be_a_string())
+    }
+
+    fn collect_str<T>(self, value: &T) -> Result<String, Error>
+    where
+        T: ?Sized + fmt::Display,
+    {
+        Ok(value.to_string())
+    }
+}
@@ -0,0 +1,128 @@
+use super::*;
+use alloc::string::ToString;
+use alloc::vec;
+use serde::Serialize;
+use std::hash::RandomState;
+
+#[test]
+fn value_macro() {
+    let name = "x";
+    let v: Value = value!({
+        "null": null,
+        "bool": true,
+        "neg": -1,
+        "float": 0.5,
+        "array": [1, "two", [], {}],
+        name: 1 + 2,
+        "nested": { "a": { "b": [null] } },
+    });
+    let object = v.as_object().unwrap();
+    assert!(
+        object
+            .keys()
+            .eq(["null", "bool", "neg", "float", "array", "x", "nested"])
+    );
+    assert!(object["null"].is_null());
+    assert_eq!(object["bool"].as_bool(), Some(true));
+    assert_eq!(object["neg"].as_i64(), Some(-1));
+    assert_eq!(object["neg"].as_u64(), None);
+    assert_eq!(object["float"].as_f64(), Some(0.5));
+    assert_eq!(object["x"].as_u64(), Some(3));
+    let array = object["array"].as_array().unwrap();
+    assert_eq!(array.len(), 4);
+    assert_eq!(array[1].as_str(), Some("two"));
+    assert_eq!(array[2], Value::<RandomState>::Array(vec![]));
+    assert_eq!(array[3].as_object().map(Map::len), Some(0));
+
+    let empty: Value = value!([]);
+    assert_eq!(empty, Value::<RandomState>::Array(vec![]));
+    let trailing: Value = value!([1, 2,]);
+    assert_eq!(trailing.as_array().map(Vec::len), Some(2));
+    let nan: Value = value!(f64::NAN);
+    assert!(nan.is_null());
+}
+
+#[test]
+fn value_get_path() {
+    let mut v: Value = value!({
+        "a": [{ "b": 1 }, { "": 2, "c/d": 3, "e~f": 4 }],
+        "01": 5,
+    });
+    assert_eq!(v.get_path(""), Some(&v));
+    assert_eq!(v.get_path("/a/0/b"), Some(&value!(1)));
+    assert_eq!(v.get_path("/a/1/"), Some(&value!(2)));
+    assert_eq!(v.get_path("/a/1/c~1d"), Some(&value!(3)));
+    assert_eq!(v.get_path("/a/1/e~0f"), Some(&value!(4)));
+    assert_eq!(v.get_path("/01"), Some(&value!(5)));
+    assert_eq!(v.get_path("/a/01"), None);
+    assert_eq!(v.get_path("/a/+1"), None);
+    assert_eq!(v.get_path("/a/2"), None);
+    assert_eq!(v.get_path("/a/0/b/c"), None);
+    assert_eq!(v.get_path("/a/1/e~2f"), None);
+    assert_eq!(v.get_path("a"), None);
+
+    *v.get_path_mut("/a/0/b").unwrap() = value!("one");
+    assert_eq!(v.get_path("/a/0/b").and_then(Value::as_str), Some("one"));
+    assert!(v.get_path_mut("/a/0/z").is_none());
+}
+
+#[test]
+fn value_to_value() {
+    #[derive(Serialize)]
+    enum E {
+        Unit,
+        Newtype(u8),
+        Tuple(u8, u8),
+        Struct { z: u8, a: u8 },
+    }
+
+    #[derive(Serialize)]
+    struct S<'a> {
+        zeta: Option<u8>,
+        alpha: (),
+        list: &'a [E],
+        map: crate::OrderMap<u8, char>,
+        text: char,
+    }
+
+    let s = S {
+        zeta: Some(1),
+        alpha: (),
+        list: &[
+            E::Unit,
+            E::Newtype(2),
+            E::Tuple(3, 4),
+            E::Struct { z: 5, a: 6 },
+        ],
+        map: [(9, 'n'), (1, 'o')].into(),
+        text: 'c',
+    };
+    let v: Value = to_value(&s).unwrap();
+    let expected: Value = value!({
+        "zeta": 1,
+        "alpha": null,
+        "list": ["Unit", { "Newtype": 2 }, { "Tuple": [3, 4] }, { "Struct": { "z": 5, "a": 6 } }],
+        "map": { "9": "n", "1": "o" },
+        "text": "c",
+    });
+    assert_eq!(v, expected);
+
+    let err = to_value::<_, RandomState>(&crate::OrderMap::from([((1, 2), 3)])).unwrap_err();
+    assert_eq!(err.to_string(), "key must be a string");
+    let err = to_value::<_, RandomState>(&u128::MAX).unwrap_err();
+    assert_eq!(err.to_string(), "number out of range");
+    assert_eq!(to_value::<_, RandomState>(&-5i128), Ok(value!(-5)));
+}
+
+#[test]
+fn value_equality_is_ordered() {
+    let a: Value = value!({ "x": 1, "y": 2 });
+    let b: Value = value!({ "y": 2, "x": 1 });
+    assert_ne!(a, b);
+    assert_eq!(a, a.clone());
+    assert_ne!(value!(1) as Value, value!(1.0));
+    assert_eq!(Number::from(-1).as_i64(), Some(-1));
+    assert!(Number::from(u64::MAX).is_u64());
+    assert!(!Number::from(u64::MAX).is_i64());
+    assert_eq!(Number::from_f64(f64::INFINITY), None);
+}
@@ -5,6 +5,7 @@ use core::hash::Hasher;
 
 use ordermap::OrderMap;
 use ordermap::OrderSet;
+use ordermap::value;
 
 #[derive(Default)]
 struct BadHasher(u64);
@@ -34,3 +35,14 @@ pub fn test_compile() {
     let mut set = Set::default();
     set.insert("a");
 }
+
+pub fn test_value() {
+    type Value = value::Value<BuildHasherDefault<BadHasher>>;
+
+    let mut v: Value = value!({ "a": [1, null, { "b": true }] });
+    assert_eq!(v.get_path("/a/2/b"), Some(&Value::Bool(true)));
+    *v.get_path_mut("/a/1").unwrap() = value!("x");
+
+    let copy: Value = value::to_value(&v).unwrap();
+    assert_eq!(copy, v);
+}
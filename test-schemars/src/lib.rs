@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+use fnv::FnvBuildHasher;
+use ordermap::{OrderMap, OrderSet};
+use schemars::{JsonSchema, json_schema, schema_for};
+use serde::{Deserialize, Serialize};
+
+#[test]
+fn test_schemars_map() {
+    assert_eq!(
+        schema_for!(OrderMap<String, u32>),
+        json_schema!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "Map_of_uint32",
+            "type": "object",
+            "additionalProperties": {
+                "type": "integer",
+                "format": "uint32",
+                "minimum": 0,
+            },
+        })
+    );
+}
+
+#[test]
+fn test_schemars_set() {
+    assert_eq!(
+        schema_for!(OrderSet<u8>),
+        json_schema!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "Set_of_uint8",
+            "type": "array",
+            "uniqueItems": true,
+            "items": {
+                "type": "integer",
+                "format": "uint8",
+                "minimum": 0,
+                "maximum": 255,
+            },
+        })
+    );
+}
+
+#[test]
+fn test_schemars_fnv_hasher() {
+    assert_eq!(
+        schema_for!(OrderMap<String, u32, FnvBuildHasher>),
+        schema_for!(OrderMap<String, u32>)
+    );
+    assert_eq!(
+        schema_for!(OrderSet<u8, FnvBuildHasher>),
+        schema_for!(OrderSet<u8>)
+    );
+}
+
+#[test]
+fn test_schemars_derive() {
+    #[allow(dead_code)]
+    #[derive(Deserialize, Serialize, JsonSchema)]
+    struct Data {
+        map: OrderMap<i32, bool>,
+        #[serde(with = "ordermap::map::serde_seq")]
+        #[schemars(schema_with = "ordermap::map::serde_seq::json_schema::<i32, bool>")]
+        seq: OrderMap<i32, bool>,
+        set: OrderSet<u8>,
+    }
+
+    assert_eq!(
+        schema_for!(Data),
+        json_schema!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "Data",
+            "type": "object",
+            "properties": {
+                "map": {
+                    "type": "object",
+                    "patternProperties": {
+                        "^-?\\d+$": { "type": "boolean" },
+                    },
+                    "additionalProperties": false,
+                },
+                "seq": {
+                    "type": "array",
+                    "items": {
+                        "type": "array",
+                        "prefixItems": [
+                            { "type": "integer", "format": "int32" },
+                            { "type": "boolean" },
+                        ],
+                        "minItems": 2,
+                        "maxItems": 2,
+                    },
+                },
+                "set": {
+                    "type": "array",
+                    "uniqueItems": true,
+                    "items": {
+                        "type": "integer",
+                        "format": "uint8",
+                        "minimum": 0,
+                        "maximum": 255,
+                    },
+                },
+            },
+            "required": ["map", "seq", "set"],
+        })
+    );
+}
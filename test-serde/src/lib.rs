@@ -1,9 +1,14 @@
 #![cfg(test)]
 
 use fnv::FnvBuildHasher;
+use ordermap::map::{OrderMapSeed, SeqPreventDuplicates};
+use ordermap::set::OrderSetSeed;
 use ordermap::{OrderMap, OrderSet, ordermap, orderset};
 use serde::{Deserialize, Serialize};
-use serde_test::{Token, assert_tokens};
+use serde_test::{
+    Token, assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens,
+};
+use serde_with::{DisplayFromStr, MapPreventDuplicates, Seq, SetPreventDuplicates, serde_as};
 
 #[test]
 fn test_serde_map() {
@@ -113,3 +118,539 @@ fn test_serde_seq_map() {
         ],
     );
 }
+
+#[test]
+fn test_serde_strict() {
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    #[serde(transparent)]
+    struct StrictOrderMap {
+        #[serde(with = "ordermap::map::serde_strict")]
+        map: OrderMap<i32, i32>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    #[serde(transparent)]
+    struct StrictOrderSet {
+        #[serde(with = "ordermap::set::serde_strict")]
+        set: OrderSet<i32>,
+    }
+
+    let map = ordermap! { 1 => 2, 3 => 4 };
+    assert_tokens(
+        &StrictOrderMap { map },
+        &[
+            Token::Map { len: Some(2) },
+            Token::I32(1),
+            Token::I32(2),
+            Token::I32(3),
+            Token::I32(4),
+            Token::MapEnd,
+        ],
+    );
+    assert_de_tokens_error::<StrictOrderMap>(
+        &[
+            Token::Map { len: Some(3) },
+            Token::I32(1),
+            Token::I32(2),
+            Token::I32(3),
+            Token::I32(4),
+            Token::I32(1),
+            Token::I32(0),
+        ],
+        "duplicate key 1 at positions 0 and 2",
+    );
+    assert_de_tokens_error::<StrictOrderMap>(
+        &[Token::Seq { len: Some(0) }, Token::SeqEnd],
+        "invalid type: sequence, expected a map without duplicate keys",
+    );
+
+    let set = orderset! { 1, 2, 3 };
+    assert_tokens(
+        &StrictOrderSet { set },
+        &[
+            Token::Seq { len: Some(3) },
+            Token::I32(1),
+            Token::I32(2),
+            Token::I32(3),
+            Token::SeqEnd,
+        ],
+    );
+    assert_de_tokens_error::<StrictOrderSet>(
+        &[
+            Token::Seq { len: Some(4) },
+            Token::I32(1),
+            Token::I32(2),
+            Token::I32(3),
+            Token::I32(2),
+        ],
+        "duplicate value 2 at positions 1 and 3",
+    );
+}
+
+#[test]
+fn test_serde_strict_seq_map() {
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    #[serde(transparent)]
+    struct StrictSeqOrderMap {
+        #[serde(with = "ordermap::map::serde_strict::seq")]
+        map: OrderMap<i32, i32>,
+    }
+
+    let map = ordermap! { 3 => 4, 1 => 2 };
+    assert_tokens(
+        &StrictSeqOrderMap { map },
+        &[
+            Token::Seq { len: Some(2) },
+            Token::Tuple { len: 2 },
+            Token::I32(3),
+            Token::I32(4),
+            Token::TupleEnd,
+            Token::Tuple { len: 2 },
+            Token::I32(1),
+            Token::I32(2),
+            Token::TupleEnd,
+            Token::SeqEnd,
+        ],
+    );
+    assert_de_tokens_error::<StrictSeqOrderMap>(
+        &[
+            Token::Seq { len: Some(2) },
+            Token::Tuple { len: 2 },
+            Token::I32(3),
+            Token::I32(4),
+            Token::TupleEnd,
+            Token::Tuple { len: 2 },
+            Token::I32(3),
+            Token::I32(2),
+            Token::TupleEnd,
+        ],
+        "duplicate key 3 at positions 0 and 1",
+    );
+    assert_de_tokens_error::<StrictSeqOrderMap>(
+        &[Token::Map { len: Some(0) }, Token::MapEnd],
+        "invalid type: map, expected a sequence of key-value pairs without duplicate keys",
+    );
+}
+
+#[test]
+fn test_serde_with() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Data {
+        #[serde_as(as = "OrderMap<DisplayFromStr, _>")]
+        map: OrderMap<i32, i32>,
+        #[serde_as(as = "Seq<(_, DisplayFromStr)>")]
+        seq: OrderMap<i32, i32>,
+        #[serde_as(as = "OrderSet<DisplayFromStr>")]
+        set: OrderSet<i32>,
+    }
+
+    let data = Data {
+        map: ordermap! { 3 => 4, 1 => 2 },
+        seq: ordermap! { 5 => 6 },
+        set: orderset! { 7, 8 },
+    };
+    assert_tokens(
+        &data,
+        &[
+            Token::Struct {
+                name: "Data",
+                len: 3,
+            },
+            Token::Str("map"),
+            Token::Map { len: Some(2) },
+            Token::Str("3"),
+            Token::I32(4),
+            Token::Str("1"),
+            Token::I32(2),
+            Token::MapEnd,
+            Token::Str("seq"),
+            Token::Seq { len: Some(1) },
+            Token::Tuple { len: 2 },
+            Token::I32(5),
+            Token::Str("6"),
+            Token::TupleEnd,
+            Token::SeqEnd,
+            Token::Str("set"),
+            Token::Seq { len: Some(2) },
+            Token::Str("7"),
+            Token::Str("8"),
+            Token::SeqEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn test_serde_with_prevent_duplicates() {
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    #[serde(transparent)]
+    struct StrictMap(#[serde_as(as = "MapPreventDuplicates<_, _>")] OrderMap<i32, i32>);
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    #[serde(transparent)]
+    struct StrictSet(#[serde_as(as = "SetPreventDuplicates<_>")] OrderSet<i32>);
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    #[serde(transparent)]
+    struct StrictSeq(#[serde_as(as = "SeqPreventDuplicates<_, _>")] OrderMap<i32, i32>);
+
+    assert_tokens(
+        &StrictMap(ordermap! { 3 => 4, 1 => 2 }),
+        &[
+            Token::Map { len: Some(2) },
+            Token::I32(3),
+            Token::I32(4),
+            Token::I32(1),
+            Token::I32(2),
+            Token::MapEnd,
+        ],
+    );
+    assert_de_tokens_error::<StrictMap>(
+        &[
+            Token::Map { len: Some(2) },
+            Token::I32(3),
+            Token::I32(4),
+            Token::I32(3),
+            Token::I32(2),
+        ],
+        "invalid entry: found duplicate key",
+    );
+
+    assert_tokens(
+        &StrictSet(orderset! { 2, 1 }),
+        &[
+            Token::Seq { len: Some(2) },
+            Token::I32(2),
+            Token::I32(1),
+            Token::SeqEnd,
+        ],
+    );
+    assert_de_tokens_error::<StrictSet>(
+        &[Token::Seq { len: Some(2) }, Token::I32(2), Token::I32(2)],
+        "invalid entry: found duplicate value",
+    );
+
+    assert_tokens(
+        &StrictSeq(ordermap! { 3 => 4, 1 => 2 }),
+        &[
+            Token::Seq { len: Some(2) },
+            Token::Tuple { len: 2 },
+            Token::I32(3),
+            Token::I32(4),
+            Token::TupleEnd,
+            Token::Tuple { len: 2 },
+            Token::I32(1),
+            Token::I32(2),
+            Token::TupleEnd,
+            Token::SeqEnd,
+        ],
+    );
+    assert_de_tokens_error::<StrictSeq>(
+        &[
+            Token::Seq { len: Some(2) },
+            Token::Tuple { len: 2 },
+            Token::I32(3),
+            Token::I32(4),
+            Token::TupleEnd,
+            Token::Tuple { len: 2 },
+            Token::I32(3),
+            Token::I32(2),
+            Token::TupleEnd,
+        ],
+        "invalid entry: found duplicate key",
+    );
+}
+
+#[test]
+fn test_serde_sorted() {
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Sorted {
+        #[serde(with = "ordermap::map::serde_sorted")]
+        map: OrderMap<i32, char>,
+        #[serde(with = "ordermap::set::serde_sorted")]
+        set: OrderSet<i32>,
+    }
+
+    let sorted = Sorted {
+        map: ordermap! { 3 => 'c', 1 => 'a', 2 => 'b' },
+        set: orderset! { 5, -1, 3 },
+    };
+    let tokens = [
+        Token::Struct {
+            name: "Sorted",
+            len: 2,
+        },
+        Token::Str("map"),
+        Token::Map { len: Some(3) },
+        Token::I32(1),
+        Token::Char('a'),
+        Token::I32(2),
+        Token::Char('b'),
+        Token::I32(3),
+        Token::Char('c'),
+        Token::MapEnd,
+        Token::Str("set"),
+        Token::Seq { len: Some(3) },
+        Token::I32(-1),
+        Token::I32(3),
+        Token::I32(5),
+        Token::SeqEnd,
+        Token::StructEnd,
+    ];
+    assert_ser_tokens(&sorted, &tokens);
+    assert!(sorted.map.keys().eq(&[3, 1, 2]));
+
+    // Deserializing keeps the sorted order of the input.
+    let sorted = Sorted {
+        map: ordermap! { 1 => 'a', 2 => 'b', 3 => 'c' },
+        set: orderset! { -1, 3, 5 },
+    };
+    assert_de_tokens(&sorted, &tokens);
+}
+
+#[test]
+fn test_serde_sorted_by() {
+    #[derive(Serialize)]
+    #[serde(transparent)]
+    struct ByValue(#[serde(serialize_with = "by_value")] OrderMap<i32, i32>);
+
+    fn by_value<S: serde::Serializer>(
+        map: &OrderMap<i32, i32>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ordermap::map::serde_sorted::serialize_by(map, |_, v1, _, v2| v1.cmp(v2), serializer)
+    }
+
+    assert_ser_tokens(
+        &ByValue(ordermap! { 1 => 30, 2 => 10, 3 => 20 }),
+        &[
+            Token::Map { len: Some(3) },
+            Token::I32(2),
+            Token::I32(10),
+            Token::I32(3),
+            Token::I32(20),
+            Token::I32(1),
+            Token::I32(30),
+            Token::MapEnd,
+        ],
+    );
+}
+
+#[test]
+fn test_serde_seed() {
+    use serde::de::DeserializeSeed;
+    use serde::de::value::{Error, MapDeserializer, SeqDeserializer};
+    use std::hash::{BuildHasher, RandomState};
+
+    let hasher = RandomState::new();
+
+    let input = MapDeserializer::<_, Error>::new([(3, 4), (1, 2)].into_iter());
+    let map: OrderMap<i32, i32, RandomState> = OrderMapSeed::new(hasher.clone())
+        .deserialize(input)
+        .unwrap();
+    assert!(map.iter().eq(&ordermap! { 3 => 4, 1 => 2 }));
+    assert_eq!(map.hasher().hash_one(1), hasher.hash_one(1));
+
+    let input = MapDeserializer::<_, Error>::new([(1, 2)].into_iter());
+    let map: OrderMap<i32, i32, RandomState> =
+        OrderMapSeed::with_capacity_and_hasher(Some(100), hasher.clone())
+            .deserialize(input)
+            .unwrap();
+    assert!(map.capacity() >= 100);
+
+    let input = SeqDeserializer::<_, Error>::new([3, 1, 2].into_iter());
+    let set: OrderSet<i32, RandomState> = OrderSetSeed::new(hasher.clone())
+        .deserialize(input)
+        .unwrap();
+    assert_eq!(set.as_slice(), &[3, 1, 2]);
+    assert_eq!(set.hasher().hash_one(1), hasher.hash_one(1));
+}
+
+#[test]
+fn test_serde_seq_seed() {
+    use ordermap::map::serde_seq::OrderMapSeqSeed;
+    use serde::de::DeserializeSeed;
+    use serde::de::value::{Error, MapAccessDeserializer, MapDeserializer};
+    use std::hash::{BuildHasher, RandomState};
+
+    let hasher = RandomState::new();
+
+    // `MapDeserializer` can also be read as a sequence of pairs.
+    let input = MapDeserializer::<_, Error>::new([(3, 4), (1, 2), (3, 5)].into_iter());
+    let map: OrderMap<i32, i32, RandomState> =
+        OrderMapSeqSeed::with_capacity_and_hasher(Some(100), hasher.clone())
+            .deserialize(input)
+            .unwrap();
+    assert!(map.iter().eq(&ordermap! { 3 => 5, 1 => 2 }));
+    assert!(map.capacity() >= 100);
+    assert_eq!(map.hasher().hash_one(1), hasher.hash_one(1));
+
+    let input = MapDeserializer::<_, Error>::new([(1, 2)].into_iter());
+    let err = OrderMapSeqSeed::<i32, i32, _>::new(hasher)
+        .deserialize(MapAccessDeserializer::new(input))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid type: map, expected a sequenced map"
+    );
+}
+
+#[test]
+fn test_serde_deserialize_in_place() {
+    use serde::de::value::{Error, MapDeserializer, SeqDeserializer};
+
+    let mut map: OrderMap<i32, i32> = OrderMap::with_capacity(100);
+    map.insert(0, 0);
+    map.insert(3, 0);
+    let input = MapDeserializer::<_, Error>::new([(3, 4), (1, 2)].into_iter());
+    OrderMap::deserialize_in_place(input, &mut map).unwrap();
+    assert!(map.iter().eq(&ordermap! { 3 => 4, 1 => 2 }));
+    assert!(map.capacity() >= 100);
+
+    let mut set: OrderSet<i32> = OrderSet::with_capacity(100);
+    set.insert(0);
+    let input = SeqDeserializer::<_, Error>::new([3, 1, 3, 2].into_iter());
+    OrderSet::deserialize_in_place(input, &mut set).unwrap();
+    assert_eq!(set.as_slice(), &[3, 1, 2]);
+    assert!(set.capacity() >= 100);
+}
+
+#[test]
+fn test_serde_merge() {
+    use ordermap::map::serde_merge::{self, MergeOrder};
+    use serde::de::IntoDeserializer;
+    use serde::de::value::Error;
+
+    let base = ordermap! { 1 => 10, 2 => 20, 3 => 30 };
+    let layer = ordermap! { 4 => 40, 2 => 21 };
+
+    let mut map = base.clone();
+    let input = IntoDeserializer::<Error>::into_deserializer(layer.clone());
+    serde_merge::merge(&mut map, input, MergeOrder::KeepPositions).unwrap();
+    assert!(
+        map.iter()
+            .eq(&ordermap! { 1 => 10, 2 => 21, 3 => 30, 4 => 40 })
+    );
+
+    let mut map = base.clone();
+    let input = IntoDeserializer::<Error>::into_deserializer(layer);
+    serde_merge::merge(&mut map, input, MergeOrder::Append).unwrap();
+    assert!(
+        map.iter()
+            .eq(&ordermap! { 1 => 10, 3 => 30, 4 => 40, 2 => 21 })
+    );
+
+    let base = ordermap! {
+        'a' => ordermap! { 1 => 10, 2 => 20 },
+        'b' => ordermap! { 3 => 30 },
+        'c' => ordermap! {},
+    };
+    let layer = ordermap! {
+        'a' => ordermap! { 0 => 0, 1 => 11 },
+        'd' => ordermap! { 4 => 40 },
+    };
+
+    let mut map = base.clone();
+    let input = IntoDeserializer::<Error>::into_deserializer(layer.clone());
+    serde_merge::merge_nested(&mut map, input, MergeOrder::KeepPositions).unwrap();
+    assert!(map.keys().eq(&['a', 'b', 'c', 'd']));
+    assert!(map[&'a'].iter().eq(&ordermap! { 1 => 11, 2 => 20, 0 => 0 }));
+    assert!(map[&'d'].iter().eq(&ordermap! { 4 => 40 }));
+
+    let mut map = base;
+    let input = IntoDeserializer::<Error>::into_deserializer(layer);
+    serde_merge::merge_nested(&mut map, input, MergeOrder::Append).unwrap();
+    assert!(map.keys().eq(&['b', 'c', 'a', 'd']));
+    assert!(map[&'a'].iter().eq(&ordermap! { 2 => 20, 0 => 0, 1 => 11 }));
+}
+
+#[test]
+fn test_serde_borrowed_into_deserializer() {
+    use serde::de::value::{BorrowedStrDeserializer, Error, I64Deserializer};
+    use serde::de::{self, IntoDeserializer};
+
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    struct Name(&'static str);
+
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    struct Value(i64);
+
+    impl<'de, E: de::Error> IntoDeserializer<'de, E> for &Name {
+        type Deserializer = BorrowedStrDeserializer<'de, E>;
+
+        fn into_deserializer(self) -> Self::Deserializer {
+            BorrowedStrDeserializer::new(self.0)
+        }
+    }
+
+    impl<'de, E: de::Error> IntoDeserializer<'de, E> for &Value {
+        type Deserializer = I64Deserializer<E>;
+
+        fn into_deserializer(self) -> Self::Deserializer {
+            self.0.into_deserializer()
+        }
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Point {
+        x: i64,
+        y: i64,
+    }
+
+    let map = ordermap! { Name("y") => Value(2), Name("x") => Value(1) };
+    let point = Point::deserialize(IntoDeserializer::<Error>::into_deserializer(&map)).unwrap();
+    assert_eq!(point, Point { x: 1, y: 2 });
+    assert_eq!(map.len(), 2);
+
+    let set = orderset! { Value(20), Value(10), Value(30) };
+    let values = Vec::<i64>::deserialize(IntoDeserializer::<Error>::into_deserializer(&set));
+    assert_eq!(values, Ok(vec![20, 10, 30]));
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn test_serde_value() {
+    use ordermap::value;
+    use ordermap::value::Value;
+
+    let v: Value = value!({ "z": [1, -2, 0.5], "a": { "t": true, "n": null }, "s": "str" });
+    assert_tokens(
+        &v,
+        &[
+            Token::Map { len: Some(3) },
+            Token::Str("z"),
+            Token::Seq { len: Some(3) },
+            Token::U64(1),
+            Token::I64(-2),
+            Token::F64(0.5),
+            Token::SeqEnd,
+            Token::Str("a"),
+            Token::Map { len: Some(2) },
+            Token::Str("t"),
+            Token::Bool(true),
+            Token::Str("n"),
+            Token::Unit,
+            Token::MapEnd,
+            Token::Str("s"),
+            Token::Str("str"),
+            Token::MapEnd,
+        ],
+    );
+
+    #[derive(Serialize)]
+    struct Record {
+        zeta: u8,
+        alpha: &'static str,
+    }
+    let v: Value = value::to_value(&Record {
+        zeta: 1,
+        alpha: "a",
+    })
+    .unwrap();
+    assert_eq!(v, value!({ "zeta": 1, "alpha": "a" }));
+    assert!(v.as_object().unwrap().keys().eq(["zeta", "alpha"]));
+}